@@ -68,6 +68,7 @@ impl Pkmnapi {
                     routes::access_tokens::delete_access_token,
                     routes::access_tokens::post_access_token_delete,
                     routes::access_tokens::post_access_token,
+                    routes::battle_damage::post_battle_damage,
                     routes::hm_moves::get_hm_move_all,
                     routes::hm_moves::get_hm_move,
                     routes::hm_moves::post_hm_move,
@@ -76,6 +77,17 @@ impl Pkmnapi {
                     routes::item_names::get_item_name_all,
                     routes::item_names::get_item_name,
                     routes::item_names::post_item_name,
+                    routes::map_blocks::get_map_blocks,
+                    routes::map_blocks::post_map_blocks,
+                    routes::map_headers::get_map_header_all,
+                    routes::map_headers::get_map_header,
+                    routes::map_headers::post_map_header,
+                    routes::map_hidden_objects::get_map_hidden_objects_all,
+                    routes::map_hidden_objects::get_map_hidden_objects,
+                    routes::map_hidden_objects::post_map_hidden_objects,
+                    routes::map_objects::get_map_objects_all,
+                    routes::map_objects::get_map_objects,
+                    routes::map_objects::post_map_objects,
                     routes::map_pokemon::get_map_pokemon_all,
                     routes::map_pokemon::get_map_pokemon,
                     routes::map_pokemon::post_map_pokemon,
@@ -117,15 +129,47 @@ impl Pkmnapi {
                     routes::pokemon_names::get_pokemon_name_all,
                     routes::pokemon_names::get_pokemon_name,
                     routes::pokemon_names::post_pokemon_name,
+                    routes::pokemon_pics::get_pokemon_pic_json,
                     routes::pokemon_stats::get_pokemon_stats_all,
                     routes::pokemon_stats::get_pokemon_stats,
                     routes::pokemon_stats::post_pokemon_stats,
                     routes::rom_patches::delete_rom_patch,
                     routes::rom_patches::get_rom_patches,
                     routes::roms::delete_rom,
+                    routes::sav_badges::get_sav_badges,
+                    routes::sav_badges::post_sav_badges,
+                    routes::sav_bag_items::get_sav_bag_items,
+                    routes::sav_bag_items::post_sav_bag_items,
+                    routes::sav_box_items::get_sav_box_items,
+                    routes::sav_box_items::post_sav_box_items,
+                    routes::sav_coins::get_sav_coins,
+                    routes::sav_coins::post_sav_coins,
+                    routes::sav_current_box::get_sav_current_box,
+                    routes::sav_current_box::post_sav_current_box,
+                    routes::sav_money::get_sav_money,
+                    routes::sav_money::post_sav_money,
+                    routes::sav_options::get_sav_options,
+                    routes::sav_options::post_sav_options,
+                    routes::sav_patches::delete_sav_patch,
+                    routes::sav_patches::get_sav_patches,
+                    routes::sav_player_ids::get_sav_player_id,
+                    routes::sav_player_ids::post_sav_player_id,
                     routes::sav_player_names::get_sav_player_name,
                     routes::sav_player_names::post_sav_player_name,
+                    routes::sav_pokedex_owned::get_sav_pokedex_owned,
+                    routes::sav_pokedex_owned::post_sav_pokedex_owned,
+                    routes::sav_pokedex_seen::get_sav_pokedex_seen,
+                    routes::sav_pokedex_seen::post_sav_pokedex_seen,
+                    routes::sav_rival_names::get_sav_rival_name,
+                    routes::sav_rival_names::post_sav_rival_name,
                     routes::savs::delete_sav,
+                    routes::starters::get_starters,
+                    routes::starters::post_starters,
+                    routes::texts::get_text,
+                    routes::texts::post_text,
+                    routes::tilesets::get_tileset_all,
+                    routes::tilesets::get_tileset_json,
+                    routes::tilesets::post_tileset_json,
                     routes::tm_moves::get_tm_move_all,
                     routes::tm_moves::get_tm_move,
                     routes::tm_moves::post_tm_move,
@@ -134,6 +178,9 @@ impl Pkmnapi {
                     routes::tm_prices::get_tm_price_all,
                     routes::tm_prices::get_tm_price,
                     routes::tm_prices::post_tm_price,
+                    routes::town_map_locations::get_town_map_location_all,
+                    routes::town_map_locations::get_town_map_location,
+                    routes::town_map_locations::post_town_map_location,
                     routes::trades::get_trade_all,
                     routes::trades::get_trade,
                     routes::trades::post_trade,
@@ -158,6 +205,8 @@ impl Pkmnapi {
                 "/v1",
                 routes![
                     routes::icons::get_icon,
+                    routes::icons::post_icon_gif,
+                    routes::icons::post_icon_png,
                     routes::imgs::get_game_boy_jpeg,
                     routes::imgs::get_game_boy_png,
                     routes::imgs::get_pokemon_logo_jpeg,
@@ -166,19 +215,33 @@ impl Pkmnapi {
                     routes::imgs::get_town_map_png,
                     routes::imgs::post_pokemon_logo_jpeg,
                     routes::imgs::post_pokemon_logo_png,
+                    routes::imgs::post_town_map_jpeg,
+                    routes::imgs::post_town_map_png,
                     routes::map_pics::get_map_pic_jpeg,
                     routes::map_pics::get_map_pic_png,
+                    routes::map_pics::post_map_pic_jpeg,
+                    routes::map_pics::post_map_pic_png,
                     routes::pokemon_cries::get_pokemon_cry_wav,
+                    routes::pokemon_icons::get_pokemon_icon_gif,
                     routes::pokemon_pics::get_pokemon_pic_jpeg,
                     routes::pokemon_pics::get_pokemon_pic_png,
                     routes::pokemon_pics::post_pokemon_pic_jpeg,
                     routes::pokemon_pics::post_pokemon_pic_png,
                     routes::rom_patches::get_rom_patch,
+                    routes::rom_patches::get_rom_patches_bps,
                     routes::rom_patches::get_rom_patches_raw,
+                    routes::rom_patches::get_rom_patches_ups,
+                    routes::rom_patches::post_rom_patches,
                     routes::roms::get_rom,
                     routes::roms::post_rom,
+                    routes::sav_patches::get_sav_patch,
+                    routes::sav_patches::get_sav_patches_raw,
                     routes::savs::get_sav,
                     routes::savs::post_sav,
+                    routes::tilesets::get_tileset_jpeg,
+                    routes::tilesets::get_tileset_png,
+                    routes::tilesets::post_tileset_jpeg,
+                    routes::tilesets::post_tileset_png,
                     routes::trainer_pics::get_trainer_pic_jpeg,
                     routes::trainer_pics::get_trainer_pic_png,
                     routes::trainer_pics::post_trainer_pic_jpeg,
@@ -0,0 +1,102 @@
+use pkmnapi_db::battle::{Battler, DVs, StatExp};
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type BattleDamageRequest = BaseRequest<BattleDamageRequestType, BattleDamageRequestAttributes>;
+
+impl BattleDamageRequest {
+    pub fn get_move_id(&self) -> u8 {
+        self.data.attributes._move.id
+    }
+
+    pub fn get_attacker(&self) -> Battler {
+        self.data.attributes.attacker.to_battler()
+    }
+
+    pub fn get_defender(&self) -> Battler {
+        self.data.attributes.defender.to_battler()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum BattleDamageRequestType {
+    battle_damage,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BattleDamageRequestAttributes {
+    #[serde(rename = "move")]
+    pub _move: BattleDamageRequestAttributesMove,
+    pub attacker: BattleDamageRequestAttributesBattler,
+    pub defender: BattleDamageRequestAttributesBattler,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BattleDamageRequestAttributesMove {
+    #[serde(deserialize_with = "crate::utils::from_numeric_str")]
+    pub id: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BattleDamageRequestAttributesBattler {
+    pub pokemon: BattleDamageRequestAttributesPokemon,
+    pub level: u8,
+
+    #[serde(default)]
+    pub dvs: BattleDamageRequestAttributesDVs,
+
+    #[serde(default)]
+    pub stat_exp: BattleDamageRequestAttributesStatExp,
+
+    #[serde(default)]
+    pub badges: Vec<u8>,
+}
+
+impl BattleDamageRequestAttributesBattler {
+    pub fn to_battler(&self) -> Battler {
+        Battler {
+            pokedex_id: self.pokemon.id,
+            level: self.level,
+            dvs: DVs::new(
+                self.dvs.attack,
+                self.dvs.defence,
+                self.dvs.speed,
+                self.dvs.special,
+            ),
+            stat_exp: StatExp {
+                hp: self.stat_exp.hp,
+                attack: self.stat_exp.attack,
+                defence: self.stat_exp.defence,
+                speed: self.stat_exp.speed,
+                special: self.stat_exp.special,
+            },
+            badges: self.badges.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BattleDamageRequestAttributesPokemon {
+    #[serde(deserialize_with = "crate::utils::from_numeric_str")]
+    pub id: u8,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct BattleDamageRequestAttributesDVs {
+    pub attack: u8,
+    pub defence: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct BattleDamageRequestAttributesStatExp {
+    pub hp: u16,
+    pub attack: u16,
+    pub defence: u16,
+    pub speed: u16,
+    pub special: u16,
+}
@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type MapBlocksRequest = BaseRequest<MapBlocksRequestType, MapBlocksRequestAttributes>;
+
+impl MapBlocksRequest {
+    pub fn get_blocks(&self) -> Vec<Vec<u8>> {
+        self.data.attributes.blocks.to_vec()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapBlocksRequestType {
+    map_blocks,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapBlocksRequestAttributes {
+    pub blocks: Vec<Vec<u8>>,
+}
@@ -0,0 +1,120 @@
+use pkmnapi_db::{MapConnection, MapConnections};
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type MapHeaderRequest = BaseRequest<MapHeaderRequestType, MapHeaderRequestAttributes>;
+
+impl MapHeaderRequest {
+    pub fn get_tileset_id(&self) -> u8 {
+        self.data.attributes.tileset_id
+    }
+
+    pub fn get_height(&self) -> u8 {
+        self.data.attributes.height
+    }
+
+    pub fn get_width(&self) -> u8 {
+        self.data.attributes.width
+    }
+
+    pub fn get_blocks_pointer(&self) -> u16 {
+        self.data.attributes.blocks_pointer
+    }
+
+    pub fn get_text_pointer(&self) -> u16 {
+        self.data.attributes.text_pointer
+    }
+
+    pub fn get_script_pointer(&self) -> u16 {
+        self.data.attributes.script_pointer
+    }
+
+    pub fn get_connections(&self) -> MapConnections {
+        let connections = &self.data.attributes.connections;
+
+        MapConnections {
+            north: connections
+                .north
+                .as_ref()
+                .map(|connection| connection.into()),
+            south: connections
+                .south
+                .as_ref()
+                .map(|connection| connection.into()),
+            west: connections
+                .west
+                .as_ref()
+                .map(|connection| connection.into()),
+            east: connections
+                .east
+                .as_ref()
+                .map(|connection| connection.into()),
+        }
+    }
+
+    pub fn get_objects_pointer(&self) -> u16 {
+        self.data.attributes.objects_pointer
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapHeaderRequestType {
+    map_headers,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapHeaderRequestAttributes {
+    pub tileset_id: u8,
+    pub height: u8,
+    pub width: u8,
+    pub blocks_pointer: u16,
+    pub text_pointer: u16,
+    pub script_pointer: u16,
+    pub connections: MapHeaderRequestAttributesConnections,
+    pub objects_pointer: u16,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapHeaderRequestAttributesConnections {
+    #[serde(default)]
+    pub north: Option<MapHeaderRequestAttributesConnection>,
+
+    #[serde(default)]
+    pub south: Option<MapHeaderRequestAttributesConnection>,
+
+    #[serde(default)]
+    pub west: Option<MapHeaderRequestAttributesConnection>,
+
+    #[serde(default)]
+    pub east: Option<MapHeaderRequestAttributesConnection>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapHeaderRequestAttributesConnection {
+    pub map_id: u8,
+    pub source_pointer: u16,
+    pub destination_pointer: u16,
+    pub length: u8,
+    pub width: u8,
+    pub y_alignment: u8,
+    pub x_alignment: u8,
+    pub window_pointer: u16,
+}
+
+impl From<&MapHeaderRequestAttributesConnection> for MapConnection {
+    fn from(connection: &MapHeaderRequestAttributesConnection) -> Self {
+        MapConnection {
+            map_id: connection.map_id,
+            source_pointer: connection.source_pointer,
+            destination_pointer: connection.destination_pointer,
+            length: connection.length,
+            width: connection.width,
+            y_alignment: connection.y_alignment,
+            x_alignment: connection.x_alignment,
+            window_pointer: connection.window_pointer,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use pkmnapi_db::HiddenObject;
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type MapHiddenObjectsRequest =
+    BaseRequest<MapHiddenObjectsRequestType, MapHiddenObjectsRequestAttributes>;
+
+impl MapHiddenObjectsRequest {
+    pub fn get_hidden_objects(&self) -> Vec<HiddenObject> {
+        self.data
+            .attributes
+            .hidden_objects
+            .iter()
+            .map(|hidden_object| HiddenObject {
+                x: hidden_object.x,
+                y: hidden_object.y,
+                item_id: hidden_object.item_id,
+                routine_bank_id: hidden_object.routine_bank_id,
+                routine_pointer: hidden_object.routine_pointer,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapHiddenObjectsRequestType {
+    map_hidden_objects,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapHiddenObjectsRequestAttributes {
+    pub hidden_objects: Vec<MapHiddenObjectsRequestAttributesHiddenObject>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapHiddenObjectsRequestAttributesHiddenObject {
+    pub x: u8,
+    pub y: u8,
+    pub item_id: u8,
+    pub routine_bank_id: u8,
+    pub routine_pointer: u16,
+}
@@ -0,0 +1,204 @@
+use pkmnapi_db::{MapObject, MapObjectPokemon, MapObjectTrainer, MapObjectType, MapSign, MapWarp};
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type MapObjectsRequest = BaseRequest<MapObjectsRequestType, MapObjectsRequestAttributes>;
+
+impl MapObjectsRequest {
+    pub fn get_border_block(&self) -> u8 {
+        self.data.attributes.border_block
+    }
+
+    pub fn get_warps(&self) -> Vec<MapWarp> {
+        self.data
+            .attributes
+            .warps
+            .iter()
+            .map(|warp| MapWarp {
+                x: warp.x,
+                y: warp.y,
+                destination_warp_id: warp.destination_warp_id,
+                destination_map_id: warp.destination_map_id,
+            })
+            .collect()
+    }
+
+    pub fn get_signs(&self) -> Vec<MapSign> {
+        self.data
+            .attributes
+            .signs
+            .iter()
+            .map(|sign| MapSign {
+                x: sign.x,
+                y: sign.y,
+                text_id: sign.text_id,
+            })
+            .collect()
+    }
+
+    pub fn get_objects(&self) -> Vec<MapObject> {
+        self.data
+            .attributes
+            .objects
+            .iter()
+            .map(|map_object| match map_object {
+                object::NPC(object) => MapObject {
+                    sprite_id: object.sprite_id,
+                    x: object.x,
+                    y: object.y,
+                    movement: object.movement,
+                    direction: object.direction,
+                    text_id: object.text_id,
+                    object_type: MapObjectType::NPC,
+                },
+                object::ITEM(object) => MapObject {
+                    sprite_id: object.sprite_id,
+                    x: object.x,
+                    y: object.y,
+                    movement: object.movement,
+                    direction: object.direction,
+                    text_id: object.text_id,
+                    object_type: MapObjectType::ITEM(object.item_id),
+                },
+                object::TRAINER(object) => MapObject {
+                    sprite_id: object.sprite_id,
+                    x: object.x,
+                    y: object.y,
+                    movement: object.movement,
+                    direction: object.direction,
+                    text_id: object.text_id,
+                    object_type: MapObjectType::TRAINER(MapObjectTrainer {
+                        trainer_id: object.trainer_id,
+                        party_id: object.party_id,
+                    }),
+                },
+                object::POKEMON(object) => MapObject {
+                    sprite_id: object.sprite_id,
+                    x: object.x,
+                    y: object.y,
+                    movement: object.movement,
+                    direction: object.direction,
+                    text_id: object.text_id,
+                    object_type: MapObjectType::POKEMON(MapObjectPokemon::new(
+                        object.pokedex_id,
+                        object.level,
+                    )),
+                },
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapObjectsRequestType {
+    map_objects,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributes {
+    pub border_block: u8,
+    pub warps: Vec<MapObjectsRequestAttributesWarp>,
+    pub signs: Vec<MapObjectsRequestAttributesSign>,
+    pub objects: Vec<object>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributesWarp {
+    pub x: u8,
+    pub y: u8,
+    pub destination_warp_id: u8,
+    pub destination_map_id: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributesSign {
+    pub x: u8,
+    pub y: u8,
+    pub text_id: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+#[serde(untagged)]
+pub enum object {
+    NPC(MapObjectsRequestAttributesObjectNPC),
+    ITEM(MapObjectsRequestAttributesObjectItem),
+    TRAINER(MapObjectsRequestAttributesObjectTrainer),
+    POKEMON(MapObjectsRequestAttributesObjectPokemon),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributesObjectNPC {
+    pub object_type: MapObjectsRequestAttributesObjectNPCType,
+    pub sprite_id: u8,
+    pub x: u8,
+    pub y: u8,
+    pub movement: u8,
+    pub direction: u8,
+    pub text_id: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapObjectsRequestAttributesObjectNPCType {
+    npc,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributesObjectItem {
+    pub object_type: MapObjectsRequestAttributesObjectItemType,
+    pub sprite_id: u8,
+    pub x: u8,
+    pub y: u8,
+    pub movement: u8,
+    pub direction: u8,
+    pub text_id: u8,
+    pub item_id: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapObjectsRequestAttributesObjectItemType {
+    item,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributesObjectTrainer {
+    pub object_type: MapObjectsRequestAttributesObjectTrainerType,
+    pub sprite_id: u8,
+    pub x: u8,
+    pub y: u8,
+    pub movement: u8,
+    pub direction: u8,
+    pub text_id: u8,
+    pub trainer_id: u8,
+    pub party_id: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapObjectsRequestAttributesObjectTrainerType {
+    trainer,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MapObjectsRequestAttributesObjectPokemon {
+    pub object_type: MapObjectsRequestAttributesObjectPokemonType,
+    pub sprite_id: u8,
+    pub x: u8,
+    pub y: u8,
+    pub movement: u8,
+    pub direction: u8,
+    pub text_id: u8,
+    pub pokedex_id: u8,
+    pub level: u8,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapObjectsRequestAttributesObjectPokemonType {
+    pokemon,
+}
@@ -1,7 +1,12 @@
 pub mod access_tokens;
 pub mod base;
+pub mod battle_damage;
 pub mod hm_moves;
 pub mod item_names;
+pub mod map_blocks;
+pub mod map_headers;
+pub mod map_hidden_objects;
+pub mod map_objects;
 pub mod map_pokemon;
 pub mod mart_items;
 pub mod move_names;
@@ -17,9 +22,24 @@ pub mod pokemon_machines;
 pub mod pokemon_movesets;
 pub mod pokemon_names;
 pub mod pokemon_stats;
+pub mod sav_badges;
+pub mod sav_bag_items;
+pub mod sav_box_items;
+pub mod sav_coins;
+pub mod sav_current_box;
+pub mod sav_money;
+pub mod sav_options;
+pub mod sav_player_ids;
 pub mod sav_player_names;
+pub mod sav_pokedex_owned;
+pub mod sav_pokedex_seen;
+pub mod sav_rival_names;
+pub mod starters;
+pub mod texts;
+pub mod tilesets;
 pub mod tm_moves;
 pub mod tm_prices;
+pub mod town_map_locations;
 pub mod trades;
 pub mod trainer_names;
 pub mod trainer_parties;
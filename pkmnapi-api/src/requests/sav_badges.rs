@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavBadgesRequest = BaseRequest<SavBadgesRequestType, SavBadgesRequestAttributes>;
+
+impl SavBadgesRequest {
+    pub fn get_badges(&self) -> &Vec<u8> {
+        &self.data.attributes.badges
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavBadgesRequestType {
+    sav_badges,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavBadgesRequestAttributes {
+    pub badges: Vec<u8>,
+}
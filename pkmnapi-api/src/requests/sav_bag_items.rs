@@ -0,0 +1,51 @@
+use pkmnapi_db::sav::SaveItem;
+use pkmnapi_db::MartItem;
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+use crate::requests::mart_items::{
+    MartItemsRequestAttributesItem, MartItemsRequestAttributesItemType,
+};
+
+pub type SavBagItemsRequest = BaseRequest<SavBagItemsRequestType, SavBagItemsRequestAttributes>;
+
+impl SavBagItemsRequest {
+    pub fn get_bag_items(&self) -> Vec<SaveItem> {
+        self.data
+            .attributes
+            .bag_items
+            .iter()
+            .map(|bag_item| {
+                let item = match bag_item.item._type {
+                    MartItemsRequestAttributesItemType::item_names => {
+                        MartItem::ITEM(bag_item.item.id)
+                    }
+                    MartItemsRequestAttributesItemType::tm_names => MartItem::TM(bag_item.item.id),
+                };
+
+                SaveItem {
+                    item_id: item.to_raw()[0],
+                    amount: bag_item.amount,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavBagItemsRequestType {
+    sav_bag_items,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavBagItemsRequestAttributes {
+    pub bag_items: Vec<SavBagItemsRequestAttributesItem>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavBagItemsRequestAttributesItem {
+    pub amount: u8,
+    pub item: MartItemsRequestAttributesItem,
+}
@@ -0,0 +1,51 @@
+use pkmnapi_db::sav::SaveItem;
+use pkmnapi_db::MartItem;
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+use crate::requests::mart_items::{
+    MartItemsRequestAttributesItem, MartItemsRequestAttributesItemType,
+};
+
+pub type SavBoxItemsRequest = BaseRequest<SavBoxItemsRequestType, SavBoxItemsRequestAttributes>;
+
+impl SavBoxItemsRequest {
+    pub fn get_box_items(&self) -> Vec<SaveItem> {
+        self.data
+            .attributes
+            .box_items
+            .iter()
+            .map(|box_item| {
+                let item = match box_item.item._type {
+                    MartItemsRequestAttributesItemType::item_names => {
+                        MartItem::ITEM(box_item.item.id)
+                    }
+                    MartItemsRequestAttributesItemType::tm_names => MartItem::TM(box_item.item.id),
+                };
+
+                SaveItem {
+                    item_id: item.to_raw()[0],
+                    amount: box_item.amount,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavBoxItemsRequestType {
+    sav_box_items,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavBoxItemsRequestAttributes {
+    pub box_items: Vec<SavBoxItemsRequestAttributesItem>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavBoxItemsRequestAttributesItem {
+    pub amount: u8,
+    pub item: MartItemsRequestAttributesItem,
+}
@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavCoinsRequest = BaseRequest<SavCoinsRequestType, SavCoinsRequestAttributes>;
+
+impl SavCoinsRequest {
+    pub fn get_coins(&self) -> u16 {
+        self.data.attributes.coins
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavCoinsRequestType {
+    sav_coins,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavCoinsRequestAttributes {
+    pub coins: u16,
+}
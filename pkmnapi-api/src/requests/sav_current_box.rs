@@ -0,0 +1,24 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavCurrentBoxRequest =
+    BaseRequest<SavCurrentBoxRequestType, SavCurrentBoxRequestAttributes>;
+
+impl SavCurrentBoxRequest {
+    pub fn get_current_box(&self) -> u8 {
+        self.data.attributes.current_box
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavCurrentBoxRequestType {
+    sav_current_box,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavCurrentBoxRequestAttributes {
+    pub current_box: u8,
+}
@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavMoneyRequest = BaseRequest<SavMoneyRequestType, SavMoneyRequestAttributes>;
+
+impl SavMoneyRequest {
+    pub fn get_money(&self) -> u32 {
+        self.data.attributes.money
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavMoneyRequestType {
+    sav_money,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavMoneyRequestAttributes {
+    pub money: u32,
+}
@@ -0,0 +1,64 @@
+use pkmnapi_db::sav::*;
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavOptionsRequest = BaseRequest<SavOptionsRequestType, SavOptionsRequestAttributes>;
+
+impl SavOptionsRequest {
+    pub fn get_options(&self) -> SaveOptions {
+        let attributes = &self.data.attributes;
+
+        SaveOptions {
+            text_speed: match attributes.text_speed {
+                SavOptionsRequestAttributesTextSpeed::fast => SaveTextSpeed::FAST,
+                SavOptionsRequestAttributesTextSpeed::medium => SaveTextSpeed::MEDIUM,
+                SavOptionsRequestAttributesTextSpeed::slow => SaveTextSpeed::SLOW,
+            },
+            battle_animation: match attributes.battle_animation {
+                SavOptionsRequestAttributesBattleAnimation::on => SaveBattleAnimation::ON,
+                SavOptionsRequestAttributesBattleAnimation::off => SaveBattleAnimation::OFF,
+            },
+            battle_style: match attributes.battle_style {
+                SavOptionsRequestAttributesBattleStyle::shift => SaveBattleStyle::SHIFT,
+                SavOptionsRequestAttributesBattleStyle::set => SaveBattleStyle::SET,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsRequestType {
+    sav_options,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavOptionsRequestAttributes {
+    pub text_speed: SavOptionsRequestAttributesTextSpeed,
+    pub battle_animation: SavOptionsRequestAttributesBattleAnimation,
+    pub battle_style: SavOptionsRequestAttributesBattleStyle,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsRequestAttributesTextSpeed {
+    fast,
+    medium,
+    slow,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsRequestAttributesBattleAnimation {
+    on,
+    off,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsRequestAttributesBattleStyle {
+    shift,
+    set,
+}
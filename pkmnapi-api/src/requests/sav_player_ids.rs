@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavPlayerIDRequest = BaseRequest<SavPlayerIDRequestType, SavPlayerIDRequestAttributes>;
+
+impl SavPlayerIDRequest {
+    pub fn get_player_id(&self) -> u16 {
+        self.data.attributes.player_id
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavPlayerIDRequestType {
+    sav_player_ids,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavPlayerIDRequestAttributes {
+    pub player_id: u16,
+}
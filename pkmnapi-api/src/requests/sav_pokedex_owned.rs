@@ -0,0 +1,35 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavPokedexOwnedRequest =
+    BaseRequest<SavPokedexOwnedRequestType, SavPokedexOwnedRequestAttributes>;
+
+impl SavPokedexOwnedRequest {
+    pub fn get_pokedex_ids(&self) -> Vec<u8> {
+        self.data
+            .attributes
+            .pokemon
+            .iter()
+            .map(|pokemon| pokemon.id)
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavPokedexOwnedRequestType {
+    sav_pokedex_owned,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavPokedexOwnedRequestAttributes {
+    pub pokemon: Vec<SavPokedexOwnedRequestAttributesPokemon>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavPokedexOwnedRequestAttributesPokemon {
+    #[serde(deserialize_with = "crate::utils::from_numeric_str")]
+    pub id: u8,
+}
@@ -0,0 +1,35 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavPokedexSeenRequest =
+    BaseRequest<SavPokedexSeenRequestType, SavPokedexSeenRequestAttributes>;
+
+impl SavPokedexSeenRequest {
+    pub fn get_pokedex_ids(&self) -> Vec<u8> {
+        self.data
+            .attributes
+            .pokemon
+            .iter()
+            .map(|pokemon| pokemon.id)
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavPokedexSeenRequestType {
+    sav_pokedex_seen,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavPokedexSeenRequestAttributes {
+    pub pokemon: Vec<SavPokedexSeenRequestAttributesPokemon>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavPokedexSeenRequestAttributesPokemon {
+    #[serde(deserialize_with = "crate::utils::from_numeric_str")]
+    pub id: u8,
+}
@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type SavRivalNameRequest = BaseRequest<SavRivalNameRequestType, SavRivalNameRequestAttributes>;
+
+impl SavRivalNameRequest {
+    pub fn get_name(&self) -> &String {
+        &self.data.attributes.name
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavRivalNameRequestType {
+    sav_rival_names,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SavRivalNameRequestAttributes {
+    pub name: String,
+}
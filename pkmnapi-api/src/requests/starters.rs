@@ -0,0 +1,33 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type StartersRequest = BaseRequest<StartersRequestType, StartersRequestAttributes>;
+
+impl StartersRequest {
+    pub fn get_starters(&self) -> Vec<u8> {
+        self.data
+            .attributes
+            .pokemon
+            .iter()
+            .map(|pokemon| pokemon.pokedex_id)
+            .collect()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum StartersRequestType {
+    starters,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StartersRequestAttributes {
+    pub pokemon: Vec<StartersRequestAttributesPokemon>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StartersRequestAttributesPokemon {
+    pub pokedex_id: u8,
+}
@@ -0,0 +1,23 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type TextRequest = BaseRequest<TextRequestType, TextRequestAttributes>;
+
+impl TextRequest {
+    pub fn get_text(&self) -> &String {
+        &self.data.attributes.text
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum TextRequestType {
+    texts,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TextRequestAttributes {
+    pub text: String,
+}
@@ -0,0 +1,40 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type TilesetRequest = BaseRequest<TilesetRequestType, TilesetRequestAttributes>;
+
+impl TilesetRequest {
+    pub fn get_blocks(&self) -> Vec<Vec<u8>> {
+        self.data.attributes.blocks.to_vec()
+    }
+
+    pub fn get_collision_tiles(&self) -> Vec<u8> {
+        self.data.attributes.collision_tiles.to_vec()
+    }
+
+    pub fn get_counter_tiles(&self) -> Vec<u8> {
+        self.data.attributes.counter_tiles.to_vec()
+    }
+
+    pub fn get_grass_tile(&self) -> Option<u8> {
+        self.data.attributes.grass_tile
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum TilesetRequestType {
+    tilesets,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TilesetRequestAttributes {
+    pub blocks: Vec<Vec<u8>>,
+    pub collision_tiles: Vec<u8>,
+    pub counter_tiles: Vec<u8>,
+
+    #[serde(default)]
+    pub grass_tile: Option<u8>,
+}
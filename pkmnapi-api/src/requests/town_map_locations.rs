@@ -0,0 +1,39 @@
+use rocket_okapi::JsonSchema;
+use serde::Deserialize;
+
+use crate::requests::base::BaseRequest;
+
+pub type TownMapLocationRequest =
+    BaseRequest<TownMapLocationRequestType, TownMapLocationRequestAttributes>;
+
+impl TownMapLocationRequest {
+    pub fn get_name(&self) -> &String {
+        &self.data.attributes.name
+    }
+
+    pub fn get_x(&self) -> u8 {
+        self.data.attributes.x
+    }
+
+    pub fn get_y(&self) -> u8 {
+        self.data.attributes.y
+    }
+
+    pub fn get_map_ids(&self) -> Vec<u8> {
+        self.data.attributes.map_ids.to_vec()
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum TownMapLocationRequestType {
+    town_map_locations,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TownMapLocationRequestAttributes {
+    pub name: String,
+    pub x: u8,
+    pub y: u8,
+    pub map_ids: Vec<u8>,
+}
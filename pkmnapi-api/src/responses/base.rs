@@ -27,10 +27,15 @@ pub struct BaseResponseAll<T> {
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 #[allow(non_camel_case_types)]
 pub enum BaseResponseType {
+    battle_damage,
     hm_moves,
     hm_names,
     icons,
     item_names,
+    map_blocks,
+    map_headers,
+    map_hidden_objects,
+    map_objects,
     map_pokemon,
     mart_items,
     move_names,
@@ -45,14 +50,31 @@ pub enum BaseResponseType {
     pokemon_machines,
     pokemon_movesets,
     pokemon_names,
+    pokemon_pics,
     pokemon_stats,
     rom_patches,
     roms,
+    sav_badges,
+    sav_bag_items,
+    sav_box_items,
+    sav_coins,
+    sav_current_box,
+    sav_money,
+    sav_options,
+    sav_patches,
+    sav_player_ids,
     sav_player_names,
+    sav_pokedex_owned,
+    sav_pokedex_seen,
+    sav_rival_names,
     savs,
+    starters,
+    texts,
+    tilesets,
     tm_moves,
     tm_names,
     tm_prices,
+    town_map_locations,
     trades,
     trainer_names,
     trainer_parties,
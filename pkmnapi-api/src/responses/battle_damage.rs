@@ -0,0 +1,59 @@
+use pkmnapi_db::battle::{BattleDamage, DamageRange};
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type BattleDamageResponse = BaseResponse<BattleDamageResponseAttributes>;
+
+impl BattleDamageResponse {
+    pub fn new(move_id: &u8, battle_damage: &BattleDamage) -> BattleDamageResponse {
+        BattleDamageResponse {
+            data: BaseResponseData {
+                id: move_id.to_string(),
+                _type: BaseResponseType::battle_damage,
+                attributes: BattleDamageResponseAttributes {
+                    damage: BattleDamageResponseAttributesRange::new(&battle_damage.damage),
+                    critical_damage: BattleDamageResponseAttributesRange::new(
+                        &battle_damage.critical_damage,
+                    ),
+                    critical_chance: battle_damage.critical_chance,
+                    stab: battle_damage.stab,
+                    type_multiplier: battle_damage.type_multiplier,
+                },
+                links: Links {
+                    _self: utils::generate_url("battle/damage", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("battle/damage", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BattleDamageResponseAttributes {
+    pub damage: BattleDamageResponseAttributesRange,
+    pub critical_damage: BattleDamageResponseAttributesRange,
+    pub critical_chance: f32,
+    pub stab: bool,
+    pub type_multiplier: f32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BattleDamageResponseAttributesRange {
+    pub min: u16,
+    pub max: u16,
+}
+
+impl BattleDamageResponseAttributesRange {
+    pub fn new(damage_range: &DamageRange) -> Self {
+        BattleDamageResponseAttributesRange {
+            min: damage_range.min,
+            max: damage_range.max,
+        }
+    }
+}
@@ -64,7 +64,13 @@ pub enum ResponseError {
 
 impl From<pkmnapi_db::error::Error> for ResponseError {
     fn from(err: pkmnapi_db::error::Error) -> Self {
-        NotFoundError::new(BaseErrorResponseId::error_not_found, Some(err.to_string()))
+        match err {
+            pkmnapi_db::error::Error::StringCharactersInvalid(_) => BadRequestError::new(
+                BaseErrorResponseId::error_strings_invalid,
+                Some(err.to_string()),
+            ),
+            _ => NotFoundError::new(BaseErrorResponseId::error_not_found, Some(err.to_string())),
+        }
     }
 }
 
@@ -102,6 +108,7 @@ pub enum BaseErrorResponseId {
     error_access_tokens_invalid,
     error_access_tokens_timeout,
     error_access_tokens_unauthorized,
+    error_battle_damage_invalid,
     error_etag_mismatch,
     error_etag_missing,
     error_hms_invalid,
@@ -110,6 +117,14 @@ pub enum BaseErrorResponseId {
     error_internal_server,
     error_item_names_invalid,
     error_item_names,
+    error_map_blocks_invalid,
+    error_map_blocks,
+    error_map_headers_invalid,
+    error_map_headers,
+    error_map_hidden_objects_invalid,
+    error_map_hidden_objects,
+    error_map_objects_invalid,
+    error_map_objects,
     error_map_pics,
     error_map_pokemon_invalid,
     error_map_pokemon,
@@ -145,20 +160,53 @@ pub enum BaseErrorResponseId {
     error_pokemon_stats_invalid,
     error_pokemon_stats,
     error_rom_patches,
+    error_rom_patches_invalid,
     error_roms_invalid_rom,
     error_roms_no_rom,
     error_roms_rom_exists,
+    error_sav_badges_invalid,
+    error_sav_badges,
+    error_sav_bag_items_invalid,
+    error_sav_bag_items,
+    error_sav_box_items_invalid,
+    error_sav_box_items,
+    error_sav_coins_invalid,
+    error_sav_coins,
+    error_sav_current_box_invalid,
+    error_sav_current_box,
+    error_sav_money_invalid,
+    error_sav_money,
+    error_sav_options_invalid,
+    error_sav_options,
+    error_sav_patches,
+    error_sav_player_ids_invalid,
+    error_sav_player_ids,
     error_sav_player_names_invalid,
     error_sav_player_names,
+    error_sav_pokedex_owned_invalid,
+    error_sav_pokedex_owned,
+    error_sav_pokedex_seen_invalid,
+    error_sav_pokedex_seen,
+    error_sav_rival_names_invalid,
+    error_sav_rival_names,
     error_savs_invalid_sav,
     error_savs_no_sav,
     error_savs_sav_exists,
+    error_starters_invalid,
+    error_starters,
+    error_strings_invalid,
+    error_texts_invalid,
+    error_texts,
+    error_tilesets_invalid,
+    error_tilesets,
     error_tm_moves_invalid,
     error_tm_moves,
     error_tm_prices_invalid,
     error_tm_prices,
     error_too_many_requests,
     error_town_map_imgs,
+    error_town_map_locations_invalid,
+    error_town_map_locations,
     error_trades_invalid,
     error_trades,
     error_trainer_names_invalid,
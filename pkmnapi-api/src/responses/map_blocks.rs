@@ -0,0 +1,40 @@
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type MapBlocksResponse = BaseResponse<MapBlocksResponseAttributes>;
+pub type MapBlocksResponseData = BaseResponseData<MapBlocksResponseAttributes>;
+
+impl MapBlocksResponse {
+    pub fn new(map_id: &u8, map_blocks: &Vec<Vec<u8>>) -> MapBlocksResponse {
+        MapBlocksResponse {
+            data: MapBlocksResponseData::new(map_id, map_blocks),
+            links: Links {
+                _self: utils::generate_url("maps/blocks", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+impl MapBlocksResponseData {
+    pub fn new(map_id: &u8, map_blocks: &Vec<Vec<u8>>) -> MapBlocksResponseData {
+        BaseResponseData {
+            id: map_id.to_string(),
+            _type: BaseResponseType::map_blocks,
+            attributes: MapBlocksResponseAttributes {
+                blocks: map_blocks.to_vec(),
+            },
+            links: Links {
+                _self: utils::generate_url("maps/blocks", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapBlocksResponseAttributes {
+    pub blocks: Vec<Vec<u8>>,
+}
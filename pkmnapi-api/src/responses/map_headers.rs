@@ -0,0 +1,132 @@
+use pkmnapi_db::{MapConnection, MapHeader};
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseAll, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type MapHeaderResponse = BaseResponse<MapHeaderResponseAttributes>;
+pub type MapHeaderResponseData = BaseResponseData<MapHeaderResponseAttributes>;
+pub type MapHeaderResponseAll = BaseResponseAll<MapHeaderResponseData>;
+
+impl MapHeaderResponseAll {
+    pub fn new(map_ids: &Vec<u8>, map_headers: &HashMap<u8, MapHeader>) -> MapHeaderResponseAll {
+        MapHeaderResponseAll {
+            data: map_ids
+                .iter()
+                .map(|map_id| MapHeaderResponseData::new(map_id, map_headers.get(&map_id).unwrap()))
+                .collect(),
+            links: Links {
+                _self: utils::generate_url("maps/headers", None),
+            },
+        }
+    }
+}
+
+impl MapHeaderResponse {
+    pub fn new(map_id: &u8, map_header: &MapHeader) -> MapHeaderResponse {
+        MapHeaderResponse {
+            data: MapHeaderResponseData::new(map_id, map_header),
+            links: Links {
+                _self: utils::generate_url("maps/headers", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+impl MapHeaderResponseData {
+    pub fn new(map_id: &u8, map_header: &MapHeader) -> MapHeaderResponseData {
+        let connections = &map_header.connections;
+
+        BaseResponseData {
+            id: map_id.to_string(),
+            _type: BaseResponseType::map_headers,
+            attributes: MapHeaderResponseAttributes {
+                tileset_id: map_header.tileset_id,
+                height: map_header.height,
+                width: map_header.width,
+                blocks_pointer: map_header.blocks_pointer,
+                text_pointer: map_header.text_pointer,
+                script_pointer: map_header.script_pointer,
+                connections: MapHeaderResponseAttributesConnections {
+                    north: connections
+                        .north
+                        .as_ref()
+                        .map(|connection| connection.into()),
+                    south: connections
+                        .south
+                        .as_ref()
+                        .map(|connection| connection.into()),
+                    west: connections
+                        .west
+                        .as_ref()
+                        .map(|connection| connection.into()),
+                    east: connections
+                        .east
+                        .as_ref()
+                        .map(|connection| connection.into()),
+                },
+                objects_pointer: map_header.objects_pointer,
+            },
+            links: Links {
+                _self: utils::generate_url("maps/headers", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapHeaderResponseAttributes {
+    pub tileset_id: u8,
+    pub height: u8,
+    pub width: u8,
+    pub blocks_pointer: u16,
+    pub text_pointer: u16,
+    pub script_pointer: u16,
+    pub connections: MapHeaderResponseAttributesConnections,
+    pub objects_pointer: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapHeaderResponseAttributesConnections {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub north: Option<MapHeaderResponseAttributesConnection>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub south: Option<MapHeaderResponseAttributesConnection>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub west: Option<MapHeaderResponseAttributesConnection>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub east: Option<MapHeaderResponseAttributesConnection>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapHeaderResponseAttributesConnection {
+    pub map_id: u8,
+    pub source_pointer: u16,
+    pub destination_pointer: u16,
+    pub length: u8,
+    pub width: u8,
+    pub y_alignment: u8,
+    pub x_alignment: u8,
+    pub window_pointer: u16,
+}
+
+impl From<&MapConnection> for MapHeaderResponseAttributesConnection {
+    fn from(connection: &MapConnection) -> Self {
+        MapHeaderResponseAttributesConnection {
+            map_id: connection.map_id,
+            source_pointer: connection.source_pointer,
+            destination_pointer: connection.destination_pointer,
+            length: connection.length,
+            width: connection.width,
+            y_alignment: connection.y_alignment,
+            x_alignment: connection.x_alignment,
+            window_pointer: connection.window_pointer,
+        }
+    }
+}
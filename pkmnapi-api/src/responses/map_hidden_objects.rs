@@ -0,0 +1,82 @@
+use pkmnapi_db::HiddenObject;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseAll, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type MapHiddenObjectsResponse = BaseResponse<MapHiddenObjectsResponseAttributes>;
+pub type MapHiddenObjectsResponseData = BaseResponseData<MapHiddenObjectsResponseAttributes>;
+pub type MapHiddenObjectsResponseAll = BaseResponseAll<MapHiddenObjectsResponseData>;
+
+impl MapHiddenObjectsResponseAll {
+    pub fn new(
+        map_ids: &Vec<u8>,
+        hidden_objects: &HashMap<u8, Vec<HiddenObject>>,
+    ) -> MapHiddenObjectsResponseAll {
+        MapHiddenObjectsResponseAll {
+            data: map_ids
+                .iter()
+                .map(|map_id| {
+                    MapHiddenObjectsResponseData::new(map_id, hidden_objects.get(&map_id).unwrap())
+                })
+                .collect(),
+            links: Links {
+                _self: utils::generate_url("maps/hidden_objects", None),
+            },
+        }
+    }
+}
+
+impl MapHiddenObjectsResponse {
+    pub fn new(map_id: &u8, hidden_objects: &Vec<HiddenObject>) -> MapHiddenObjectsResponse {
+        MapHiddenObjectsResponse {
+            data: MapHiddenObjectsResponseData::new(map_id, hidden_objects),
+            links: Links {
+                _self: utils::generate_url("maps/hidden_objects", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+impl MapHiddenObjectsResponseData {
+    pub fn new(map_id: &u8, hidden_objects: &Vec<HiddenObject>) -> MapHiddenObjectsResponseData {
+        BaseResponseData {
+            id: map_id.to_string(),
+            _type: BaseResponseType::map_hidden_objects,
+            attributes: MapHiddenObjectsResponseAttributes {
+                hidden_objects: hidden_objects
+                    .iter()
+                    .map(
+                        |hidden_object| MapHiddenObjectsResponseAttributesHiddenObject {
+                            x: hidden_object.x,
+                            y: hidden_object.y,
+                            item_id: hidden_object.item_id,
+                            routine_bank_id: hidden_object.routine_bank_id,
+                            routine_pointer: hidden_object.routine_pointer,
+                        },
+                    )
+                    .collect(),
+            },
+            links: Links {
+                _self: utils::generate_url("maps/hidden_objects", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapHiddenObjectsResponseAttributes {
+    pub hidden_objects: Vec<MapHiddenObjectsResponseAttributesHiddenObject>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapHiddenObjectsResponseAttributesHiddenObject {
+    pub x: u8,
+    pub y: u8,
+    pub item_id: u8,
+    pub routine_bank_id: u8,
+    pub routine_pointer: u16,
+}
@@ -0,0 +1,176 @@
+use pkmnapi_db::{MapObject, MapObjectType, MapObjects};
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseAll, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type MapObjectsResponse = BaseResponse<MapObjectsResponseAttributes>;
+pub type MapObjectsResponseData = BaseResponseData<MapObjectsResponseAttributes>;
+pub type MapObjectsResponseAll = BaseResponseAll<MapObjectsResponseData>;
+
+impl MapObjectsResponseAll {
+    pub fn new(map_ids: &Vec<u8>, map_objects: &HashMap<u8, MapObjects>) -> MapObjectsResponseAll {
+        MapObjectsResponseAll {
+            data: map_ids
+                .iter()
+                .map(|map_id| {
+                    MapObjectsResponseData::new(map_id, map_objects.get(&map_id).unwrap())
+                })
+                .collect(),
+            links: Links {
+                _self: utils::generate_url("maps/objects", None),
+            },
+        }
+    }
+}
+
+impl MapObjectsResponse {
+    pub fn new(map_id: &u8, map_objects: &MapObjects) -> MapObjectsResponse {
+        MapObjectsResponse {
+            data: MapObjectsResponseData::new(map_id, map_objects),
+            links: Links {
+                _self: utils::generate_url("maps/objects", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+impl MapObjectsResponseData {
+    pub fn new(map_id: &u8, map_objects: &MapObjects) -> MapObjectsResponseData {
+        BaseResponseData {
+            id: map_id.to_string(),
+            _type: BaseResponseType::map_objects,
+            attributes: MapObjectsResponseAttributes {
+                border_block: map_objects.border_block,
+                warps: map_objects
+                    .warps
+                    .iter()
+                    .map(|warp| MapObjectsResponseAttributesWarp {
+                        x: warp.x,
+                        y: warp.y,
+                        destination_warp_id: warp.destination_warp_id,
+                        destination_map_id: warp.destination_map_id,
+                    })
+                    .collect(),
+                signs: map_objects
+                    .signs
+                    .iter()
+                    .map(|sign| MapObjectsResponseAttributesSign {
+                        x: sign.x,
+                        y: sign.y,
+                        text_id: sign.text_id,
+                    })
+                    .collect(),
+                objects: map_objects
+                    .objects
+                    .iter()
+                    .map(|object| MapObjectsResponseAttributesObject::new(object))
+                    .collect(),
+            },
+            links: Links {
+                _self: utils::generate_url("maps/objects", Some(&map_id.to_string())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapObjectsResponseAttributes {
+    pub border_block: u8,
+    pub warps: Vec<MapObjectsResponseAttributesWarp>,
+    pub signs: Vec<MapObjectsResponseAttributesSign>,
+    pub objects: Vec<MapObjectsResponseAttributesObject>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapObjectsResponseAttributesWarp {
+    pub x: u8,
+    pub y: u8,
+    pub destination_warp_id: u8,
+    pub destination_map_id: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapObjectsResponseAttributesSign {
+    pub x: u8,
+    pub y: u8,
+    pub text_id: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MapObjectsResponseAttributesObject {
+    pub object_type: MapObjectsResponseAttributesObjectType,
+    pub sprite_id: u8,
+    pub x: u8,
+    pub y: u8,
+    pub movement: u8,
+    pub direction: u8,
+    pub text_id: u8,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_id: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trainer_id: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub party_id: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pokedex_id: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<u8>,
+}
+
+impl MapObjectsResponseAttributesObject {
+    pub fn new(object: &MapObject) -> Self {
+        let response = MapObjectsResponseAttributesObject {
+            object_type: MapObjectsResponseAttributesObjectType::npc,
+            sprite_id: object.sprite_id,
+            x: object.x,
+            y: object.y,
+            movement: object.movement,
+            direction: object.direction,
+            text_id: object.text_id,
+            item_id: None,
+            trainer_id: None,
+            party_id: None,
+            pokedex_id: None,
+            level: None,
+        };
+
+        match &object.object_type {
+            MapObjectType::NPC => response,
+            MapObjectType::ITEM(item_id) => MapObjectsResponseAttributesObject {
+                object_type: MapObjectsResponseAttributesObjectType::item,
+                item_id: Some(*item_id),
+                ..response
+            },
+            MapObjectType::TRAINER(trainer) => MapObjectsResponseAttributesObject {
+                object_type: MapObjectsResponseAttributesObjectType::trainer,
+                trainer_id: Some(trainer.trainer_id),
+                party_id: Some(trainer.party_id),
+                ..response
+            },
+            MapObjectType::POKEMON(pokemon) => MapObjectsResponseAttributesObject {
+                object_type: MapObjectsResponseAttributesObjectType::pokemon,
+                pokedex_id: Some(pokemon.pokedex_id),
+                level: Some(pokemon.level),
+                ..response
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum MapObjectsResponseAttributesObjectType {
+    npc,
+    item,
+    trainer,
+    pokemon,
+}
@@ -1,9 +1,14 @@
 pub mod base;
+pub mod battle_damage;
 pub mod errors;
 pub mod hm_moves;
 pub mod hm_names;
 pub mod item_names;
 pub mod links;
+pub mod map_blocks;
+pub mod map_headers;
+pub mod map_hidden_objects;
+pub mod map_objects;
 pub mod map_pokemon;
 pub mod mart_items;
 pub mod move_names;
@@ -18,14 +23,31 @@ pub mod pokemon_learnsets;
 pub mod pokemon_machines;
 pub mod pokemon_movesets;
 pub mod pokemon_names;
+pub mod pokemon_pics;
 pub mod pokemon_stats;
 pub mod rom_patches;
 pub mod roms;
+pub mod sav_badges;
+pub mod sav_bag_items;
+pub mod sav_box_items;
+pub mod sav_coins;
+pub mod sav_current_box;
+pub mod sav_money;
+pub mod sav_options;
+pub mod sav_patches;
+pub mod sav_player_ids;
 pub mod sav_player_names;
+pub mod sav_pokedex_owned;
+pub mod sav_pokedex_seen;
+pub mod sav_rival_names;
 pub mod savs;
+pub mod starters;
+pub mod texts;
+pub mod tilesets;
 pub mod tm_moves;
 pub mod tm_names;
 pub mod tm_prices;
+pub mod town_map_locations;
 pub mod trades;
 pub mod trainer_names;
 pub mod trainer_parties;
@@ -0,0 +1,43 @@
+use pkmnapi_db::pic::Pic;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type PokemonPicResponse = BaseResponse<PokemonPicResponseAttributes>;
+pub type PokemonPicResponseData = BaseResponseData<PokemonPicResponseAttributes>;
+
+impl PokemonPicResponse {
+    pub fn new(pokedex_id: &u8, pokemon_pic: &Pic) -> PokemonPicResponse {
+        PokemonPicResponse {
+            data: PokemonPicResponseData::new(pokedex_id, pokemon_pic),
+            links: Links {
+                _self: utils::generate_url("pokemon/pics", Some(&pokedex_id.to_string())),
+            },
+        }
+    }
+}
+
+impl PokemonPicResponseData {
+    pub fn new(pokedex_id: &u8, pokemon_pic: &Pic) -> PokemonPicResponseData {
+        BaseResponseData {
+            id: pokedex_id.to_string(),
+            _type: BaseResponseType::pokemon_pics,
+            attributes: PokemonPicResponseAttributes {
+                width: pokemon_pic.width,
+                height: pokemon_pic.height,
+            },
+            links: Links {
+                _self: utils::generate_url("pokemon/pics", Some(&pokedex_id.to_string())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PokemonPicResponseAttributes {
+    pub width: u8,
+    pub height: u8,
+}
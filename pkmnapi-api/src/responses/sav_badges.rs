@@ -0,0 +1,34 @@
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavBadgesResponse = BaseResponse<SavBadgesResponseAttributes>;
+
+impl SavBadgesResponse {
+    /// Create a new `SavBadgesResponse`
+    pub fn new(player_id: &u16, badges: &Vec<u8>) -> SavBadgesResponse {
+        SavBadgesResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_badges,
+                attributes: SavBadgesResponseAttributes {
+                    badges: badges.to_vec(),
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/badges", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/badges", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavBadgesResponseAttributes {
+    badges: Vec<u8>,
+}
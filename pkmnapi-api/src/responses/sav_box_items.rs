@@ -0,0 +1,68 @@
+use pkmnapi_db::sav::SaveItem;
+use pkmnapi_db::{ItemName, MartItem, TMName};
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::item_names::ItemNameResponseData;
+use crate::responses::links::Links;
+use crate::responses::mart_items::mart_item;
+use crate::responses::tm_names::TMNameResponseData;
+use crate::utils;
+
+pub type SavBoxItemsResponse = BaseResponse<SavBoxItemsResponseAttributes>;
+
+impl SavBoxItemsResponse {
+    /// Create a new `SavBoxItemsResponse`
+    pub fn new(
+        player_id: &u16,
+        box_items: &Vec<SaveItem>,
+        item_names: &HashMap<u8, ItemName>,
+        tm_names: &HashMap<u8, TMName>,
+    ) -> SavBoxItemsResponse {
+        SavBoxItemsResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_box_items,
+                attributes: SavBoxItemsResponseAttributes {
+                    box_items: box_items
+                        .iter()
+                        .map(|box_item| SavBoxItemsResponseAttributesItem {
+                            amount: box_item.amount,
+                            item: match MartItem::from(&box_item.item_id) {
+                                MartItem::ITEM(item_id) => {
+                                    mart_item::ITEM(ItemNameResponseData::new(
+                                        &item_id,
+                                        item_names.get(&item_id).unwrap(),
+                                    ))
+                                }
+                                MartItem::TM(tm_id) => mart_item::TM(TMNameResponseData::new(
+                                    &tm_id,
+                                    tm_names.get(&tm_id).unwrap(),
+                                )),
+                            },
+                        })
+                        .collect(),
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/box_items", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/box_items", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavBoxItemsResponseAttributes {
+    box_items: Vec<SavBoxItemsResponseAttributesItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavBoxItemsResponseAttributesItem {
+    amount: u8,
+    item: mart_item,
+}
@@ -0,0 +1,32 @@
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavCoinsResponse = BaseResponse<SavCoinsResponseAttributes>;
+
+impl SavCoinsResponse {
+    /// Create a new `SavCoinsResponse`
+    pub fn new(player_id: &u16, coins: &u16) -> SavCoinsResponse {
+        SavCoinsResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_coins,
+                attributes: SavCoinsResponseAttributes { coins: *coins },
+                links: Links {
+                    _self: utils::generate_url("savs/coins", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/coins", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavCoinsResponseAttributes {
+    coins: u16,
+}
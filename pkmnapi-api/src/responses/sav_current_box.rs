@@ -0,0 +1,34 @@
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavCurrentBoxResponse = BaseResponse<SavCurrentBoxResponseAttributes>;
+
+impl SavCurrentBoxResponse {
+    /// Create a new `SavCurrentBoxResponse`
+    pub fn new(player_id: &u16, current_box: &u8) -> SavCurrentBoxResponse {
+        SavCurrentBoxResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_current_box,
+                attributes: SavCurrentBoxResponseAttributes {
+                    current_box: *current_box,
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/current_box", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/current_box", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavCurrentBoxResponseAttributes {
+    current_box: u8,
+}
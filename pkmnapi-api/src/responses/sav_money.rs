@@ -0,0 +1,32 @@
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavMoneyResponse = BaseResponse<SavMoneyResponseAttributes>;
+
+impl SavMoneyResponse {
+    /// Create a new `SavMoneyResponse`
+    pub fn new(player_id: &u16, money: &u32) -> SavMoneyResponse {
+        SavMoneyResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_money,
+                attributes: SavMoneyResponseAttributes { money: *money },
+                links: Links {
+                    _self: utils::generate_url("savs/money", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/money", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavMoneyResponseAttributes {
+    money: u32,
+}
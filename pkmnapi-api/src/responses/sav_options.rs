@@ -0,0 +1,73 @@
+use pkmnapi_db::sav::*;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavOptionsResponse = BaseResponse<SavOptionsResponseAttributes>;
+
+impl SavOptionsResponse {
+    /// Create a new `SavOptionsResponse`
+    pub fn new(player_id: &u16, options: &SaveOptions) -> SavOptionsResponse {
+        SavOptionsResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_options,
+                attributes: SavOptionsResponseAttributes {
+                    text_speed: match options.text_speed {
+                        SaveTextSpeed::FAST => SavOptionsResponseAttributesTextSpeed::fast,
+                        SaveTextSpeed::MEDIUM => SavOptionsResponseAttributesTextSpeed::medium,
+                        SaveTextSpeed::SLOW => SavOptionsResponseAttributesTextSpeed::slow,
+                    },
+                    battle_animation: match options.battle_animation {
+                        SaveBattleAnimation::ON => SavOptionsResponseAttributesBattleAnimation::on,
+                        SaveBattleAnimation::OFF => {
+                            SavOptionsResponseAttributesBattleAnimation::off
+                        }
+                    },
+                    battle_style: match options.battle_style {
+                        SaveBattleStyle::SHIFT => SavOptionsResponseAttributesBattleStyle::shift,
+                        SaveBattleStyle::SET => SavOptionsResponseAttributesBattleStyle::set,
+                    },
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/options", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/options", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavOptionsResponseAttributes {
+    text_speed: SavOptionsResponseAttributesTextSpeed,
+    battle_animation: SavOptionsResponseAttributesBattleAnimation,
+    battle_style: SavOptionsResponseAttributesBattleStyle,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsResponseAttributesTextSpeed {
+    fast,
+    medium,
+    slow,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsResponseAttributesBattleAnimation {
+    on,
+    off,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavOptionsResponseAttributesBattleStyle {
+    shift,
+    set,
+}
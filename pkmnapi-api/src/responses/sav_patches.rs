@@ -0,0 +1,66 @@
+use pkmnapi_sql::models::SavPatch;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseAll, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavPatchResponse = BaseResponse<SavPatchResponseAttributes>;
+pub type SavPatchResponseData = BaseResponseData<SavPatchResponseAttributes>;
+pub type SavPatchResponseAll = BaseResponseAll<SavPatchResponseData>;
+
+impl SavPatchResponseAll {
+    pub fn new(sav_patches: &Vec<SavPatch>) -> SavPatchResponseAll {
+        SavPatchResponseAll {
+            data: sav_patches
+                .iter()
+                .map(|sav_patch| SavPatchResponseData::new(sav_patch))
+                .collect(),
+            links: Links {
+                _self: utils::generate_url("savs/patches", None),
+            },
+        }
+    }
+}
+
+impl SavPatchResponse {
+    pub fn new(sav_patch: &SavPatch) -> SavPatchResponse {
+        SavPatchResponse {
+            data: SavPatchResponseData::new(sav_patch),
+            links: Links {
+                _self: utils::generate_url("savs/patches", Some(&sav_patch.id)),
+            },
+        }
+    }
+}
+
+impl SavPatchResponseData {
+    pub fn new(sav_patch: &SavPatch) -> SavPatchResponseData {
+        BaseResponseData {
+            id: sav_patch.id.to_owned(),
+            _type: BaseResponseType::sav_patches,
+            attributes: SavPatchResponseAttributes {
+                description: match &sav_patch.description {
+                    Some(description) => Some(description.to_owned()),
+                    None => None,
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/patches", Some(&sav_patch.id)),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[allow(non_camel_case_types)]
+pub enum SavPatchResponseType {
+    sav_patches,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavPatchResponseAttributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
@@ -0,0 +1,34 @@
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavPlayerIDResponse = BaseResponse<SavPlayerIDResponseAttributes>;
+
+impl SavPlayerIDResponse {
+    /// Create a new `SavPlayerIDResponse`
+    pub fn new(player_id: &u16) -> SavPlayerIDResponse {
+        SavPlayerIDResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_player_ids,
+                attributes: SavPlayerIDResponseAttributes {
+                    player_id: *player_id,
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/player_ids", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/player_ids", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavPlayerIDResponseAttributes {
+    player_id: u16,
+}
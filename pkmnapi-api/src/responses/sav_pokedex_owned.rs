@@ -0,0 +1,49 @@
+use pkmnapi_db::PokemonName;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::responses::pokemon_names::PokemonNameResponseData;
+use crate::utils;
+
+pub type SavPokedexOwnedResponse = BaseResponse<SavPokedexOwnedResponseAttributes>;
+
+impl SavPokedexOwnedResponse {
+    /// Create a new `SavPokedexOwnedResponse`
+    pub fn new(
+        player_id: &u16,
+        pokedex_ids: &Vec<u8>,
+        pokemon_names: &HashMap<u8, PokemonName>,
+    ) -> SavPokedexOwnedResponse {
+        SavPokedexOwnedResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_pokedex_owned,
+                attributes: SavPokedexOwnedResponseAttributes {
+                    pokemon: pokedex_ids
+                        .iter()
+                        .map(|pokedex_id| {
+                            PokemonNameResponseData::new(
+                                pokedex_id,
+                                pokemon_names.get(pokedex_id).unwrap(),
+                            )
+                        })
+                        .collect(),
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/pokedex_owned", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/pokedex_owned", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavPokedexOwnedResponseAttributes {
+    pokemon: Vec<PokemonNameResponseData>,
+}
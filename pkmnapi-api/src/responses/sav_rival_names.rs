@@ -0,0 +1,35 @@
+use pkmnapi_db::sav::SaveRivalName;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type SavRivalNameResponse = BaseResponse<SavRivalNameResponseAttributes>;
+
+impl SavRivalNameResponse {
+    /// Create a new `SavRivalNameResponse`
+    pub fn new(player_id: &u16, rival_name: &SaveRivalName) -> SavRivalNameResponse {
+        SavRivalNameResponse {
+            data: BaseResponseData {
+                id: player_id.to_string(),
+                _type: BaseResponseType::sav_rival_names,
+                attributes: SavRivalNameResponseAttributes {
+                    name: rival_name.name.to_string(),
+                },
+                links: Links {
+                    _self: utils::generate_url("savs/rival_names", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("savs/rival_names", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SavRivalNameResponseAttributes {
+    name: String,
+}
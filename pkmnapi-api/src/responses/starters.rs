@@ -0,0 +1,43 @@
+use rocket_okapi::JsonSchema;
+use serde::Serialize;
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type StartersResponse = BaseResponse<StartersResponseAttributes>;
+
+impl StartersResponse {
+    pub fn new(starters: &Vec<u8>) -> StartersResponse {
+        StartersResponse {
+            data: BaseResponseData {
+                id: "0".to_owned(),
+                _type: BaseResponseType::starters,
+                attributes: StartersResponseAttributes {
+                    pokemon: starters
+                        .iter()
+                        .map(|pokedex_id| StartersResponseAttributesPokemon {
+                            pokedex_id: *pokedex_id,
+                        })
+                        .collect(),
+                },
+                links: Links {
+                    _self: utils::generate_url("starters", None),
+                },
+            },
+            links: Links {
+                _self: utils::generate_url("starters", None),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct StartersResponseAttributes {
+    pokemon: Vec<StartersResponseAttributesPokemon>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct StartersResponseAttributesPokemon {
+    pokedex_id: u8,
+}
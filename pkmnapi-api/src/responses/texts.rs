@@ -0,0 +1,50 @@
+use pkmnapi_db::text::*;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::responses::base::{BaseResponse, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type TextResponse = BaseResponse<TextResponseAttributes>;
+pub type TextResponseData = BaseResponseData<TextResponseAttributes>;
+
+impl TextResponse {
+    pub fn new(bank_id: &u8, pointer: &u16, text: &Text) -> TextResponse {
+        TextResponse {
+            data: TextResponseData::new(bank_id, pointer, text),
+            links: Links {
+                _self: utils::generate_url("texts", Some(&format!("{}/{}", bank_id, pointer))),
+            },
+        }
+    }
+}
+
+impl TextResponseData {
+    pub fn new(bank_id: &u8, pointer: &u16, text: &Text) -> TextResponseData {
+        BaseResponseData {
+            id: format!("{}/{}", bank_id, pointer),
+            _type: BaseResponseType::texts,
+            attributes: TextResponseAttributes {
+                text: text.to_string(),
+                commands: text
+                    .commands
+                    .iter()
+                    .map(|command| match command {
+                        TextCommand::Start(_) => format!("{{text}}{}", command),
+                        _ => command.to_string(),
+                    })
+                    .collect(),
+            },
+            links: Links {
+                _self: utils::generate_url("texts", Some(&format!("{}/{}", bank_id, pointer))),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TextResponseAttributes {
+    pub text: String,
+    pub commands: Vec<String>,
+}
@@ -0,0 +1,65 @@
+use pkmnapi_db::Tileset;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseAll, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type TilesetResponse = BaseResponse<TilesetResponseAttributes>;
+pub type TilesetResponseData = BaseResponseData<TilesetResponseAttributes>;
+pub type TilesetResponseAll = BaseResponseAll<TilesetResponseData>;
+
+impl TilesetResponseAll {
+    pub fn new(tileset_ids: &Vec<u8>, tilesets: &HashMap<u8, Tileset>) -> TilesetResponseAll {
+        TilesetResponseAll {
+            data: tileset_ids
+                .iter()
+                .map(|tileset_id| {
+                    TilesetResponseData::new(tileset_id, tilesets.get(&tileset_id).unwrap())
+                })
+                .collect(),
+            links: Links {
+                _self: utils::generate_url("tilesets", None),
+            },
+        }
+    }
+}
+
+impl TilesetResponse {
+    pub fn new(tileset_id: &u8, tileset: &Tileset) -> TilesetResponse {
+        TilesetResponse {
+            data: TilesetResponseData::new(tileset_id, tileset),
+            links: Links {
+                _self: utils::generate_url("tilesets", Some(&tileset_id.to_string())),
+            },
+        }
+    }
+}
+
+impl TilesetResponseData {
+    pub fn new(tileset_id: &u8, tileset: &Tileset) -> TilesetResponseData {
+        BaseResponseData {
+            id: tileset_id.to_string(),
+            _type: BaseResponseType::tilesets,
+            attributes: TilesetResponseAttributes {
+                blocks: tileset.blocks.to_vec(),
+                collision_tiles: tileset.collision_tiles.to_vec(),
+                counter_tiles: tileset.counter_tiles.to_vec(),
+                grass_tile: tileset.grass_tile,
+            },
+            links: Links {
+                _self: utils::generate_url("tilesets", Some(&tileset_id.to_string())),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TilesetResponseAttributes {
+    pub blocks: Vec<Vec<u8>>,
+    pub collision_tiles: Vec<u8>,
+    pub counter_tiles: Vec<u8>,
+    pub grass_tile: Option<u8>,
+}
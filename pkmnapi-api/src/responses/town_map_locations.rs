@@ -0,0 +1,83 @@
+use pkmnapi_db::TownMapLocation;
+use rocket_okapi::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::responses::base::{BaseResponse, BaseResponseAll, BaseResponseData, BaseResponseType};
+use crate::responses::links::Links;
+use crate::utils;
+
+pub type TownMapLocationResponse = BaseResponse<TownMapLocationResponseAttributes>;
+pub type TownMapLocationResponseData = BaseResponseData<TownMapLocationResponseAttributes>;
+pub type TownMapLocationResponseAll = BaseResponseAll<TownMapLocationResponseData>;
+
+impl TownMapLocationResponseAll {
+    pub fn new(
+        town_map_location_ids: &Vec<u8>,
+        town_map_locations: &HashMap<u8, TownMapLocation>,
+    ) -> TownMapLocationResponseAll {
+        TownMapLocationResponseAll {
+            data: town_map_location_ids
+                .iter()
+                .map(|town_map_location_id| {
+                    TownMapLocationResponseData::new(
+                        town_map_location_id,
+                        town_map_locations.get(town_map_location_id).unwrap(),
+                    )
+                })
+                .collect(),
+            links: Links {
+                _self: utils::generate_url("town_map/locations", None),
+            },
+        }
+    }
+}
+
+impl TownMapLocationResponse {
+    pub fn new(
+        town_map_location_id: &u8,
+        town_map_location: &TownMapLocation,
+    ) -> TownMapLocationResponse {
+        TownMapLocationResponse {
+            data: TownMapLocationResponseData::new(town_map_location_id, town_map_location),
+            links: Links {
+                _self: utils::generate_url(
+                    "town_map/locations",
+                    Some(&town_map_location_id.to_string()),
+                ),
+            },
+        }
+    }
+}
+
+impl TownMapLocationResponseData {
+    pub fn new(
+        town_map_location_id: &u8,
+        town_map_location: &TownMapLocation,
+    ) -> TownMapLocationResponseData {
+        BaseResponseData {
+            id: town_map_location_id.to_string(),
+            _type: BaseResponseType::town_map_locations,
+            attributes: TownMapLocationResponseAttributes {
+                name: town_map_location.name.to_string(),
+                x: town_map_location.x,
+                y: town_map_location.y,
+                map_ids: town_map_location.map_ids.to_vec(),
+            },
+            links: Links {
+                _self: utils::generate_url(
+                    "town_map/locations",
+                    Some(&town_map_location_id.to_string()),
+                ),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TownMapLocationResponseAttributes {
+    pub name: String,
+    pub x: u8,
+    pub y: u8,
+    pub map_ids: Vec<u8>,
+}
@@ -0,0 +1,31 @@
+use pkmnapi_sql::*;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::battle_damage::*;
+use crate::responses::battle_damage::*;
+use crate::responses::errors::*;
+use crate::utils;
+
+#[openapi]
+#[post("/battle/damage", format = "application/json", data = "<data>")]
+pub fn post_battle_damage(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    data: Result<Json<BattleDamageRequest>, JsonError>,
+) -> Result<Json<BattleDamageResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_battle_damage_invalid)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let move_id = data.get_move_id();
+    let battle_damage =
+        db.get_battle_damage(&data.get_attacker(), &data.get_defender(), &move_id)?;
+
+    let response = BattleDamageResponse::new(&move_id, &battle_damage);
+
+    Ok(Json(response))
+}
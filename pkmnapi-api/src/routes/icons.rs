@@ -1,25 +1,37 @@
+use pkmnapi_db::img::*;
 use pkmnapi_sql::*;
 use rocket::http::{ContentType, Header};
+use rocket::response::status;
 use rocket::response::Response;
-use rocket::State;
+use rocket::{Data, State};
+use rocket_contrib::json::JsonValue;
 use std::io::Cursor;
 
 use crate::guards::*;
 use crate::responses::errors::*;
 use crate::utils;
 
-#[get("/icons/<icon_id>", format = "image/gif")]
+#[get("/icons/<icon_id>?<palette>", format = "image/gif")]
 pub fn get_icon<'a>(
     sql: State<PkmnapiSQL>,
     _rate_limit: RateLimit,
     access_token: Result<AccessToken, AccessTokenError>,
     icon_id: u8,
+    palette: Option<String>,
 ) -> Result<Response<'a>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let icon = db.get_icon(&icon_id)?;
-    let gif = icon.to_gif(26)?;
+    let gif = match palette {
+        Some(palette) if palette == "sgb" => {
+            let sgb_palette_id = db.get_icon_sgb_palette_id(&icon_id)?;
+            let sgb_palette = db.get_sgb_palette(&sgb_palette_id)?;
+
+            icon.to_gif_with_palette(26, &sgb_palette.to_palette())?
+        }
+        _ => icon.to_gif(26)?,
+    };
 
     let response = Response::build()
         .header(ContentType::GIF)
@@ -32,3 +44,65 @@ pub fn get_icon<'a>(
 
     Ok(response)
 }
+
+#[post("/icons/<icon_id>", format = "image/gif", data = "<data>", rank = 1)]
+pub fn post_icon_gif<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    icon_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let gif = Gif::from_gif(raw_data)?;
+    let patch = db.set_icon(&icon_id, &gif.frames)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_icons,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
+
+#[post(
+    "/icons/<icon_id>?<frame>",
+    format = "image/png",
+    data = "<data>",
+    rank = 2
+)]
+pub fn post_icon_png<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    icon_id: u8,
+    frame: Option<u8>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let img = Img::from_png(raw_data)?;
+    let patch = db.set_icon_frame(&icon_id, &frame.unwrap_or(0), &img)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_icons,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -208,3 +208,57 @@ pub fn get_town_map_jpeg<'a>(
 
     Ok(response)
 }
+
+#[post("/imgs/town_map", format = "image/png", data = "<data>", rank = 1)]
+pub fn post_town_map_png<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let img = Img::from_png(raw_data)?;
+    let patch = db.set_town_map_img(&img)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_town_map_imgs,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
+
+#[post("/imgs/town_map", format = "image/jpeg", data = "<data>", rank = 2)]
+pub fn post_town_map_jpeg<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let img = Img::from_jpeg(raw_data)?;
+    let patch = db.set_town_map_img(&img)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_town_map_imgs,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -66,7 +66,7 @@ pub fn post_item_name(
     let (db, connection) = utils::get_db(&sql, &access_token)?;
 
     let item_name = ItemName {
-        name: ROMString::from(data.get_name()),
+        name: ROMString::try_from(data.get_name())?,
     };
 
     let patch = db.set_item_name(&item_id, &item_name)?;
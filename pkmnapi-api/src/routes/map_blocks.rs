@@ -0,0 +1,57 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::map_blocks::*;
+use crate::responses::errors::*;
+use crate::responses::map_blocks::*;
+use crate::utils;
+
+#[openapi]
+#[get("/maps/blocks/<map_id>")]
+pub fn get_map_blocks(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    map_id: u8,
+) -> Result<Json<MapBlocksResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let map_blocks = db.get_map_blocks(&map_id)?;
+
+    let response = MapBlocksResponse::new(&map_id, &map_blocks);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/maps/blocks/<map_id>", format = "application/json", data = "<data>")]
+pub fn post_map_blocks(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<MapBlocksRequest>, JsonError>,
+    map_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_map_blocks_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let patch = db.set_map_blocks(&map_id, &data.get_blocks())?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_map_blocks,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,94 @@
+use pkmnapi_db::*;
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::map_headers::*;
+use crate::responses::errors::*;
+use crate::responses::map_headers::*;
+use crate::utils;
+
+#[openapi]
+#[get("/maps/headers")]
+pub fn get_map_header_all(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<MapHeaderResponseAll>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let (min_map_id, max_map_id) = db.map_id_bounds();
+    let map_ids: Vec<u8> = (min_map_id..=max_map_id)
+        .map(|map_id| map_id as u8)
+        .collect();
+    let map_headers = db.get_map_header_all(&map_ids)?;
+    let map_ids = map_ids
+        .into_iter()
+        .filter(|map_id| map_headers.contains_key(map_id))
+        .collect();
+
+    let response = MapHeaderResponseAll::new(&map_ids, &map_headers);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[get("/maps/headers/<map_id>")]
+pub fn get_map_header(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    map_id: u8,
+) -> Result<Json<MapHeaderResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let map_header = db.get_map_header(&map_id)?;
+
+    let response = MapHeaderResponse::new(&map_id, &map_header);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/maps/headers/<map_id>", format = "application/json", data = "<data>")]
+pub fn post_map_header(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<MapHeaderRequest>, JsonError>,
+    map_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_map_headers_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let map_header = MapHeader {
+        tileset_id: data.get_tileset_id(),
+        height: data.get_height(),
+        width: data.get_width(),
+        blocks_pointer: data.get_blocks_pointer(),
+        text_pointer: data.get_text_pointer(),
+        script_pointer: data.get_script_pointer(),
+        connections: data.get_connections(),
+        objects_pointer: data.get_objects_pointer(),
+    };
+
+    let patch = db.set_map_header(&map_id, &map_header)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_map_headers,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
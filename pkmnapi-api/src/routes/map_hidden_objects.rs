@@ -0,0 +1,88 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::map_hidden_objects::*;
+use crate::responses::errors::*;
+use crate::responses::map_hidden_objects::*;
+use crate::utils;
+
+#[openapi]
+#[get("/maps/hidden_objects")]
+pub fn get_map_hidden_objects_all(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<MapHiddenObjectsResponseAll>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let (min_map_id, max_map_id) = db.map_id_bounds();
+    let map_ids: Vec<u8> = (min_map_id..=max_map_id)
+        .map(|map_id| map_id as u8)
+        .collect();
+    let hidden_objects = db.get_hidden_objects_all(&map_ids)?;
+    let map_ids = map_ids
+        .into_iter()
+        .filter(|map_id| hidden_objects.contains_key(map_id))
+        .collect();
+
+    let response = MapHiddenObjectsResponseAll::new(&map_ids, &hidden_objects);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[get("/maps/hidden_objects/<map_id>")]
+pub fn get_map_hidden_objects(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    map_id: u8,
+) -> Result<Json<MapHiddenObjectsResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let hidden_objects = db.get_hidden_objects(&map_id)?;
+
+    let response = MapHiddenObjectsResponse::new(&map_id, &hidden_objects);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post(
+    "/maps/hidden_objects/<map_id>",
+    format = "application/json",
+    data = "<data>"
+)]
+pub fn post_map_hidden_objects(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<MapHiddenObjectsRequest>, JsonError>,
+    map_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_map_hidden_objects_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let hidden_objects = data.get_hidden_objects();
+
+    let patch = db.set_hidden_objects(&map_id, &hidden_objects)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_map_hidden_objects,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,90 @@
+use pkmnapi_db::*;
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::map_objects::*;
+use crate::responses::errors::*;
+use crate::responses::map_objects::*;
+use crate::utils;
+
+#[openapi]
+#[get("/maps/objects")]
+pub fn get_map_objects_all(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<MapObjectsResponseAll>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let (min_map_id, max_map_id) = db.map_id_bounds();
+    let map_ids: Vec<u8> = (min_map_id..=max_map_id)
+        .map(|map_id| map_id as u8)
+        .collect();
+    let map_objects = db.get_map_objects_all(&map_ids)?;
+    let map_ids = map_ids
+        .into_iter()
+        .filter(|map_id| map_objects.contains_key(map_id))
+        .collect();
+
+    let response = MapObjectsResponseAll::new(&map_ids, &map_objects);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[get("/maps/objects/<map_id>")]
+pub fn get_map_objects(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    map_id: u8,
+) -> Result<Json<MapObjectsResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let map_objects = db.get_map_objects(&map_id)?;
+
+    let response = MapObjectsResponse::new(&map_id, &map_objects);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/maps/objects/<map_id>", format = "application/json", data = "<data>")]
+pub fn post_map_objects(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<MapObjectsRequest>, JsonError>,
+    map_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_map_objects_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let map_objects = MapObjects {
+        border_block: data.get_border_block(),
+        warps: data.get_warps(),
+        signs: data.get_signs(),
+        objects: data.get_objects(),
+    };
+
+    let patch = db.set_map_objects(&map_id, &map_objects)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_map_objects,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -1,25 +1,37 @@
+use pkmnapi_db::map::*;
 use pkmnapi_sql::*;
 use rocket::http::{ContentType, Header};
+use rocket::response::status;
 use rocket::response::Response;
-use rocket::State;
+use rocket::{Data, State};
+use rocket_contrib::json::JsonValue;
 use std::io::Cursor;
 
 use crate::guards::*;
 use crate::responses::errors::*;
 use crate::utils;
 
-#[get("/maps/pics/<map_id>", format = "image/png", rank = 1)]
+#[get("/maps/pics/<map_id>?<palette>", format = "image/png", rank = 1)]
 pub fn get_map_pic_png<'a>(
     sql: State<PkmnapiSQL>,
     _rate_limit: RateLimit,
     access_token: Result<AccessToken, AccessTokenError>,
     map_id: u8,
+    palette: Option<String>,
 ) -> Result<Response<'a>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let map = db.get_map_pic(&map_id)?;
-    let img = map.to_png()?;
+    let img = match palette {
+        Some(palette) if palette == "sgb" => {
+            let sgb_palette_id = db.get_map_sgb_palette_id(&map_id)?;
+            let sgb_palette = db.get_sgb_palette(&sgb_palette_id)?;
+
+            map.to_png_with_palette(&sgb_palette.to_palette())?
+        }
+        _ => map.to_png()?,
+    };
 
     let response = Response::build()
         .header(ContentType::PNG)
@@ -57,3 +69,64 @@ pub fn get_map_pic_jpeg<'a>(
 
     Ok(response)
 }
+
+#[post("/maps/pics/<map_id>", format = "image/png", data = "<data>", rank = 1)]
+pub fn post_map_pic_png<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    map_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let map = Map::from_png(raw_data)?;
+    let patch = db.set_map_pic(&map_id, &map)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_map_pics,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
+
+#[post(
+    "/maps/pics/<map_id>",
+    format = "image/jpeg",
+    data = "<data>",
+    rank = 2
+)]
+pub fn post_map_pic_jpeg<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    map_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let map = Map::from_jpeg(raw_data)?;
+    let patch = db.set_map_pic(&map_id, &map)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_map_pics,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -98,17 +98,17 @@ pub fn post_mart_items(
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let data = utils::get_data(data, BaseErrorResponseId::error_mart_items_invalid)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let mart_items = data.get_mart_items();
 
-    let patch = db.set_mart_items(&mart_id, &mart_items)?;
+    let patch_set = db.set_mart_items(&mart_id, &mart_items)?;
 
     utils::insert_rom_patch(
         sql,
         connection,
         access_token,
-        patch,
+        patch_set,
         patch_description,
         BaseErrorResponseId::error_mart_items,
     )?;
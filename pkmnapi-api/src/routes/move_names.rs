@@ -66,7 +66,7 @@ pub fn post_move_name(
     let (db, connection) = utils::get_db(&sql, &access_token)?;
 
     let move_name = MoveName {
-        name: ROMString::from(data.get_name()),
+        name: ROMString::try_from(data.get_name())?,
     };
 
     let patch = db.set_move_name(&move_id, &move_name)?;
@@ -46,22 +46,22 @@ pub fn post_player_names(
         player: data
             .get_player_names()
             .iter()
-            .map(|name| ROMString::from(name))
-            .collect(),
+            .map(|name| ROMString::try_from(name))
+            .collect::<Result<Vec<ROMString>, _>>()?,
         rival: data
             .get_rival_names()
             .iter()
-            .map(|name| ROMString::from(name))
-            .collect(),
+            .map(|name| ROMString::try_from(name))
+            .collect::<Result<Vec<ROMString>, _>>()?,
     };
 
-    let patch = db.set_player_names(&player_names)?;
+    let patch_set = db.set_player_names(&player_names)?;
 
     utils::insert_rom_patch(
         sql,
         connection,
         access_token,
-        patch,
+        patch_set,
         patch_description,
         BaseErrorResponseId::error_player_names,
     )?;
@@ -70,7 +70,7 @@ pub fn post_pokedex_entry(
     let (db, connection) = utils::get_db(&sql, &access_token)?;
 
     let pokedex_entry = PokedexEntry {
-        species: ROMString::from(data.get_species()),
+        species: ROMString::try_from(data.get_species())?,
         height: data.get_height(),
         weight: data.get_weight(),
     };
@@ -67,19 +67,19 @@ pub fn post_pokedex_text(
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let data = utils::get_data(data, BaseErrorResponseId::error_pokedex_texts_invalid)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let pokedex_text = PokedexText {
-        text: ROMString::from(data.get_text()),
+        text: ROMString::try_from(data.get_text())?,
     };
 
-    let patch = db.set_pokedex_text(&pokedex_id, &pokedex_text)?;
+    let patch_set = db.set_pokedex_text(&pokedex_id, &pokedex_text)?;
 
     utils::insert_rom_patch(
         sql,
         connection,
         access_token,
-        patch,
+        patch_set,
         patch_description,
         BaseErrorResponseId::error_pokedex_texts,
     )?;
@@ -116,17 +116,17 @@ pub fn post_pokemon_evolutions(
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let data = utils::get_data(data, BaseErrorResponseId::error_pokemon_evolutions_invalid)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let pokemon_evolutions = data.get_evolutions();
 
-    let patch = db.set_pokemon_evolutions(&pokedex_id, &pokemon_evolutions)?;
+    let patch_set = db.set_pokemon_evolutions(&pokedex_id, &pokemon_evolutions)?;
 
     utils::insert_rom_patch(
         sql,
         connection,
         access_token,
-        patch,
+        patch_set,
         patch_description,
         BaseErrorResponseId::error_pokemon_evolutions,
     )?;
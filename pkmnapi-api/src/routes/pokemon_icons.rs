@@ -1,9 +1,12 @@
 use pkmnapi_db::*;
 use pkmnapi_sql::*;
+use rocket::http::{ContentType, Header};
 use rocket::response::status;
+use rocket::response::Response;
 use rocket::State;
 use rocket_contrib::json::{Json, JsonError, JsonValue};
 use rocket_okapi::openapi;
+use std::io::Cursor;
 
 use crate::guards::*;
 use crate::requests::pokemon_icons::*;
@@ -33,7 +36,7 @@ pub fn get_pokemon_icon_all(
 }
 
 #[openapi]
-#[get("/pokemon/icons/<pokedex_id>")]
+#[get("/pokemon/icons/<pokedex_id>", format = "application/json", rank = 1)]
 pub fn get_pokemon_icon(
     sql: State<PkmnapiSQL>,
     _rate_limit: RateLimit,
@@ -50,6 +53,33 @@ pub fn get_pokemon_icon(
     Ok(Json(response))
 }
 
+#[get("/pokemon/icons/<pokedex_id>", format = "image/gif", rank = 2)]
+pub fn get_pokemon_icon_gif<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    pokedex_id: u8,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let pokemon_icon = db.get_pokemon_icon(&pokedex_id)?;
+    let pokemon_name = db.get_pokemon_name(&pokedex_id)?;
+    let icon = db.get_icon(&pokemon_icon.icon_id)?;
+    let gif = icon.to_gif(26)?;
+
+    let response = Response::build()
+        .header(ContentType::GIF)
+        .header(Header::new(
+            "Content-Disposition",
+            format!(r#"attachment; filename="{}.gif""#, pokemon_name.name),
+        ))
+        .sized_body(Cursor::new(gif))
+        .finalize();
+
+    Ok(response)
+}
+
 #[openapi]
 #[post(
     "/pokemon/icons/<pokedex_id>",
@@ -77,17 +77,17 @@ pub fn post_pokemon_learnset(
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let data = utils::get_data(data, BaseErrorResponseId::error_pokemon_learnsets_invalid)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let pokemon_learnset = data.get_learnset();
 
-    let patch = db.set_pokemon_learnset(&pokedex_id, &pokemon_learnset)?;
+    let patch_set = db.set_pokemon_learnset(&pokedex_id, &pokemon_learnset)?;
 
     utils::insert_rom_patch(
         sql,
         connection,
         access_token,
-        patch,
+        patch_set,
         patch_description,
         BaseErrorResponseId::error_pokemon_learnsets,
     )?;
@@ -70,7 +70,7 @@ pub fn post_pokemon_name(
     let (db, connection) = utils::get_db(&sql, &access_token)?;
 
     let pokemon_name = PokemonName {
-        name: ROMString::from(data.get_name()),
+        name: ROMString::try_from(data.get_name())?,
     };
 
     let patch = db.set_pokemon_name(&pokedex_id, &pokemon_name)?;
@@ -1,3 +1,4 @@
+use pkmnapi_db::img::*;
 use pkmnapi_db::pic::*;
 use pkmnapi_db::*;
 use pkmnapi_sql::*;
@@ -5,15 +6,40 @@ use rocket::http::{ContentType, Header};
 use rocket::response::status;
 use rocket::response::Response;
 use rocket::{Data, State};
-use rocket_contrib::json::JsonValue;
+use rocket_contrib::json::{Json, JsonValue};
+use rocket_okapi::openapi;
 use std::io::Cursor;
 
 use crate::guards::*;
 use crate::responses::errors::*;
+use crate::responses::pokemon_pics::*;
 use crate::utils;
 
+#[openapi]
 #[get(
-    "/pokemon/pics/<pokedex_id>?<face>&<mirror>",
+    "/pokemon/pics/<pokedex_id>?<face>",
+    format = "application/json",
+    rank = 3
+)]
+pub fn get_pokemon_pic_json(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    pokedex_id: u8,
+    face: Option<String>,
+) -> Result<Json<PokemonPicResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let pokemon_pic = db.get_pokemon_pic(&pokedex_id, &PokemonPicFace::from(face))?;
+
+    let response = PokemonPicResponse::new(&pokedex_id, &pokemon_pic);
+
+    Ok(Json(response))
+}
+
+#[get(
+    "/pokemon/pics/<pokedex_id>?<face>&<mirror>&<palette>",
     format = "image/png",
     rank = 1
 )]
@@ -24,13 +50,22 @@ pub fn get_pokemon_pic_png<'a>(
     pokedex_id: u8,
     face: Option<String>,
     mirror: Option<bool>,
+    palette: Option<String>,
 ) -> Result<Response<'a>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let pic = db.get_pokemon_pic(&pokedex_id, &PokemonPicFace::from(face))?;
     let pokemon_name = db.get_pokemon_name(&pokedex_id)?;
-    let img = pic.to_png(mirror.is_some())?;
+    let img = match palette {
+        Some(palette) if palette == "sgb" => {
+            let sgb_palette_id = db.get_pokemon_sgb_palette_id(&pokedex_id)?;
+            let sgb_palette = db.get_sgb_palette(&sgb_palette_id)?;
+
+            pic.to_png_with_palette(mirror.is_some(), &sgb_palette.to_palette())?
+        }
+        _ => pic.to_png(mirror.is_some())?,
+    };
 
     let response = Response::build()
         .header(ContentType::PNG)
@@ -77,7 +112,7 @@ pub fn get_pokemon_pic_jpeg<'a>(
 }
 
 #[post(
-    "/pokemon/pics/<pokedex_id>?<face>&<method>&<primary>",
+    "/pokemon/pics/<pokedex_id>?<face>&<method>&<primary>&<palette>",
     format = "image/png",
     data = "<data>",
     rank = 1
@@ -92,13 +127,15 @@ pub fn post_pokemon_pic_png<'a>(
     face: Option<String>,
     method: Option<u8>,
     primary: Option<u8>,
+    palette: Option<String>,
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
     let raw_data = utils::get_data_raw(data);
 
-    let pic = Pic::from_png(raw_data)?;
-    let encoding_method = PicEncodingMethod::from(method.unwrap_or(0x01), primary.unwrap_or(0x00));
+    let pic = Pic::from_png_with_palette(raw_data, &PaletteOrder::from(palette))?;
+    let encoding_method =
+        method.map(|method| PicEncodingMethod::from(method, primary.unwrap_or(0x00)));
     let patch = db.set_pokemon_pic(
         &pokedex_id,
         &PokemonPicFace::from(face),
@@ -136,11 +173,12 @@ pub fn post_pokemon_pic_jpeg<'a>(
     primary: Option<u8>,
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
     let raw_data = utils::get_data_raw(data);
 
     let pic = Pic::from_jpeg(raw_data)?;
-    let encoding_method = PicEncodingMethod::from(method.unwrap_or(0x01), primary.unwrap_or(0x00));
+    let encoding_method =
+        method.map(|method| PicEncodingMethod::from(method, primary.unwrap_or(0x00)));
     let patch = db.set_pokemon_pic(
         &pokedex_id,
         &PokemonPicFace::from(face),
@@ -1,9 +1,10 @@
+use pkmnapi_db::patch::*;
 use pkmnapi_sql::*;
 use rocket::http::{ContentType, Header};
 use rocket::response::status;
 use rocket::response::Response;
-use rocket::State;
-use rocket_contrib::json::Json;
+use rocket::{Data, State};
+use rocket_contrib::json::{Json, JsonValue};
 use rocket_okapi::openapi;
 use std::io::Cursor;
 
@@ -76,6 +77,104 @@ pub fn get_rom_patches_raw<'a>(
     Ok(response)
 }
 
+#[get("/roms/patches?<checksum>", format = "application/x-bps", rank = 3)]
+pub fn get_rom_patches_bps<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    checksum: Option<bool>,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (source, target) = get_rom_patches_source_target(&sql, &access_token, checksum)?;
+
+    let patch = BPSPatch::new(&source, &target).to_raw();
+
+    let response = Response::build()
+        .header(ContentType::new("application", "x-bps"))
+        .header(Header::new(
+            "Content-Disposition",
+            r#"attachment; filename="patch.bps""#,
+        ))
+        .sized_body(Cursor::new(patch))
+        .finalize();
+
+    Ok(response)
+}
+
+#[get("/roms/patches?<checksum>", format = "application/x-ups", rank = 4)]
+pub fn get_rom_patches_ups<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    checksum: Option<bool>,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (source, target) = get_rom_patches_source_target(&sql, &access_token, checksum)?;
+
+    let patch = UPSPatch::new(&source, &target).to_raw();
+
+    let response = Response::build()
+        .header(ContentType::new("application", "x-ups"))
+        .header(Header::new(
+            "Content-Disposition",
+            r#"attachment; filename="patch.ups""#,
+        ))
+        .sized_body(Cursor::new(patch))
+        .finalize();
+
+    Ok(response)
+}
+
+fn get_rom_patches_source_target(
+    sql: &State<PkmnapiSQL>,
+    access_token: &String,
+    checksum: Option<bool>,
+) -> Result<(Vec<u8>, Vec<u8>), ResponseError> {
+    let (source_db, _) = utils::get_db(sql, access_token)?;
+    let (mut db, _) = utils::get_db_with_applied_patches(sql, access_token)?;
+
+    match checksum {
+        Some(false) => {}
+        _ => db.apply_patch(db.generate_checksum())?,
+    };
+
+    Ok((source_db.rom, db.rom))
+}
+
+#[post("/roms/patches", data = "<data>")]
+pub fn post_rom_patches(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let patch_set = match db.import_patch(&raw_data) {
+        Ok(patch_set) => patch_set,
+        Err(e) => {
+            return Err(BadRequestError::new(
+                BaseErrorResponseId::error_rom_patches_invalid,
+                Some(e.to_string()),
+            ))
+        }
+    };
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch_set,
+        patch_description,
+        BaseErrorResponseId::error_rom_patches,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
+
 #[get("/roms/patches/<patch_id>")]
 pub fn get_rom_patch<'a>(
     sql: State<PkmnapiSQL>,
@@ -0,0 +1,68 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::sav_badges::*;
+use crate::responses::errors::*;
+use crate::responses::sav_badges::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/badges")]
+pub fn get_sav_badges(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavBadgesResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let player_id = sav.get_player_id()?;
+    let badges = sav.get_badges()?;
+
+    let response = SavBadgesResponse::new(&player_id, &badges);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/savs/badges", format = "application/json", data = "<data>")]
+pub fn post_sav_badges(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<SavBadgesRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_sav_badges_invalid)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let badges = data.get_badges();
+
+    let patch = sav.set_badges(badges)?;
+
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_sav_badges,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,85 @@
+use pkmnapi_db::*;
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::sav_bag_items::*;
+use crate::responses::errors::*;
+use crate::responses::sav_bag_items::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/bag_items")]
+pub fn get_sav_bag_items(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavBagItemsResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(ref sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let player_id = sav.get_player_id()?;
+    let bag_items = sav.get_bag_items()?;
+    let item_ids = bag_items
+        .iter()
+        .filter_map(|bag_item| match MartItem::from(&bag_item.item_id) {
+            MartItem::ITEM(item_id) => Some(item_id),
+            _ => None,
+        })
+        .collect();
+    let item_names = db.get_item_name_all(&item_ids)?;
+    let tm_ids = bag_items
+        .iter()
+        .filter_map(|bag_item| match MartItem::from(&bag_item.item_id) {
+            MartItem::TM(tm_id) => Some(tm_id),
+            _ => None,
+        })
+        .collect();
+    let tm_names = db.get_tm_name_all(&tm_ids)?;
+
+    let response = SavBagItemsResponse::new(&player_id, &bag_items, &item_names, &tm_names);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/savs/bag_items", format = "application/json", data = "<data>")]
+pub fn post_sav_bag_items(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<SavBagItemsRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_sav_bag_items_invalid)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let bag_items = data.get_bag_items();
+
+    let patch = sav.set_bag_items(&bag_items)?;
+
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_sav_bag_items,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
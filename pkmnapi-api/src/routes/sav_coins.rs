@@ -0,0 +1,68 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::sav_coins::*;
+use crate::responses::errors::*;
+use crate::responses::sav_coins::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/coins")]
+pub fn get_sav_coins(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavCoinsResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let player_id = sav.get_player_id()?;
+    let coins = sav.get_coins()?;
+
+    let response = SavCoinsResponse::new(&player_id, &coins);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/savs/coins", format = "application/json", data = "<data>")]
+pub fn post_sav_coins(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<SavCoinsRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_sav_coins_invalid)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let coins = data.get_coins();
+
+    let patch = sav.set_coins(&coins)?;
+
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_sav_coins,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,68 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::sav_current_box::*;
+use crate::responses::errors::*;
+use crate::responses::sav_current_box::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/current_box")]
+pub fn get_sav_current_box(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavCurrentBoxResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let player_id = sav.get_player_id()?;
+    let current_box = sav.get_current_box()?;
+
+    let response = SavCurrentBoxResponse::new(&player_id, &current_box);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/savs/current_box", format = "application/json", data = "<data>")]
+pub fn post_sav_current_box(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<SavCurrentBoxRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_sav_current_box_invalid)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let current_box = data.get_current_box();
+
+    let patch = sav.set_current_box(&current_box)?;
+
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_sav_current_box,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
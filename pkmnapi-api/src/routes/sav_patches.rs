@@ -0,0 +1,143 @@
+use pkmnapi_sql::*;
+use rocket::http::{ContentType, Header};
+use rocket::response::status;
+use rocket::response::Response;
+use rocket::State;
+use rocket_contrib::json::Json;
+use rocket_okapi::openapi;
+use std::io::Cursor;
+
+use crate::guards::*;
+use crate::responses::errors::*;
+use crate::responses::sav_patches::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/patches", format = "application/json", rank = 1)]
+pub fn get_sav_patches(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavPatchResponseAll>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+
+    let connection = sql.get_connection().unwrap();
+    let patches = match sql.select_sav_patches_by_access_token(&connection, &access_token) {
+        Ok(patches) => patches,
+        Err(_) => return Err(SavErrorNoSav::new()),
+    };
+
+    let response = SavPatchResponseAll::new(&patches);
+
+    Ok(Json(response))
+}
+
+#[get("/savs/patches?<checksum>", format = "application/patch", rank = 2)]
+pub fn get_sav_patches_raw<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    checksum: Option<bool>,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let patches = match sql.select_sav_patches_by_access_token(&connection, &access_token) {
+        Ok(patches) => patches,
+        Err(_) => return Err(SavErrorNoSav::new()),
+    };
+
+    let header = "PATCH".chars().map(|c| c as u8).collect::<Vec<u8>>();
+    let footer = "EOF".chars().map(|c| c as u8).collect::<Vec<u8>>();
+    let mut body: Vec<u8> = patches
+        .iter()
+        .map(|patch| patch.data.to_vec())
+        .flatten()
+        .collect();
+
+    let checksum = match checksum {
+        Some(false) => vec![],
+        _ => {
+            let mut checksum = sav.generate_checksum()?.to_raw();
+
+            checksum.extend(sav.generate_box_checksums()?.to_raw());
+
+            checksum
+        }
+    };
+
+    body.extend(&checksum);
+
+    let patch: Vec<u8> = [header, body, footer].concat();
+
+    let response = Response::build()
+        .header(ContentType::new("application", "patch"))
+        .header(Header::new(
+            "Content-Disposition",
+            r#"attachment; filename="patch.ips""#,
+        ))
+        .sized_body(Cursor::new(patch))
+        .finalize();
+
+    Ok(response)
+}
+
+#[get("/savs/patches/<patch_id>")]
+pub fn get_sav_patch<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_id: String,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+
+    let connection = sql.get_connection().unwrap();
+    let patch = match sql.select_sav_patch_by_id(&connection, &access_token, &patch_id) {
+        Ok(Some(patch)) => patch,
+        Ok(None) => {
+            return Err(NotFoundError::new(
+                BaseErrorResponseId::error_sav_patches,
+                Some("No SAV patch found".to_owned()),
+            ))
+        }
+        Err(_) => return Err(SavErrorNoSav::new()),
+    };
+
+    let response = SavPatchResponse::new(&patch);
+    let body = serde_json::to_string(&response).unwrap();
+
+    let response = Response::build()
+        .header(ContentType::JSON)
+        .header(Header::new("ETag", patch.etag))
+        .sized_body(Cursor::new(body))
+        .finalize();
+
+    Ok(response)
+}
+
+#[openapi]
+#[delete("/savs/patches/<patch_id>")]
+pub fn delete_sav_patch(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    if_match: Result<IfMatch, IfMatchError>,
+    patch_id: String,
+) -> Result<status::NoContent, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let etag = utils::get_etag(if_match)?;
+    let connection = sql.get_connection().unwrap();
+
+    match sql.delete_sav_patch_by_id(&connection, &access_token, &patch_id, &etag) {
+        Ok(_) => {}
+        Err(pkmnapi_sql::error::Error::ETagError) => return Err(ETagErrorMismatch::new()),
+        Err(_) => return Err(SavErrorNoSav::new()),
+    }
+
+    Ok(status::NoContent)
+}
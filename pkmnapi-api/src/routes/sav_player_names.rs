@@ -54,23 +54,19 @@ pub fn post_sav_player_name(
     };
 
     let player_name = SavePlayerName {
-        name: ROMString::from(data.get_name()),
+        name: ROMString::try_from(data.get_name())?,
     };
 
     let patch = sav.set_player_name(&player_name)?;
-    let patch_description = utils::get_patch_description(patch_description);
 
-    if let Err(e) = sql.insert_sav_patch(
-        &connection,
-        &access_token,
-        &patch.to_raw(),
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
         patch_description,
-    ) {
-        return Err(NotFoundError::new(
-            BaseErrorResponseId::error_sav_player_names,
-            Some(e.to_string()),
-        ));
-    }
+        BaseErrorResponseId::error_sav_player_names,
+    )?;
 
     Ok(status::Accepted(Some(json!({}))))
 }
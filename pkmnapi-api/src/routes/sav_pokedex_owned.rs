@@ -0,0 +1,69 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::sav_pokedex_owned::*;
+use crate::responses::errors::*;
+use crate::responses::sav_pokedex_owned::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/pokedex_owned")]
+pub fn get_sav_pokedex_owned(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavPokedexOwnedResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(ref sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let player_id = sav.get_player_id()?;
+    let pokedex_ids = sav.get_pokemon_owned()?;
+    let pokemon_names = db.get_pokemon_name_all(&pokedex_ids)?;
+
+    let response = SavPokedexOwnedResponse::new(&player_id, &pokedex_ids, &pokemon_names);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/savs/pokedex_owned", format = "application/json", data = "<data>")]
+pub fn post_sav_pokedex_owned(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<SavPokedexOwnedRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_sav_pokedex_owned_invalid)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let pokedex_ids = data.get_pokedex_ids();
+
+    let patch = sav.set_pokemon_owned(&pokedex_ids)?;
+
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_sav_pokedex_owned,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
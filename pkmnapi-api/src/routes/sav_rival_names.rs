@@ -0,0 +1,72 @@
+use pkmnapi_db::sav::*;
+use pkmnapi_db::string::*;
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::sav_rival_names::*;
+use crate::responses::errors::*;
+use crate::responses::sav_rival_names::*;
+use crate::utils;
+
+#[openapi]
+#[get("/savs/rival_names")]
+pub fn get_sav_rival_name(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<SavRivalNameResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let player_id = sav.get_player_id()?;
+    let rival_name = sav.get_rival_name()?;
+
+    let response = SavRivalNameResponse::new(&player_id, &rival_name);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/savs/rival_names", format = "application/json", data = "<data>")]
+pub fn post_sav_rival_name(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<SavRivalNameRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_sav_rival_names_invalid)?;
+    let (db, connection) = utils::get_db(&sql, &access_token)?;
+
+    let sav = match db.sav {
+        Some(sav) => sav,
+        None => return Err(SavErrorNoSav::new()),
+    };
+
+    let rival_name = SaveRivalName {
+        name: ROMString::try_from(data.get_name())?,
+    };
+
+    let patch = sav.set_rival_name(&rival_name)?;
+
+    utils::insert_sav_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_sav_rival_names,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,57 @@
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::starters::*;
+use crate::responses::errors::*;
+use crate::responses::starters::*;
+use crate::utils;
+
+#[openapi]
+#[get("/starters")]
+pub fn get_starters(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<StartersResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let starters = db.get_starters()?;
+
+    let response = StartersResponse::new(&starters);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post("/starters", format = "application/json", data = "<data>")]
+pub fn post_starters(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<StartersRequest>, JsonError>,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_starters_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let starters = data.get_starters();
+
+    let patch_set = db.set_starters(&starters)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch_set,
+        patch_description,
+        BaseErrorResponseId::error_starters,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
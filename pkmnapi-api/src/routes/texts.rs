@@ -0,0 +1,66 @@
+use pkmnapi_db::text::*;
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::texts::*;
+use crate::responses::errors::*;
+use crate::responses::texts::*;
+use crate::utils;
+
+#[openapi]
+#[get("/texts/<bank_id>/<pointer>")]
+pub fn get_text(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    bank_id: u8,
+    pointer: u16,
+) -> Result<Json<TextResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let text = db.get_text(&bank_id, &pointer)?;
+
+    let response = TextResponse::new(&bank_id, &pointer, &text);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post(
+    "/texts/<bank_id>/<pointer>",
+    format = "application/json",
+    data = "<data>"
+)]
+pub fn post_text(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<TextRequest>, JsonError>,
+    bank_id: u8,
+    pointer: u16,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_texts_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let text = Text::from_markup(data.get_text())?;
+
+    let patch = db.set_text(&bank_id, &pointer, &text)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_texts,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,211 @@
+use pkmnapi_db::img::*;
+use pkmnapi_db::*;
+use pkmnapi_sql::*;
+use rocket::http::{ContentType, Header};
+use rocket::response::status;
+use rocket::response::Response;
+use rocket::{Data, State};
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+use std::io::Cursor;
+
+use crate::guards::*;
+use crate::requests::tilesets::*;
+use crate::responses::errors::*;
+use crate::responses::tilesets::*;
+use crate::utils;
+
+#[openapi]
+#[get("/tilesets", format = "application/json")]
+pub fn get_tileset_all(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<TilesetResponseAll>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let (min_tileset_id, max_tileset_id) = db.tileset_id_bounds();
+    let tileset_ids: Vec<u8> = (min_tileset_id..=max_tileset_id)
+        .map(|tileset_id| tileset_id as u8)
+        .collect();
+    let tilesets = db.get_tileset_all(&tileset_ids)?;
+
+    let response = TilesetResponseAll::new(&tileset_ids, &tilesets);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[get("/tilesets/<tileset_id>", format = "application/json", rank = 1)]
+pub fn get_tileset_json(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    tileset_id: u8,
+) -> Result<Json<TilesetResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let tileset = db.get_tileset(&tileset_id)?;
+
+    let response = TilesetResponse::new(&tileset_id, &tileset);
+
+    Ok(Json(response))
+}
+
+#[get("/tilesets/<tileset_id>", format = "image/png", rank = 2)]
+pub fn get_tileset_png<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    tileset_id: u8,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let tileset_img = db.get_tileset_img(&tileset_id)?;
+    let img = tileset_img.to_png()?;
+
+    let response = Response::build()
+        .header(ContentType::PNG)
+        .header(Header::new(
+            "Content-Disposition",
+            format!(r#"attachment; filename="tileset-{}.png""#, tileset_id),
+        ))
+        .sized_body(Cursor::new(img))
+        .finalize();
+
+    Ok(response)
+}
+
+#[get("/tilesets/<tileset_id>", format = "image/jpeg", rank = 3)]
+pub fn get_tileset_jpeg<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    tileset_id: u8,
+) -> Result<Response<'a>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let tileset_img = db.get_tileset_img(&tileset_id)?;
+    let img = tileset_img.to_jpeg()?;
+
+    let response = Response::build()
+        .header(ContentType::JPEG)
+        .header(Header::new(
+            "Content-Disposition",
+            format!(r#"attachment; filename="tileset-{}.jpg""#, tileset_id),
+        ))
+        .sized_body(Cursor::new(img))
+        .finalize();
+
+    Ok(response)
+}
+
+#[openapi]
+#[post(
+    "/tilesets/<tileset_id>",
+    format = "application/json",
+    data = "<data>",
+    rank = 1
+)]
+pub fn post_tileset_json(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<TilesetRequest>, JsonError>,
+    tileset_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_tilesets_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let tileset = Tileset {
+        blocks: data.get_blocks(),
+        collision_tiles: data.get_collision_tiles(),
+        counter_tiles: data.get_counter_tiles(),
+        grass_tile: data.get_grass_tile(),
+    };
+
+    let patch = db.set_tileset(&tileset_id, &tileset)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_tilesets,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
+
+#[post(
+    "/tilesets/<tileset_id>",
+    format = "image/png",
+    data = "<data>",
+    rank = 2
+)]
+pub fn post_tileset_png<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    tileset_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let img = Img::from_png(raw_data)?;
+    let patch = db.set_tileset_img(&tileset_id, &img)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_tilesets,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
+
+#[post(
+    "/tilesets/<tileset_id>",
+    format = "image/jpeg",
+    data = "<data>",
+    rank = 3
+)]
+pub fn post_tileset_jpeg<'a>(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    data: Data,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    tileset_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+    let raw_data = utils::get_data_raw(data);
+
+    let img = Img::from_jpeg(raw_data)?;
+    let patch = db.set_tileset_img(&tileset_id, &img)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_tilesets,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
@@ -0,0 +1,91 @@
+use pkmnapi_db::string::*;
+use pkmnapi_db::*;
+use pkmnapi_sql::*;
+use rocket::response::status;
+use rocket::State;
+use rocket_contrib::json::{Json, JsonError, JsonValue};
+use rocket_okapi::openapi;
+
+use crate::guards::*;
+use crate::requests::town_map_locations::*;
+use crate::responses::errors::*;
+use crate::responses::town_map_locations::*;
+use crate::utils;
+
+#[openapi]
+#[get("/town_map/locations")]
+pub fn get_town_map_location_all(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+) -> Result<Json<TownMapLocationResponseAll>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let (min_town_map_location_id, max_town_map_location_id) = db.town_map_location_id_bounds();
+    let town_map_location_ids: Vec<u8> = (min_town_map_location_id..=max_town_map_location_id)
+        .map(|town_map_location_id| town_map_location_id as u8)
+        .collect();
+    let town_map_locations = db.get_town_map_location_all(&town_map_location_ids)?;
+
+    let response = TownMapLocationResponseAll::new(&town_map_location_ids, &town_map_locations);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[get("/town_map/locations/<town_map_location_id>")]
+pub fn get_town_map_location(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    town_map_location_id: u8,
+) -> Result<Json<TownMapLocationResponse>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let town_map_location = db.get_town_map_location(&town_map_location_id)?;
+
+    let response = TownMapLocationResponse::new(&town_map_location_id, &town_map_location);
+
+    Ok(Json(response))
+}
+
+#[openapi]
+#[post(
+    "/town_map/locations/<town_map_location_id>",
+    format = "application/json",
+    data = "<data>"
+)]
+pub fn post_town_map_location(
+    sql: State<PkmnapiSQL>,
+    _rate_limit: RateLimit,
+    access_token: Result<AccessToken, AccessTokenError>,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    data: Result<Json<TownMapLocationRequest>, JsonError>,
+    town_map_location_id: u8,
+) -> Result<status::Accepted<JsonValue>, ResponseError> {
+    let access_token = utils::get_access_token(access_token)?;
+    let data = utils::get_data(data, BaseErrorResponseId::error_town_map_locations_invalid)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
+
+    let town_map_location = TownMapLocation {
+        name: ROMString::try_from(data.get_name())?,
+        x: data.get_x(),
+        y: data.get_y(),
+        map_ids: data.get_map_ids(),
+    };
+
+    let patch = db.set_town_map_location(&town_map_location_id, &town_map_location)?;
+
+    utils::insert_rom_patch(
+        sql,
+        connection,
+        access_token,
+        patch,
+        patch_description,
+        BaseErrorResponseId::error_town_map_locations,
+    )?;
+
+    Ok(status::Accepted(Some(json!({}))))
+}
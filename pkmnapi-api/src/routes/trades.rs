@@ -1,3 +1,4 @@
+use pkmnapi_db::string::*;
 use pkmnapi_db::*;
 use pkmnapi_sql::*;
 use rocket::response::status;
@@ -75,7 +76,7 @@ pub fn post_trade(
     let trade = Trade::new(
         data.get_give_pokedex_id(),
         data.get_get_pokedex_id(),
-        data.get_nickname(),
+        ROMString::try_from(data.get_nickname())?,
     );
 
     let patch = db.set_trade(&trade_id, &trade)?;
@@ -70,7 +70,7 @@ pub fn post_trainer_name(
     let (db, connection) = utils::get_db(&sql, &access_token)?;
 
     let trainer_name = TrainerName {
-        name: ROMString::from(data.get_name()),
+        name: ROMString::try_from(data.get_name())?,
     };
 
     let patch = db.set_trainer_name(&trainer_id, &trainer_name)?;
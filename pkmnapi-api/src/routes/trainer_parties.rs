@@ -89,17 +89,17 @@ pub fn post_trainer_parties(
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let data = utils::get_data(data, BaseErrorResponseId::error_trainer_parties_invalid)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let trainer_parties = data.get_parties();
 
-    let patch = db.set_trainer_parties(&trainer_id, &trainer_parties)?;
+    let patch_set = db.set_trainer_parties(&trainer_id, &trainer_parties)?;
 
     utils::insert_rom_patch(
         sql,
         connection,
         access_token,
-        patch,
+        patch_set,
         patch_description,
         BaseErrorResponseId::error_trainer_parties,
     )?;
@@ -1,3 +1,4 @@
+use pkmnapi_db::img::*;
 use pkmnapi_db::pic::*;
 use pkmnapi_sql::*;
 use rocket::http::{ContentType, Header};
@@ -11,20 +12,33 @@ use crate::guards::*;
 use crate::responses::errors::*;
 use crate::utils;
 
-#[get("/trainers/pics/<trainer_id>?<mirror>", format = "image/png", rank = 1)]
+#[get(
+    "/trainers/pics/<trainer_id>?<mirror>&<palette>",
+    format = "image/png",
+    rank = 1
+)]
 pub fn get_trainer_pic_png<'a>(
     sql: State<PkmnapiSQL>,
     _rate_limit: RateLimit,
     access_token: Result<AccessToken, AccessTokenError>,
     trainer_id: u8,
     mirror: Option<bool>,
+    palette: Option<String>,
 ) -> Result<Response<'a>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
     let (db, _) = utils::get_db_with_applied_patches(&sql, &access_token)?;
 
     let pic = db.get_trainer_pic(&trainer_id)?;
     let trainer_name = db.get_trainer_name(&trainer_id)?;
-    let img = pic.to_png(mirror.is_some())?;
+    let img = match palette {
+        Some(palette) if palette == "sgb" => {
+            let sgb_palette_id = db.get_trainer_sgb_palette_id(&trainer_id)?;
+            let sgb_palette = db.get_sgb_palette(&sgb_palette_id)?;
+
+            pic.to_png_with_palette(mirror.is_some(), &sgb_palette.to_palette())?
+        }
+        _ => pic.to_png(mirror.is_some())?,
+    };
 
     let response = Response::build()
         .header(ContentType::PNG)
@@ -70,7 +84,7 @@ pub fn get_trainer_pic_jpeg<'a>(
 }
 
 #[post(
-    "/trainers/pics/<trainer_id>?<method>&<primary>",
+    "/trainers/pics/<trainer_id>?<method>&<primary>&<palette>",
     format = "image/png",
     data = "<data>",
     rank = 1
@@ -84,13 +98,15 @@ pub fn post_trainer_pic_png<'a>(
     trainer_id: u8,
     method: Option<u8>,
     primary: Option<u8>,
+    palette: Option<String>,
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
     let raw_data = utils::get_data_raw(data);
 
-    let pic = Pic::from_png(raw_data)?;
-    let encoding_method = PicEncodingMethod::from(method.unwrap_or(0x01), primary.unwrap_or(0x00));
+    let pic = Pic::from_png_with_palette(raw_data, &PaletteOrder::from(palette))?;
+    let encoding_method =
+        method.map(|method| PicEncodingMethod::from(method, primary.unwrap_or(0x00)));
     let patch = db.set_trainer_pic(&trainer_id, &pic, encoding_method)?;
 
     utils::insert_rom_patch(
@@ -122,11 +138,12 @@ pub fn post_trainer_pic_jpeg<'a>(
     primary: Option<u8>,
 ) -> Result<status::Accepted<JsonValue>, ResponseError> {
     let access_token = utils::get_access_token(access_token)?;
-    let (db, connection) = utils::get_db(&sql, &access_token)?;
+    let (db, connection) = utils::get_db_with_applied_patches(&sql, &access_token)?;
     let raw_data = utils::get_data_raw(data);
 
     let pic = Pic::from_jpeg(raw_data)?;
-    let encoding_method = PicEncodingMethod::from(method.unwrap_or(0x01), primary.unwrap_or(0x00));
+    let encoding_method =
+        method.map(|method| PicEncodingMethod::from(method, primary.unwrap_or(0x00)));
     let patch = db.set_trainer_pic(&trainer_id, &pic, encoding_method)?;
 
     utils::insert_rom_patch(
@@ -66,7 +66,7 @@ pub fn post_type_name(
     let (db, connection) = utils::get_db(&sql, &access_token)?;
 
     let type_name = TypeName {
-        name: ROMString::from(data.get_name()),
+        name: ROMString::try_from(data.get_name())?,
     };
 
     let patch = db.set_type_name(&type_id, &type_name)?;
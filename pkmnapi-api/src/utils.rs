@@ -103,12 +103,12 @@ pub fn get_db_with_applied_patches(
     };
 
     for patch in rom_patches {
-        db.apply_patch(patch.data);
+        db.apply_patch(patch.data)?;
     }
 
     if let Some(ref mut sav) = db.sav {
         for patch in sav_patches {
-            sav.apply_patch(patch.data);
+            sav.apply_patch(patch.data)?;
         }
     }
 
@@ -124,20 +124,45 @@ pub fn get_patch_description(
     }
 }
 
-pub fn insert_rom_patch(
+pub fn insert_rom_patch<P: Into<PatchSet>>(
     sql: State<PkmnapiSQL>,
     connection: PgPooledConnection,
     access_token: String,
-    patch: Patch,
+    patch: P,
     patch_description: Result<PatchDescription, PatchDescriptionError>,
     error_id: BaseErrorResponseId,
 ) -> Result<(), ResponseError> {
-    let patch_description = get_patch_description(patch_description);
+    let patch_set = patch.into();
+    let patch_description =
+        get_patch_description(patch_description).or(patch_set.description.clone());
 
     match sql.insert_rom_patch(
         &connection,
         &access_token,
-        &patch.to_raw(),
+        &patch_set.to_raw(),
+        patch_description,
+    ) {
+        Ok(_) => Ok(()),
+        Err(e) => return Err(NotFoundError::new(error_id, Some(e.to_string()))),
+    }
+}
+
+pub fn insert_sav_patch<P: Into<PatchSet>>(
+    sql: State<PkmnapiSQL>,
+    connection: PgPooledConnection,
+    access_token: String,
+    patch: P,
+    patch_description: Result<PatchDescription, PatchDescriptionError>,
+    error_id: BaseErrorResponseId,
+) -> Result<(), ResponseError> {
+    let patch_set = patch.into();
+    let patch_description =
+        get_patch_description(patch_description).or(patch_set.description.clone());
+
+    match sql.insert_sav_patch(
+        &connection,
+        &access_token,
+        &patch_set.to_raw(),
         patch_description,
     ) {
         Ok(_) => Ok(()),
@@ -0,0 +1,160 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(post_battle_damage_200, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "battle_damage",
+            "attributes": {
+                "move": {
+                    "id": "52"
+                },
+                "attacker": {
+                    "pokemon": {
+                        "id": "4"
+                    },
+                    "level": 5
+                },
+                "defender": {
+                    "pokemon": {
+                        "id": "1"
+                    },
+                    "level": 5
+                }
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/battle/damage")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "52",
+            "type": "battle_damage",
+            "attributes": {
+                "damage": {
+                    "min": 10,
+                    "max": 12
+                },
+                "critical_damage": {
+                    "min": 15,
+                    "max": 18
+                },
+                "critical_chance": 0.125,
+                "stab": true,
+                "type_multiplier": 2.0
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/battle/damage"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/battle/damage"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_battle_damage_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "battle_damage",
+            "attributes": {
+                "move": {
+                    "id": "52"
+                },
+                "attacker": {
+                    "pokemon": {
+                        "id": "4"
+                    },
+                    "level": 5
+                },
+                "defender": {
+                    "pokemon": {
+                        "id": "1"
+                    },
+                    "level": 5
+                }
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/battle/damage")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_battle_damage_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "battle_damage",
+            "attributes": {
+                "move": {
+                    "id": "52"
+                },
+                "attacker": {
+                    "pokemon": {
+                        "id": "200"
+                    },
+                    "level": 5
+                },
+                "defender": {
+                    "pokemon": {
+                        "id": "1"
+                    },
+                    "level": 5
+                }
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/battle/damage")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid Pokédex ID: 200"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -1,4 +1,4 @@
-use rocket::http::Status;
+use rocket::http::{ContentType, Status};
 use serde_json::json;
 use std::fs;
 
@@ -25,6 +25,27 @@ test!(get_icon_200, (client, access_token) {
     ])
 });
 
+test!(get_icon_200_sgb, (client, access_token) {
+    let request = client
+        .get("/v1/icons/0?palette=sgb")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = fs::read("../secrets/data/icon/0.gif").unwrap();
+
+    assert_ne!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"icon-0.gif\""),
+        ("Content-Type", "image/gif"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
 test!(get_icon_401, (client) {
     let request = client.get("/v1/icons/0");
 
@@ -60,3 +81,89 @@ test!(get_icon_404, (client, access_token) {
         ("Server", "pkmnapi/0.1.0"),
     ])
 });
+
+test!(post_icon_gif_202, (client, access_token) {
+    let request_body = fs::read("../secrets/data/icon/0.gif").unwrap();
+
+    let request = client
+        .post("/v1/icons/0")
+        .body(&request_body)
+        .header(ContentType::GIF)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/icons/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    assert_eq!(response_body, request_body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"icon-0.gif\""),
+        ("Content-Type", "image/gif"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_icon_gif_401, (client) {
+    let request_body = fs::read("../secrets/data/icon/0.gif").unwrap();
+
+    let request = client
+        .post("/v1/icons/0")
+        .body(&request_body)
+        .header(ContentType::GIF);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_icon_gif_404, (client, access_token) {
+    let request_body = fs::read("../secrets/data/icon/0.gif").unwrap();
+
+    let request = client
+        .post("/v1/icons/200")
+        .body(&request_body)
+        .header(ContentType::GIF)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid icon ID 200: valid range is 0-9"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -244,3 +244,91 @@ test!(get_town_map_jpeg_200, (client, access_token) {
         ("Server", "pkmnapi/0.1.0"),
     ])
 });
+
+test!(post_town_map_png_202, (client, access_token) {
+    let request_body = fs::read("../secrets/data/town_map.png").unwrap();
+
+    let request = client
+        .post("/v1/imgs/town_map")
+        .body(request_body)
+        .header(ContentType::PNG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_town_map_png_404, (client, access_token) {
+    let request_body = fs::read("../secrets/data/pokemon_logo.png").unwrap();
+
+    let request = client
+        .post("/v1/imgs/town_map")
+        .body(request_body)
+        .header(ContentType::PNG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Town map size mismatch: should be 20x18 tiles, found 16x7"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_town_map_jpeg_405, (client, access_token) {
+    let request_body = vec![0x01];
+
+    let request = client
+        .post("/v1/imgs/town_map")
+        .body(request_body)
+        .header(ContentType::JPEG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Could not read image"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
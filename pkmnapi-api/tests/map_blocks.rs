@@ -0,0 +1,679 @@
+use pkmnapi_api::responses::map_blocks::MapBlocksResponse;
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_map_blocks_200, (client, access_token) {
+    let request = client
+        .get("/v1/maps/blocks/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let map_blocks: MapBlocksResponse = serde_json::from_str(&response_body).unwrap();
+
+    assert_eq!(map_blocks.data.id, "0");
+    assert_eq!(map_blocks.data.attributes.blocks.len(), 9);
+    assert!(map_blocks
+        .data
+        .attributes
+        .blocks
+        .iter()
+        .all(|row| row.len() == 10));
+    assert_eq!(map_blocks.links._self, "http://localhost:8080/v1/maps/blocks/0");
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_map_blocks_401, (client) {
+    let request = client.get("/v1/maps/blocks/0");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_map_blocks_404, (client, access_token) {
+    let request = client
+        .get("/v1/maps/blocks/255")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_blocks_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_blocks",
+            "attributes": {
+                "blocks": [
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ]
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/blocks/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/maps/blocks/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "map_blocks",
+            "attributes": {
+                "blocks": [
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ]
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/maps/blocks/0"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/maps/blocks/0"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_blocks_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "map_blocks",
+            "attributes": {
+                "blocks": [
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ]
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/blocks/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_map_blocks_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_blocks",
+            "attributes": {
+                "blocks": [
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ],
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ]
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/blocks/255")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_blocks_404_size, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_blocks",
+            "attributes": {
+                "blocks": [
+                    [
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11,
+                        11
+                    ]
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/blocks/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Map size mismatch: should be 10x9 blocks, found 10x1"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -0,0 +1,295 @@
+use pkmnapi_api::responses::map_headers::MapHeaderResponseAll;
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_map_header_all_200, (client, access_token) {
+    let request = client
+        .get("/v1/maps/headers")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = common::load_json::<MapHeaderResponseAll>("../secrets/data/json/get_map_header_all_200.json");
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_map_header_401, (client) {
+    let request = client.get("/v1/maps/headers/0");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_map_header_404, (client, access_token) {
+    let request = client
+        .get("/v1/maps/headers/255")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_header_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_headers",
+            "attributes": {
+                "tileset_id": 0,
+                "height": 9,
+                "width": 10,
+                "blocks_pointer": 16384,
+                "text_pointer": 16474,
+                "script_pointer": 16500,
+                "connections": {
+                    "north": {
+                        "map_id": 12,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    },
+                    "south": {
+                        "map_id": 32,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    }
+                },
+                "objects_pointer": 16600
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/headers/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/maps/headers/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "map_headers",
+            "attributes": {
+                "tileset_id": 0,
+                "height": 9,
+                "width": 10,
+                "blocks_pointer": 16384,
+                "text_pointer": 16474,
+                "script_pointer": 16500,
+                "connections": {
+                    "north": {
+                        "map_id": 12,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    },
+                    "south": {
+                        "map_id": 32,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    }
+                },
+                "objects_pointer": 16600
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/maps/headers/0"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/maps/headers/0"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_header_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "map_headers",
+            "attributes": {
+                "tileset_id": 0,
+                "height": 9,
+                "width": 10,
+                "blocks_pointer": 16384,
+                "text_pointer": 16474,
+                "script_pointer": 16500,
+                "connections": {
+                    "north": {
+                        "map_id": 12,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    },
+                    "south": {
+                        "map_id": 32,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    }
+                },
+                "objects_pointer": 16600
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/headers/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_map_header_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_headers",
+            "attributes": {
+                "tileset_id": 0,
+                "height": 9,
+                "width": 10,
+                "blocks_pointer": 16384,
+                "text_pointer": 16474,
+                "script_pointer": 16500,
+                "connections": {
+                    "north": {
+                        "map_id": 12,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    },
+                    "south": {
+                        "map_id": 32,
+                        "source_pointer": 16384,
+                        "destination_pointer": 50923,
+                        "length": 10,
+                        "width": 10,
+                        "y_alignment": 35,
+                        "x_alignment": 0,
+                        "window_pointer": 51209
+                    }
+                },
+                "objects_pointer": 16600
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/headers/255")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -0,0 +1,199 @@
+use pkmnapi_api::responses::map_hidden_objects::MapHiddenObjectsResponseAll;
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_map_hidden_objects_all_200, (client, access_token) {
+    let request = client
+        .get("/v1/maps/hidden_objects")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = common::load_json::<MapHiddenObjectsResponseAll>("../secrets/data/json/get_map_hidden_objects_all_200.json");
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_map_hidden_objects_401, (client) {
+    let request = client.get("/v1/maps/hidden_objects/38");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_map_hidden_objects_404, (client, access_token) {
+    let request = client
+        .get("/v1/maps/hidden_objects/255")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_hidden_objects_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_hidden_objects",
+            "attributes": {
+                "hidden_objects": [
+                    {
+                        "x": 1,
+                        "y": 2,
+                        "item_id": 4,
+                        "routine_bank_id": 24,
+                        "routine_pointer": 25344
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/hidden_objects/38")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/maps/hidden_objects/38")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "38",
+            "type": "map_hidden_objects",
+            "attributes": {
+                "hidden_objects": [
+                    {
+                        "x": 1,
+                        "y": 2,
+                        "item_id": 4,
+                        "routine_bank_id": 24,
+                        "routine_pointer": 25344
+                    }
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/maps/hidden_objects/38"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/maps/hidden_objects/38"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_hidden_objects_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "map_hidden_objects",
+            "attributes": {
+                "hidden_objects": []
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/hidden_objects/38")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_map_hidden_objects_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_hidden_objects",
+            "attributes": {
+                "hidden_objects": []
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/hidden_objects/255")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -0,0 +1,327 @@
+use pkmnapi_api::responses::map_objects::MapObjectsResponseAll;
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_map_objects_all_200, (client, access_token) {
+    let request = client
+        .get("/v1/maps/objects")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = common::load_json::<MapObjectsResponseAll>("../secrets/data/json/get_map_objects_all_200.json");
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_map_objects_401, (client) {
+    let request = client.get("/v1/maps/objects/0");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_map_objects_404, (client, access_token) {
+    let request = client
+        .get("/v1/maps/objects/255")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_objects_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_objects",
+            "attributes": {
+                "border_block": 11,
+                "warps": [
+                    {
+                        "x": 5,
+                        "y": 5,
+                        "destination_warp_id": 0,
+                        "destination_map_id": 37
+                    }
+                ],
+                "signs": [
+                    {
+                        "x": 7,
+                        "y": 13,
+                        "text_id": 2
+                    }
+                ],
+                "objects": [
+                    {
+                        "object_type": "npc",
+                        "sprite_id": 3,
+                        "x": 8,
+                        "y": 5,
+                        "movement": 255,
+                        "direction": 255,
+                        "text_id": 1
+                    },
+                    {
+                        "object_type": "item",
+                        "sprite_id": 61,
+                        "x": 4,
+                        "y": 4,
+                        "movement": 255,
+                        "direction": 0,
+                        "text_id": 3,
+                        "item_id": 1
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/objects/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/maps/objects/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "map_objects",
+            "attributes": {
+                "border_block": 11,
+                "warps": [
+                    {
+                        "x": 5,
+                        "y": 5,
+                        "destination_warp_id": 0,
+                        "destination_map_id": 37
+                    }
+                ],
+                "signs": [
+                    {
+                        "x": 7,
+                        "y": 13,
+                        "text_id": 2
+                    }
+                ],
+                "objects": [
+                    {
+                        "object_type": "npc",
+                        "sprite_id": 3,
+                        "x": 8,
+                        "y": 5,
+                        "movement": 255,
+                        "direction": 255,
+                        "text_id": 1
+                    },
+                    {
+                        "object_type": "item",
+                        "sprite_id": 61,
+                        "x": 4,
+                        "y": 4,
+                        "movement": 255,
+                        "direction": 0,
+                        "text_id": 3,
+                        "item_id": 1
+                    }
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/maps/objects/0"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/maps/objects/0"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_map_objects_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "map_objects",
+            "attributes": {
+                "border_block": 11,
+                "warps": [
+                    {
+                        "x": 5,
+                        "y": 5,
+                        "destination_warp_id": 0,
+                        "destination_map_id": 37
+                    }
+                ],
+                "signs": [
+                    {
+                        "x": 7,
+                        "y": 13,
+                        "text_id": 2
+                    }
+                ],
+                "objects": [
+                    {
+                        "object_type": "npc",
+                        "sprite_id": 3,
+                        "x": 8,
+                        "y": 5,
+                        "movement": 255,
+                        "direction": 255,
+                        "text_id": 1
+                    },
+                    {
+                        "object_type": "item",
+                        "sprite_id": 61,
+                        "x": 4,
+                        "y": 4,
+                        "movement": 255,
+                        "direction": 0,
+                        "text_id": 3,
+                        "item_id": 1
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/objects/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_map_objects_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "map_objects",
+            "attributes": {
+                "border_block": 11,
+                "warps": [
+                    {
+                        "x": 5,
+                        "y": 5,
+                        "destination_warp_id": 0,
+                        "destination_map_id": 37
+                    }
+                ],
+                "signs": [
+                    {
+                        "x": 7,
+                        "y": 13,
+                        "text_id": 2
+                    }
+                ],
+                "objects": [
+                    {
+                        "object_type": "npc",
+                        "sprite_id": 3,
+                        "x": 8,
+                        "y": 5,
+                        "movement": 255,
+                        "direction": 255,
+                        "text_id": 1
+                    },
+                    {
+                        "object_type": "item",
+                        "sprite_id": 61,
+                        "x": 4,
+                        "y": 4,
+                        "movement": 255,
+                        "direction": 0,
+                        "text_id": 3,
+                        "item_id": 1
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/maps/objects/255")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map ID 255: valid range is 0-247"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -1,5 +1,5 @@
 use pkmnapi_api::responses::pokemon_icons::PokemonIconResponseAll;
-use rocket::http::{ContentType, Status};
+use rocket::http::{Accept, ContentType, Status};
 use serde_json::json;
 
 mod common;
@@ -65,6 +65,33 @@ test!(get_pokemon_icon_200, (client, access_token) {
     ])
 });
 
+test!(get_pokemon_icon_gif_200, (client, access_token) {
+    let request = client
+        .get("/v1/pokemon/icons/1")
+        .header(common::auth_header(&access_token))
+        .header(Accept::GIF);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let request = client
+        .get("/v1/icons/7")
+        .header(common::auth_header(&access_token));
+
+    let mut icon_response = request.dispatch();
+    let body = icon_response.body_bytes().unwrap();
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"BULBASAUR.gif\""),
+        ("Content-Type", "image/gif"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
 test!(get_pokemon_icon_401, (client) {
     let request = client.get("/v1/pokemon/icons/1");
 
@@ -157,6 +157,45 @@ test!(post_pokemon_name_202, (client, access_token) {
     ])
 });
 
+test!(post_pokemon_name_400_characters, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "pokemon_names",
+            "attributes": {
+                "name": "DINO~SAUR"
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/pokemon/names/1")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_strings_invalid",
+            "type": "errors",
+            "attributes": {
+                "message": "Unsupported characters in string: '~' at 4"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::BadRequest);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
 test!(post_pokemon_name_401, (client) {
     let request_body = json!({
         "data": {
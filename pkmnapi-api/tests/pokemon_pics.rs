@@ -1,4 +1,4 @@
-use rocket::http::{Accept, Status};
+use rocket::http::{Accept, ContentType, Status};
 use serde_json::json;
 use std::fs;
 
@@ -25,6 +25,27 @@ test!(get_pokemon_pic_png_200, (client, access_token) {
     ])
 });
 
+test!(get_pokemon_pic_png_200_sgb, (client, access_token) {
+    let request = client
+        .get("/v1/pokemon/pics/1?palette=sgb")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = fs::read("../secrets/data/pokemon_pic/front-1.png").unwrap();
+
+    assert_ne!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"BULBASAUR.png\""),
+        ("Content-Type", "image/png"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
 test!(get_pokemon_pic_png_401, (client) {
     let request = client.get("/v1/pokemon/pics/1");
 
@@ -119,3 +140,170 @@ test!(get_pokemon_pic_jpeg_404, (client, access_token) {
         ("Server", "pkmnapi/0.1.0"),
     ])
 });
+
+test!(get_pokemon_pic_json_200, (client, access_token) {
+    let request = client
+        .get("/v1/pokemon/pics/1")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "1",
+            "type": "pokemon_pics",
+            "attributes": {
+                "width": 5,
+                "height": 5
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/pokemon/pics/1"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/pokemon/pics/1"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_pokemon_pic_json_200_back, (client, access_token) {
+    let request = client
+        .get("/v1/pokemon/pics/1?face=back")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "1",
+            "type": "pokemon_pics",
+            "attributes": {
+                "width": 4,
+                "height": 4
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/pokemon/pics/1"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/pokemon/pics/1"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_pokemon_pic_json_401, (client) {
+    let request = client.get("/v1/pokemon/pics/1").header(Accept::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_pokemon_pic_json_404, (client, access_token) {
+    let request = client
+        .get("/v1/pokemon/pics/200")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid Pokédex ID: 200"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_pokemon_pic_png_202, (client, access_token) {
+    let request_body = fs::read("../secrets/data/pokemon_pic/front-3.png").unwrap();
+
+    let request = client
+        .post("/v1/pokemon/pics/1")
+        .body(&request_body)
+        .header(ContentType::PNG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/pokemon/pics/1")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "1",
+            "type": "pokemon_pics",
+            "attributes": {
+                "width": 7,
+                "height": 7
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/pokemon/pics/1"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/pokemon/pics/1"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -143,6 +143,217 @@ test!(get_rom_patches_raw_401, (client) {
     common::assert_unauthorized(&mut response)
 });
 
+test!(get_rom_patches_bps_200, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "type_names",
+            "attributes": {
+                "name": "BORING"
+            }
+        }
+    });
+
+    client
+        .post("/v1/types/names/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/roms/patches")
+        .header(Accept::new(vec![
+            MediaType::new("application", "x-bps").into()
+        ]))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = vec![
+        0x42, 0x50, 0x53, 0x31, // BPS1
+    ];
+
+    assert_eq!(response_body[..4], body[..]);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"patch.bps\""),
+        ("Content-Type", "application/x-bps"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_rom_patches_bps_401, (client) {
+    let request = client
+        .get("/v1/roms/patches")
+        .header(Accept::new(vec![
+            MediaType::new("application", "x-bps").into()
+        ]));
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_rom_patches_ups_200, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "type_names",
+            "attributes": {
+                "name": "BORING"
+            }
+        }
+    });
+
+    client
+        .post("/v1/types/names/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/roms/patches")
+        .header(Accept::new(vec![
+            MediaType::new("application", "x-ups").into()
+        ]))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = vec![
+        0x55, 0x50, 0x53, 0x31, // UPS1
+    ];
+
+    assert_eq!(response_body[..4], body[..]);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"patch.ups\""),
+        ("Content-Type", "application/x-ups"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_rom_patches_ups_401, (client) {
+    let request = client
+        .get("/v1/roms/patches")
+        .header(Accept::new(vec![
+            MediaType::new("application", "x-ups").into()
+        ]));
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_rom_patches_202, (client, access_token) {
+    let request_body = vec![
+        0x50, 0x41, 0x54, 0x43, 0x48, // PATCH
+        0x02, 0x7D, 0xE4, 0x00, 0x06, 0x81, 0x8E, 0x91, 0x88, 0x8D, 0x86, // DATA
+        0x02, 0x7D, 0xE4, 0x00, 0x00, 0x00, 0x01, 0x81, // RLE
+        0x45, 0x4F, 0x46 // EOF
+    ];
+
+    let request = client
+        .post("/v1/roms/patches")
+        .body(request_body)
+        .header(ContentType::new("application", "patch"))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/types/names/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "type_names",
+            "attributes": {
+                "name": "BORING"
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/types/names/0"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/types/names/0"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_rom_patches_400, (client, access_token) {
+    let request_body = vec![0x13, 0x37];
+
+    let request = client
+        .post("/v1/roms/patches")
+        .body(request_body)
+        .header(ContentType::new("application", "patch"))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_rom_patches_invalid",
+            "type": "errors",
+            "attributes": {
+                "message": "Could not read patch"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::BadRequest);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_rom_patches_401, (client) {
+    let request = client
+        .post("/v1/roms/patches")
+        .header(ContentType::new("application", "patch"));
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
 test!(get_rom_patch_200, (client, access_token) {
     let request_body = json!({
         "data": {
@@ -0,0 +1,186 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_sav_bag_items_200, (client, access_token) {
+    let request = client
+        .get("/v1/savs/bag_items")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "666",
+            "type": "sav_bag_items",
+            "attributes": {
+                "bag_items": [
+                    {
+                        "amount": 5,
+                        "item": {
+                            "id": "4",
+                            "type": "item_names",
+                            "attributes": {
+                                "name": "POKé BALL"
+                            },
+                            "links": {
+                                "self": "http://localhost:8080/v1/items/names/4"
+                            }
+                        }
+                    },
+                    {
+                        "amount": 2,
+                        "item": {
+                            "id": "20",
+                            "type": "item_names",
+                            "attributes": {
+                                "name": "POTION"
+                            },
+                            "links": {
+                                "self": "http://localhost:8080/v1/items/names/20"
+                            }
+                        }
+                    }
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/bag_items"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/bag_items"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_bag_items_401, (client) {
+    let request = client.get("/v1/savs/bag_items");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_sav_bag_items_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_bag_items",
+            "attributes": {
+                "bag_items": [
+                    {
+                        "amount": 10,
+                        "item": {
+                            "id": "4",
+                            "type": "item_names"
+                        }
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/savs/bag_items")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/savs/bag_items")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "666",
+            "type": "sav_bag_items",
+            "attributes": {
+                "bag_items": [
+                    {
+                        "amount": 10,
+                        "item": {
+                            "id": "4",
+                            "type": "item_names",
+                            "attributes": {
+                                "name": "POKé BALL"
+                            },
+                            "links": {
+                                "self": "http://localhost:8080/v1/items/names/4"
+                            }
+                        }
+                    }
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/bag_items"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/bag_items"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_sav_bag_items_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_bag_items",
+            "attributes": {
+                "bag_items": [
+                    {
+                        "amount": 10,
+                        "item": {
+                            "id": "4",
+                            "type": "item_names"
+                        }
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/savs/bag_items")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
@@ -0,0 +1,129 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_sav_current_box_200, (client, access_token) {
+    let request = client
+        .get("/v1/savs/current_box")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "666",
+            "type": "sav_current_box",
+            "attributes": {
+                "current_box": 0
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/current_box"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/current_box"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_current_box_401, (client) {
+    let request = client.get("/v1/savs/current_box");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_sav_current_box_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_current_box",
+            "attributes": {
+                "current_box": 5
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/savs/current_box")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/savs/current_box")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "666",
+            "type": "sav_current_box",
+            "attributes": {
+                "current_box": 5
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/current_box"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/current_box"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_sav_current_box_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_current_box",
+            "attributes": {
+                "current_box": 5
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/savs/current_box")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
@@ -0,0 +1,129 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_sav_money_200, (client, access_token) {
+    let request = client
+        .get("/v1/savs/money")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "666",
+            "type": "sav_money",
+            "attributes": {
+                "money": 123456
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/money"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/money"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_money_401, (client) {
+    let request = client.get("/v1/savs/money");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_sav_money_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_money",
+            "attributes": {
+                "money": 999999
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/savs/money")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/savs/money")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "666",
+            "type": "sav_money",
+            "attributes": {
+                "money": 999999
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/money"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/money"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_sav_money_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_money",
+            "attributes": {
+                "money": 999999
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/savs/money")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
@@ -0,0 +1,474 @@
+use regex::Regex;
+use rocket::http::{Accept, ContentType, Header, MediaType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_sav_patches_200, (client, access_token) {
+    let request = client
+        .get("/v1/savs/patches")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": [],
+        "links": {
+            "self": "http://localhost:8080/v1/savs/patches"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let response_body = Regex::new(r"[a-zA-Z0-9]{32}").unwrap().replace_all(response_body.as_str(), "XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    let headers = response.headers();
+
+    let body = json!({
+        "data": [
+            {
+                "id": "XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+                "type": "sav_patches",
+                "attributes": {},
+                "links": {
+                    "self": "http://localhost:8080/v1/savs/patches/XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+                }
+            }
+        ],
+        "links": {
+            "self": "http://localhost:8080/v1/savs/patches"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_patches_401, (client) {
+    let request = client.get("/v1/savs/patches");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_sav_patches_raw_200, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches?checksum=false")
+        .header(Accept::new(vec![
+            MediaType::new("application", "patch").into()
+        ]))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = vec![
+        0x50, 0x41, 0x54, 0x43, 0x48, // PATCH
+        0x00, 0x25, 0x98, 0x00, 0x05, 0x81, 0x8B, 0x94, 0x84, 0x50, // DATA
+        0x45, 0x4F, 0x46 // EOF
+    ];
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"patch.ips\""),
+        ("Content-Type", "application/patch"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_patches_raw_checksum_200, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches")
+        .header(Accept::new(vec![
+            MediaType::new("application", "patch").into()
+        ]))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    // PATCH + DATA + SAV checksum + box bank checksums + EOF
+    assert_eq!(response_body.len(), 0x05 + 0x0A + 0x06 + (0x0C * 2) + 0x03);
+    assert_eq!(response_body[0x0F..0x14], [0x00, 0x35, 0x23, 0x00, 0x01]);
+    assert_eq!(response_body[0x15..0x1A], [0x00, 0x5A, 0x4C, 0x00, 0x07]);
+    assert_eq!(response_body[0x21..0x26], [0x00, 0x7A, 0x4C, 0x00, 0x07]);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"patch.ips\""),
+        ("Content-Type", "application/patch"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_patches_raw_401, (client) {
+    let request = client
+        .get("/v1/savs/patches")
+        .header(Accept::new(vec![
+            MediaType::new("application", "patch").into()
+        ]));
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_sav_patch_200, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .header(Header::new("X-Patch-Description", "RED -> BLUE"))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let patch_id = (&response_body[16..48]).to_string();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get(format!("/v1/savs/patches/{}", patch_id))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let response_body = Regex::new(r"[a-zA-Z0-9]{32}").unwrap().replace_all(response_body.as_str(), "XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "type": "sav_patches",
+            "attributes": {
+                "description": "RED -> BLUE"
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/savs/patches/XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/savs/patches/XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("ETag", ""),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_sav_patch_401, (client) {
+    let request = client.get("/v1/savs/patches/abcdefgh");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_sav_patch_404, (client, access_token) {
+    let request = client
+        .get("/v1/savs/patches/abcdefgh")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_sav_patches",
+            "type": "errors",
+            "attributes": {
+                "message": "No SAV patch found"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(delete_sav_patch_204, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let patch_id = (&response_body[16..48]).to_string();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get(format!("/v1/savs/patches/{}", patch_id))
+        .header(common::auth_header(&access_token));
+
+    let response = request.dispatch();
+    let headers = response.headers();
+
+    let etag = headers.get("ETag").next().unwrap().to_owned();
+
+    let request = client
+        .delete(format!("/v1/savs/patches/{}", patch_id))
+        .header(common::auth_header(&access_token))
+        .header(Header::new("If-Match", etag));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string();
+    let headers = response.headers();
+
+    assert_eq!(response_body, None);
+    assert_eq!(response.status(), Status::NoContent);
+
+    common::assert_headers(headers, vec![
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(delete_sav_patch_400, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let patch_id = (&response_body[16..48]).to_string();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .delete(format!("/v1/savs/patches/{}", patch_id))
+        .header(common::auth_header(&access_token))
+        .header(Header::new("If-Match", "wrong".to_string()));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_etag_mismatch",
+            "type": "errors",
+            "attributes": {
+                "message": "ETag mismatch"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::BadRequest);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(delete_sav_patch_403, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "sav_player_names",
+            "attributes": {
+                "name": "BLUE"
+            }
+        }
+    });
+
+    client
+        .post("/v1/savs/player_names")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token))
+        .dispatch();
+
+    let request = client
+        .get("/v1/savs/patches")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let patch_id = (&response_body[16..48]).to_string();
+
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .delete(format!("/v1/savs/patches/{}", patch_id))
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_etag_missing",
+            "type": "errors",
+            "attributes": {
+                "message": "If-Match header must be set"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Forbidden);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
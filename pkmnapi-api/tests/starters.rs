@@ -0,0 +1,215 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_starters_200, (client, access_token) {
+    let request = client
+        .get("/v1/starters")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "starters",
+            "attributes": {
+                "pokemon": [
+                    {
+                        "pokedex_id": 4
+                    },
+                    {
+                        "pokedex_id": 7
+                    },
+                    {
+                        "pokedex_id": 1
+                    }
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/starters"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/starters"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_starters_401, (client) {
+    let request = client.get("/v1/starters");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_starters_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "starters",
+            "attributes": {
+                "pokemon": [
+                    {
+                        "pokedex_id": 25
+                    },
+                    {
+                        "pokedex_id": 133
+                    },
+                    {
+                        "pokedex_id": 147
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/starters")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/starters")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "starters",
+            "attributes": {
+                "pokemon": [
+                    {
+                        "pokedex_id": 25
+                    },
+                    {
+                        "pokedex_id": 133
+                    },
+                    {
+                        "pokedex_id": 147
+                    }
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/starters"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/starters"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_starters_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "starters",
+            "attributes": {
+                "pokemon": [
+                    {
+                        "pokedex_id": 4
+                    },
+                    {
+                        "pokedex_id": 7
+                    },
+                    {
+                        "pokedex_id": 1
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/starters")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_starters_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "starters",
+            "attributes": {
+                "pokemon": [
+                    {
+                        "pokedex_id": 4
+                    },
+                    {
+                        "pokedex_id": 7
+                    }
+                ]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/starters")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Starters length mismatch: should be exactly 3 Pokémon, found 2"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
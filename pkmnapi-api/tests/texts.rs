@@ -0,0 +1,202 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_text_200, (client, access_token) {
+    let request = client
+        .get("/v1/texts/43/28289")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "43/28289",
+            "type": "texts",
+            "attributes": {
+                "text": "A strange seed was\nplanted on its\nback at birth.¶The plant sprouts\nand grows with\nthis #MON<DEXEND>",
+                "commands": [
+                    "{text}A strange seed was\nplanted on its\nback at birth.¶The plant sprouts\nand grows with\nthis #MON<DEXEND>"
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/texts/43/28289"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/texts/43/28289"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_text_401, (client) {
+    let request = client.get("/v1/texts/43/28289");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_text_404, (client, access_token) {
+    let request = client
+        .get("/v1/texts/43/12288")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid text pointer 2B:3000: pointer is outside of bank"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_text_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "texts",
+            "attributes": {
+                "text": "ABCDE<DEXEND>"
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/texts/43/28289")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/texts/43/28289")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "43/28289",
+            "type": "texts",
+            "attributes": {
+                "text": "ABCDE<DEXEND>",
+                "commands": [
+                    "{text}ABCDE<DEXEND>"
+                ]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/texts/43/28289"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/texts/43/28289"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_text_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "texts",
+            "attributes": {
+                "text": "ABCDE<DEXEND>"
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/texts/43/28289")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_text_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "texts",
+            "attributes": {
+                "text": "ABCDE"
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/texts/43/28289")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid text markup: text must end with {end}, {asm}, <DONE>, <PROMPT> or <DEXEND>"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -0,0 +1,415 @@
+use pkmnapi_api::responses::tilesets::{TilesetResponse, TilesetResponseAll};
+use rocket::http::{Accept, ContentType, Status};
+use serde_json::json;
+use std::fs;
+
+mod common;
+
+test!(get_tileset_all_200, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = common::load_json::<TilesetResponseAll>("../secrets/data/json/get_tileset_all_200.json");
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_tileset_200, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets/0")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = common::load_json::<TilesetResponse>("../secrets/data/json/get_tileset_200.json");
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_tileset_401, (client) {
+    let request = client.get("/v1/tilesets/0").header(Accept::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(get_tileset_404, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets/100")
+        .header(Accept::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid tileset ID 100: valid range is 0-23"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_tileset_png_200, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets/0")
+        .header(common::auth_header(&access_token))
+        .header(Accept::PNG);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = fs::read("../secrets/data/tileset/0.png").unwrap();
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"tileset-0.png\""),
+        ("Content-Type", "image/png"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_tileset_png_404, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets/100")
+        .header(Accept::PNG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid tileset ID 100: valid range is 0-23"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_tileset_jpeg_200, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets/0")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JPEG);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = fs::read("../secrets/data/tileset/0.jpg").unwrap();
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"tileset-0.jpg\""),
+        ("Content-Type", "image/jpeg"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_tileset_202, (client, access_token) {
+    let request = client
+        .get("/v1/tilesets/0")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+
+    let mut tileset: TilesetResponse = serde_json::from_str(&response_body).unwrap();
+
+    tileset.data.attributes.blocks[0] = vec![1; 16];
+    tileset.data.attributes.collision_tiles.pop();
+    tileset.data.attributes.counter_tiles = vec![0x20];
+    tileset.data.attributes.grass_tile = None;
+
+    let request_body = json!({
+        "data": {
+            "type": "tilesets",
+            "attributes": tileset.data.attributes
+        }
+    });
+
+    let request = client
+        .post("/v1/tilesets/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/tilesets/0")
+        .header(common::auth_header(&access_token))
+        .header(Accept::JSON);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = serde_json::to_string(&tileset).unwrap();
+
+    assert_eq!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_tileset_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "tilesets",
+            "attributes": {
+                "blocks": [],
+                "collision_tiles": [],
+                "counter_tiles": [],
+                "grass_tile": null
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/tilesets/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_tileset_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "tilesets",
+            "attributes": {
+                "blocks": [],
+                "collision_tiles": [],
+                "counter_tiles": [],
+                "grass_tile": null
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/tilesets/100")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid tileset ID 100: valid range is 0-23"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_tileset_404_size, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "tilesets",
+            "attributes": {
+                "blocks": [],
+                "collision_tiles": [],
+                "counter_tiles": [],
+                "grass_tile": null
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/tilesets/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Tileset blocks length mismatch: should be exactly 128 blocks of 16 tiles, found 0"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_tileset_png_202, (client, access_token) {
+    let request_body = fs::read("../secrets/data/tileset/0.png").unwrap();
+
+    let request = client
+        .post("/v1/tilesets/0")
+        .body(&request_body)
+        .header(ContentType::PNG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/tilesets/0")
+        .header(common::auth_header(&access_token))
+        .header(Accept::PNG);
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    assert_eq!(response_body, request_body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"tileset-0.png\""),
+        ("Content-Type", "image/png"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_tileset_png_401, (client) {
+    let request_body = fs::read("../secrets/data/tileset/0.png").unwrap();
+
+    let request = client
+        .post("/v1/tilesets/0")
+        .body(&request_body)
+        .header(ContentType::PNG);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_tileset_png_404, (client, access_token) {
+    let request_body = fs::read("../secrets/data/tileset/0.png").unwrap();
+
+    let request = client
+        .post("/v1/tilesets/100")
+        .body(&request_body)
+        .header(ContentType::PNG)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid tileset ID 100: valid range is 0-23"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
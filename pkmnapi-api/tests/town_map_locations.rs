@@ -0,0 +1,183 @@
+use rocket::http::{ContentType, Status};
+use serde_json::json;
+
+mod common;
+
+test!(get_town_map_location_200, (client, access_token) {
+    let request = client
+        .get("/v1/town_map/locations/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "town_map_locations",
+            "attributes": {
+                "name": "PALLET TOWN",
+                "x": 2,
+                "y": 11,
+                "map_ids": [0]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/town_map/locations/0"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/town_map/locations/0"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(get_town_map_location_401, (client) {
+    let request = client.get("/v1/town_map/locations/0");
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_town_map_location_202, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "town_map_locations",
+            "attributes": {
+                "name": "ABC TOWN",
+                "x": 3,
+                "y": 12,
+                "map_ids": [0]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/town_map/locations/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({});
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Accepted);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ]).unwrap();
+
+    let request = client
+        .get("/v1/town_map/locations/0")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "0",
+            "type": "town_map_locations",
+            "attributes": {
+                "name": "ABC TOWN",
+                "x": 3,
+                "y": 12,
+                "map_ids": [0]
+            },
+            "links": {
+                "self": "http://localhost:8080/v1/town_map/locations/0"
+            }
+        },
+        "links": {
+            "self": "http://localhost:8080/v1/town_map/locations/0"
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
+test!(post_town_map_location_401, (client) {
+    let request_body = json!({
+        "data": {
+            "type": "town_map_locations",
+            "attributes": {
+                "name": "ABC TOWN",
+                "x": 3,
+                "y": 12,
+                "map_ids": [0]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/town_map/locations/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON);
+
+    let mut response = request.dispatch();
+
+    common::assert_unauthorized(&mut response)
+});
+
+test!(post_town_map_location_404, (client, access_token) {
+    let request_body = json!({
+        "data": {
+            "type": "town_map_locations",
+            "attributes": {
+                "name": "ABC TOWN",
+                "x": 3,
+                "y": 12,
+                "map_ids": [0, 1]
+            }
+        }
+    });
+
+    let request = client
+        .post("/v1/town_map/locations/0")
+        .body(request_body.to_string())
+        .header(ContentType::JSON)
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_string().unwrap();
+    let headers = response.headers();
+
+    let body = json!({
+        "data": {
+            "id": "error_not_found",
+            "type": "errors",
+            "attributes": {
+                "message": "Invalid map IDs for town map location 0: should be consecutive map IDs from 0 up to at most 0"
+            }
+        }
+    });
+
+    assert_eq!(response_body, body.to_string());
+    assert_eq!(response.status(), Status::NotFound);
+
+    common::assert_headers(headers, vec![
+        ("Content-Type", "application/json"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
@@ -25,6 +25,27 @@ test!(get_trainer_pic_png_200, (client, access_token) {
     ])
 });
 
+test!(get_trainer_pic_png_200_sgb, (client, access_token) {
+    let request = client
+        .get("/v1/trainers/pics/1?palette=sgb")
+        .header(common::auth_header(&access_token));
+
+    let mut response = request.dispatch();
+    let response_body = response.body_bytes().unwrap();
+    let headers = response.headers();
+
+    let body = fs::read("../secrets/data/trainer_pic/1.png").unwrap();
+
+    assert_ne!(response_body, body);
+    assert_eq!(response.status(), Status::Ok);
+
+    common::assert_headers(headers, vec![
+        ("Content-Disposition", "attachment; filename=\"YOUNGSTER.png\""),
+        ("Content-Type", "image/png"),
+        ("Server", "pkmnapi/0.1.0"),
+    ])
+});
+
 test!(get_trainer_pic_png_401, (client) {
     let request = client.get("/v1/trainers/pics/1");
 
@@ -0,0 +1,524 @@
+//! Pkmnapi battle module
+//!
+//! Gen 1 stat and damage calculations
+//!
+//! # Example
+//!
+//! ```
+//! use pkmnapi_db::battle::*;
+//!
+//! let stats = Stats::calculate(
+//!     &BaseStats::new(45, 49, 49, 45, 65),
+//!     &DVs::new(15, 15, 15, 15),
+//!     &StatExp::default(),
+//!     &5,
+//! );
+//!
+//! assert_eq!(
+//!     stats,
+//!     Stats {
+//!         hp: 21,
+//!         attack: 11,
+//!         defence: 11,
+//!         speed: 11,
+//!         special: 13,
+//!     }
+//! );
+//! ```
+
+use crate::error::Result;
+use crate::{PkmnapiDB, PokemonStats};
+use std::cmp;
+
+/// Moves with an increased critical hit ratio
+///
+/// Karate Chop, Razor Leaf, Crabhammer and Slash
+pub const HIGH_CRITICAL_MOVE_IDS: [u8; 4] = [0x02, 0x4B, 0x98, 0xA3];
+
+/// Highest value a stat can reach in battle
+pub const MAX_STAT: u16 = 999;
+
+/// Damage is capped to this before the final +2
+const MAX_BASE_DAMAGE: u32 = 997;
+
+/// Lowest random multiplier numerator (out of 255)
+const RANDOM_MIN: u32 = 217;
+
+/// Attack types below this ID use attack/defence, otherwise special
+const SPECIAL_TYPE_ID: u8 = 0x14;
+
+/// Badges that boost a stat by 1/8
+const BOULDER_BADGE: u8 = 0;
+const THUNDER_BADGE: u8 = 2;
+const SOUL_BADGE: u8 = 4;
+const VOLCANO_BADGE: u8 = 6;
+
+impl PkmnapiDB {
+    /// Calculate damage done by a move using ROM stats and type chart
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::battle::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let attacker = Battler::new(4, 5);
+    /// let defender = Battler::new(1, 5);
+    ///
+    /// let damage = db.get_battle_damage(&attacker, &defender, &52).unwrap();
+    ///
+    /// assert_eq!(damage.type_multiplier, 2.0);
+    /// assert_eq!(damage.stab, true);
+    /// assert_eq!(damage.damage, DamageRange { min: 10, max: 12 });
+    /// ```
+    pub fn get_battle_damage(
+        &self,
+        attacker: &Battler,
+        defender: &Battler,
+        move_id: &u8,
+    ) -> Result<BattleDamage> {
+        let attacker_base_stats = self.get_pokemon_stats(&attacker.pokedex_id)?;
+        let defender_base_stats = self.get_pokemon_stats(&defender.pokedex_id)?;
+        let move_stats = self.get_move_stats(move_id)?;
+
+        let (min_type_effect_id, max_type_effect_id) = self.type_effect_id_bounds();
+        let type_effect_ids: Vec<u8> = (min_type_effect_id..=max_type_effect_id)
+            .map(|type_effect_id| type_effect_id as u8)
+            .collect();
+        let type_effects = self.get_type_effect_all(&type_effect_ids)?;
+
+        let type_multipliers: Vec<f32> = type_effect_ids
+            .iter()
+            .map(|type_effect_id| type_effects.get(type_effect_id).unwrap())
+            .filter(|type_effect| {
+                type_effect.attacking_type_id == move_stats.type_id
+                    && defender_base_stats
+                        .type_ids
+                        .contains(&type_effect.defending_type_id)
+            })
+            .map(|type_effect| type_effect.multiplier)
+            .collect();
+
+        let stab = attacker_base_stats.type_ids.contains(&move_stats.type_id);
+
+        let attacker_stats = attacker.stats(&attacker_base_stats);
+        let defender_stats = defender.stats(&defender_base_stats);
+
+        let (attack, defence, critical_attack, critical_defence) =
+            if move_stats.type_id < SPECIAL_TYPE_ID {
+                (
+                    attacker_stats.boost(&attacker.badges).attack,
+                    defender_stats.boost(&defender.badges).defence,
+                    attacker_stats.attack,
+                    defender_stats.defence,
+                )
+            } else {
+                (
+                    attacker_stats.boost(&attacker.badges).special,
+                    defender_stats.boost(&defender.badges).special,
+                    attacker_stats.special,
+                    defender_stats.special,
+                )
+            };
+
+        let damage = DamageRange::calculate(
+            &attacker.level,
+            &move_stats.power,
+            &attack,
+            &defence,
+            stab,
+            &type_multipliers,
+        );
+        let critical_damage = DamageRange::calculate(
+            &attacker.level.saturating_mul(2),
+            &move_stats.power,
+            &critical_attack,
+            &critical_defence,
+            stab,
+            &type_multipliers,
+        );
+        let critical_chance = critical_hit_chance(
+            &attacker_base_stats.base_speed,
+            HIGH_CRITICAL_MOVE_IDS.contains(move_id),
+        );
+        let type_multiplier = type_multipliers.iter().product();
+
+        Ok(BattleDamage {
+            damage,
+            critical_damage,
+            critical_chance,
+            stab,
+            type_multiplier,
+        })
+    }
+}
+
+/// Base stats used in stat calculations
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::battle::*;
+///
+/// let base_stats = BaseStats::new(45, 49, 49, 45, 65);
+///
+/// assert_eq!(base_stats.special, 65);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BaseStats {
+    pub hp: u8,
+    pub attack: u8,
+    pub defence: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+impl BaseStats {
+    pub fn new(hp: u8, attack: u8, defence: u8, speed: u8, special: u8) -> Self {
+        BaseStats {
+            hp,
+            attack,
+            defence,
+            speed,
+            special,
+        }
+    }
+}
+
+impl From<&PokemonStats> for BaseStats {
+    fn from(pokemon_stats: &PokemonStats) -> Self {
+        BaseStats {
+            hp: pokemon_stats.base_hp,
+            attack: pokemon_stats.base_attack,
+            defence: pokemon_stats.base_defence,
+            speed: pokemon_stats.base_speed,
+            special: pokemon_stats.base_special,
+        }
+    }
+}
+
+/// Determinant values (0-15)
+///
+/// The HP DV is derived from the lowest bit of the other four.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::battle::*;
+///
+/// let dvs = DVs::new(15, 14, 15, 14);
+///
+/// assert_eq!(dvs.hp(), 10);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DVs {
+    pub attack: u8,
+    pub defence: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+impl DVs {
+    pub fn new(attack: u8, defence: u8, speed: u8, special: u8) -> Self {
+        DVs {
+            attack: attack & 0x0F,
+            defence: defence & 0x0F,
+            speed: speed & 0x0F,
+            special: special & 0x0F,
+        }
+    }
+
+    pub fn hp(&self) -> u8 {
+        ((self.attack & 0x01) << 3)
+            | ((self.defence & 0x01) << 2)
+            | ((self.speed & 0x01) << 1)
+            | (self.special & 0x01)
+    }
+}
+
+/// Stat experience
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::battle::*;
+///
+/// let stat_exp = StatExp::default();
+///
+/// assert_eq!(stat_exp.hp, 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StatExp {
+    pub hp: u16,
+    pub attack: u16,
+    pub defence: u16,
+    pub speed: u16,
+    pub special: u16,
+}
+
+/// Calculated stats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defence: u16,
+    pub speed: u16,
+    pub special: u16,
+}
+
+impl Stats {
+    /// Calculate stats from base stats, DVs, stat experience and level
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::battle::*;
+    ///
+    /// let stats = Stats::calculate(
+    ///     &BaseStats::new(106, 110, 90, 130, 154),
+    ///     &DVs::new(15, 15, 15, 15),
+    ///     &StatExp {
+    ///         hp: 65535,
+    ///         attack: 65535,
+    ///         defence: 65535,
+    ///         speed: 65535,
+    ///         special: 65535,
+    ///     },
+    ///     &100,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     stats,
+    ///     Stats {
+    ///         hp: 415,
+    ///         attack: 318,
+    ///         defence: 278,
+    ///         speed: 358,
+    ///         special: 406,
+    ///     }
+    /// );
+    /// ```
+    pub fn calculate(base_stats: &BaseStats, dvs: &DVs, stat_exp: &StatExp, level: &u8) -> Self {
+        let stat = |base: u8, dv: u8, exp: u16| -> u16 {
+            let value =
+                ((((base as u32) + (dv as u32)) * 2 + stat_exp_bonus(exp)) * (*level as u32)) / 100;
+
+            value as u16
+        };
+
+        Stats {
+            hp: stat(base_stats.hp, dvs.hp(), stat_exp.hp) + (*level as u16) + 10,
+            attack: stat(base_stats.attack, dvs.attack, stat_exp.attack) + 5,
+            defence: stat(base_stats.defence, dvs.defence, stat_exp.defence) + 5,
+            speed: stat(base_stats.speed, dvs.speed, stat_exp.speed) + 5,
+            special: stat(base_stats.special, dvs.special, stat_exp.special) + 5,
+        }
+    }
+
+    /// Apply badge boosts
+    ///
+    /// Boulder, Thunder, Soul and Volcano badges raise attack, defence,
+    /// speed and special by 1/8 respectively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::battle::*;
+    ///
+    /// let stats = Stats {
+    ///     hp: 100,
+    ///     attack: 100,
+    ///     defence: 100,
+    ///     speed: 100,
+    ///     special: 100,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     stats.boost(&vec![0, 6]),
+    ///     Stats {
+    ///         hp: 100,
+    ///         attack: 112,
+    ///         defence: 100,
+    ///         speed: 100,
+    ///         special: 112,
+    ///     }
+    /// );
+    /// ```
+    pub fn boost(&self, badges: &Vec<u8>) -> Self {
+        let boost = |stat: u16, badge: u8| -> u16 {
+            if badges.contains(&badge) {
+                cmp::min(stat + (stat / 8), MAX_STAT)
+            } else {
+                stat
+            }
+        };
+
+        Stats {
+            hp: self.hp,
+            attack: boost(self.attack, BOULDER_BADGE),
+            defence: boost(self.defence, THUNDER_BADGE),
+            speed: boost(self.speed, SOUL_BADGE),
+            special: boost(self.special, VOLCANO_BADGE),
+        }
+    }
+}
+
+/// Pokémon taking part in a damage calculation
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::battle::*;
+///
+/// let battler = Battler::new(25, 50);
+///
+/// assert_eq!(battler.dvs, DVs::new(0, 0, 0, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Battler {
+    pub pokedex_id: u8,
+    pub level: u8,
+    pub dvs: DVs,
+    pub stat_exp: StatExp,
+    pub badges: Vec<u8>,
+}
+
+impl Battler {
+    pub fn new(pokedex_id: u8, level: u8) -> Self {
+        Battler {
+            pokedex_id,
+            level,
+            dvs: DVs::default(),
+            stat_exp: StatExp::default(),
+            badges: vec![],
+        }
+    }
+
+    pub fn stats(&self, pokemon_stats: &PokemonStats) -> Stats {
+        Stats::calculate(
+            &BaseStats::from(pokemon_stats),
+            &self.dvs,
+            &self.stat_exp,
+            &self.level,
+        )
+    }
+}
+
+/// Range of damage a move can deal, from the lowest to highest random roll
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageRange {
+    pub min: u16,
+    pub max: u16,
+}
+
+impl DamageRange {
+    /// Calculate damage range
+    ///
+    /// Type multipliers are applied in type chart order, one per matching
+    /// entry, as the game does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::battle::*;
+    ///
+    /// let damage = DamageRange::calculate(&50, &95, &150, &100, true, &vec![2.0]);
+    ///
+    /// assert_eq!(damage, DamageRange { min: 163, max: 192 });
+    ///
+    /// let damage = DamageRange::calculate(&50, &95, &150, &100, false, &vec![0.0]);
+    ///
+    /// assert_eq!(damage, DamageRange { min: 0, max: 0 });
+    /// ```
+    pub fn calculate(
+        level: &u8,
+        power: &u8,
+        attack: &u16,
+        defence: &u16,
+        stab: bool,
+        type_multipliers: &Vec<f32>,
+    ) -> Self {
+        if *power == 0 {
+            return DamageRange { min: 0, max: 0 };
+        }
+
+        let (attack, defence) = if *attack > 0xFF || *defence > 0xFF {
+            ((*attack / 4) & 0xFF, (*defence / 4) & 0xFF)
+        } else {
+            (*attack, *defence)
+        };
+        let attack = attack as u32;
+        let defence = cmp::max(defence as u32, 1);
+
+        let damage = (((*level as u32) * 2) / 5) + 2;
+        let damage = (damage * (*power as u32) * attack) / defence / 50;
+        let damage = cmp::min(damage, MAX_BASE_DAMAGE) + 2;
+
+        let damage = if stab { damage + (damage / 2) } else { damage };
+
+        let damage = type_multipliers.iter().fold(damage, |damage, multiplier| {
+            (damage * ((multiplier * 10.0) as u32)) / 10
+        });
+
+        if damage <= 1 {
+            return DamageRange {
+                min: damage as u16,
+                max: damage as u16,
+            };
+        }
+
+        DamageRange {
+            min: ((damage * RANDOM_MIN) / 255) as u16,
+            max: damage as u16,
+        }
+    }
+}
+
+/// Result of a damage calculation
+#[derive(Debug, Clone, PartialEq)]
+pub struct BattleDamage {
+    pub damage: DamageRange,
+    pub critical_damage: DamageRange,
+    pub critical_chance: f32,
+    pub stab: bool,
+    pub type_multiplier: f32,
+}
+
+/// Chance of landing a critical hit, based on the attacker's base speed
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::battle::*;
+///
+/// assert_eq!(critical_hit_chance(&100, false), 50.0 / 256.0);
+/// assert_eq!(critical_hit_chance(&100, true), 255.0 / 256.0);
+/// ```
+pub fn critical_hit_chance(base_speed: &u8, high_critical: bool) -> f32 {
+    let threshold = (*base_speed as u32) / 2;
+    let threshold = if high_critical {
+        cmp::min(threshold * 8, 0xFF)
+    } else {
+        threshold
+    };
+
+    (threshold as f32) / 256.0
+}
+
+/// Stat bonus from stat experience: ceil(sqrt(stat_exp)) / 4, capped at 255
+fn stat_exp_bonus(stat_exp: u16) -> u32 {
+    let stat_exp = stat_exp as u32;
+    let mut root = 0u32;
+
+    while root * root < stat_exp && root < 0xFF {
+        root += 1;
+    }
+
+    root / 4
+}
@@ -1,7 +1,9 @@
-use crate::error::Result;
-use crate::header::Header;
+use crate::error::{self, Result};
+use crate::header::{Game, Header};
+use crate::offsets::Offsets;
 use crate::sav::Sav;
 use crate::PkmnapiDB;
+use std::convert::TryFrom;
 
 #[derive(Debug)]
 pub struct PkmnapiDBBuilder {
@@ -19,6 +21,14 @@ impl PkmnapiDBBuilder {
     pub fn build(self) -> Result<PkmnapiDB> {
         let hash = format!("{:x}", md5::compute(&self.rom));
         let header = Header::from(&self.rom)?;
+
+        // Unrecognised ROMs, e.g. hacks with a new title, are read as Red/Blue
+        let game = Game::try_from(&header).unwrap_or(Game::RED);
+        let offsets = match Offsets::from_game(&game) {
+            Some(offsets) => offsets,
+            None => return Err(error::Error::GameUnsupported(header.title)),
+        };
+
         let rom = self.rom[..].to_vec();
         let sav = match self.sav {
             Some(sav) => Some(Sav::new(&sav)?),
@@ -30,6 +40,9 @@ impl PkmnapiDBBuilder {
             sav,
             hash,
             header,
+            game,
+            offsets,
+            allocations: vec![],
         })
     }
 }
@@ -4,13 +4,15 @@ use crate::PkmnapiDB;
 
 impl PkmnapiDB {
     pub fn get_game_boy_img(&self) -> Result<Img> {
-        let graphics_offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let graphics_offset = graphics_offset_base + 0x29BE;
+        let (bank_id, offset) = self.offsets.game_boy_tiles;
+        let graphics_offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let graphics_offset = graphics_offset_base + offset;
 
         let graphics_tiles = self.get_tiles(graphics_offset, 83, true);
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1E;
-        let offset = offset_base + 0x1C20;
+        let (bank_id, offset) = self.offsets.game_boy_img;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let tiles: Vec<Vec<u8>> = self.rom[offset..(offset + 0x30)]
             .iter()
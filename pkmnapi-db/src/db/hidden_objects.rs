@@ -0,0 +1,323 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl PkmnapiDB {
+    /// Get all hidden objects
+    ///
+    /// Maps without hidden objects are skipped.
+    pub fn get_hidden_objects_all(
+        &self,
+        map_ids: &Vec<u8>,
+    ) -> Result<HashMap<u8, Vec<HiddenObject>>> {
+        let mut hidden_objects = HashMap::new();
+
+        for map_id in map_ids {
+            match self.get_hidden_objects(map_id) {
+                Ok(map_hidden_objects) => {
+                    hidden_objects.insert(*map_id, map_hidden_objects);
+                }
+                Err(error::Error::HiddenObjectsMapInvalid(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(hidden_objects)
+    }
+
+    /// Get hidden objects by map ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let hidden_objects = db.get_hidden_objects(&0x01).unwrap();
+    ///
+    /// assert!(hidden_objects.len() > 0);
+    /// assert_eq!(
+    ///     hidden_objects[0].to_raw().len(),
+    ///     0x06
+    /// );
+    /// ```
+    pub fn get_hidden_objects(&self, map_id: &u8) -> Result<Vec<HiddenObject>> {
+        let (_, hidden_objects_offset) = self.get_hidden_objects_offset(map_id)?;
+
+        let hidden_objects = self.rom[hidden_objects_offset..]
+            .chunks(0x06)
+            .take_while(|chunk| chunk[0] != 0xFF)
+            .map(|chunk| HiddenObject::from(chunk))
+            .collect();
+
+        Ok(hidden_objects)
+    }
+
+    /// Set hidden objects by map ID
+    ///
+    /// Hidden objects that no longer fit are moved to free space in the bank
+    /// of the hidden object tables and the map's pointer is updated. Only maps
+    /// that already have hidden objects can be changed.
+    ///
+    /// Hidden items and coins are picked up once, using the index of their
+    /// map and coordinates in a table of pickup flags. Moving one updates its
+    /// entry in that table. New ones must be placed where the table already
+    /// has an entry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let mut hidden_objects = db.get_hidden_objects(&0x01).unwrap();
+    ///
+    /// hidden_objects[0].x = 0x00;
+    /// hidden_objects[0].y = 0x00;
+    ///
+    /// let patch_set = db.set_hidden_objects(&0x01, &hidden_objects).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_hidden_objects(&0x01).unwrap(), hidden_objects);
+    /// ```
+    pub fn set_hidden_objects(
+        &self,
+        map_id: &u8,
+        hidden_objects: &Vec<HiddenObject>,
+    ) -> Result<PatchSet> {
+        let (pointer_offset, hidden_objects_offset) = self.get_hidden_objects_offset(map_id)?;
+
+        if let Some(hidden_object) = hidden_objects.iter().find(|object| object.y == 0xFF) {
+            return Err(error::Error::HiddenObjectCoordinatesInvalid(
+                hidden_object.x,
+                hidden_object.y,
+            ));
+        }
+
+        let old_hidden_objects = self.get_hidden_objects(map_id)?;
+        let mut patch_set = PatchSet::new(&vec![]);
+
+        for (i, hidden_object) in hidden_objects.iter().enumerate() {
+            let coords_table_offset =
+                match self.get_hidden_object_coords_table_offset(hidden_object) {
+                    Some(coords_table_offset) => coords_table_offset,
+                    None => continue,
+                };
+
+            if self
+                .get_hidden_object_coords_offset(coords_table_offset, map_id, hidden_object)
+                .is_some()
+            {
+                continue;
+            }
+
+            let old_coords_offset = old_hidden_objects
+                .get(i)
+                .filter(|old_hidden_object| {
+                    old_hidden_object.routine_bank_id == hidden_object.routine_bank_id
+                        && old_hidden_object.routine_pointer == hidden_object.routine_pointer
+                })
+                .and_then(|old_hidden_object| {
+                    self.get_hidden_object_coords_offset(
+                        coords_table_offset,
+                        map_id,
+                        old_hidden_object,
+                    )
+                })
+                .ok_or(error::Error::HiddenObjectFlagMissing(
+                    hidden_object.x,
+                    hidden_object.y,
+                ))?;
+
+            patch_set.push(Patch::new(
+                &(old_coords_offset + 1),
+                &vec![hidden_object.y, hidden_object.x],
+            ));
+        }
+
+        let old_hidden_objects_data_len = (old_hidden_objects.len() * 0x06) + 1;
+        let hidden_objects_data: Vec<u8> = hidden_objects
+            .iter()
+            .map(|hidden_object| hidden_object.to_raw())
+            .flatten()
+            .chain(vec![0xFF])
+            .collect();
+
+        if hidden_objects_data.len() <= old_hidden_objects_data_len {
+            patch_set.push(Patch::new(&hidden_objects_offset, &hidden_objects_data));
+
+            return Ok(patch_set);
+        }
+
+        let (bank_id, _) = self.offsets.hidden_objects;
+        let new_hidden_objects_offset =
+            self.get_free_space(&bank_id, &hidden_objects_data.len())?;
+
+        patch_set.push(Patch::new(&new_hidden_objects_offset, &hidden_objects_data));
+        patch_set.push(Patch::new(
+            &pointer_offset,
+            &PkmnapiDB::offset_to_pointer(new_hidden_objects_offset),
+        ));
+
+        Ok(patch_set)
+    }
+
+    /// Returns the offset of the table of map and coordinates that a hidden
+    /// object's routine looks itself up in, if it has one
+    ///
+    /// The hidden item and coin routines start with `ld hl, <table>` and
+    /// `call FindHiddenItemOrCoinsIndex`, which loads the object's Y and X
+    /// coordinates and the current map before searching the table.
+    fn get_hidden_object_coords_table_offset(&self, hidden_object: &HiddenObject) -> Option<usize> {
+        let bank = (hidden_object.routine_bank_id as usize).checked_sub(1)? * PkmnapiDB::ROM_PAGE;
+        let routine_offset = bank + (hidden_object.routine_pointer as usize);
+        let routine = self.rom.get(routine_offset..(routine_offset + 0x10))?;
+
+        routine.windows(0x06).find_map(|code| {
+            let table_pointer = u16::from_le_bytes([code[1], code[2]]) as usize;
+            let call_pointer = u16::from_le_bytes([code[4], code[5]]) as usize;
+
+            if code[0] != 0x21
+                || code[3] != 0xCD
+                || !(0x4000..0x8000).contains(&table_pointer)
+                || !(0x4000..0x8000).contains(&call_pointer)
+            {
+                return None;
+            }
+
+            let find_index = self
+                .rom
+                .get((bank + call_pointer)..(bank + call_pointer + 0x0C))?;
+
+            if find_index[0] == 0xFA
+                && find_index[3] == 0x57
+                && find_index[4] == 0xFA
+                && find_index[7] == 0x5F
+                && find_index[8] == 0xFA
+                && find_index[11] == 0x47
+            {
+                Some(bank + table_pointer)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the offset of a hidden object's entry in a table of map and
+    /// coordinates
+    ///
+    /// Entries are the map ID, Y coordinate and X coordinate, ending with
+    /// 0xFF.
+    fn get_hidden_object_coords_offset(
+        &self,
+        coords_table_offset: usize,
+        map_id: &u8,
+        hidden_object: &HiddenObject,
+    ) -> Option<usize> {
+        self.rom[coords_table_offset..]
+            .chunks(0x03)
+            .take_while(|chunk| chunk[0] != 0xFF)
+            .position(|chunk| chunk == [*map_id, hidden_object.y, hidden_object.x])
+            .map(|index| coords_table_offset + (index * 0x03))
+    }
+
+    /// Returns the offset of the map's hidden objects pointer and the offset
+    /// of its hidden objects
+    ///
+    /// The list of maps with hidden objects is directly followed by one
+    /// pointer per map.
+    fn get_hidden_objects_offset(&self, map_id: &u8) -> Result<(usize, usize)> {
+        self.map_id_validate(map_id)?;
+
+        let (bank_id, offset) = self.offsets.hidden_objects;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let maps_offset = offset_base + offset;
+
+        let map_ids: Vec<u8> = self.rom[maps_offset..]
+            .iter()
+            .take_while(|&x| *x != 0xFF)
+            .map(|x| *x)
+            .collect();
+
+        let index = map_ids
+            .iter()
+            .position(|hidden_object_map_id| hidden_object_map_id == map_id)
+            .ok_or(error::Error::HiddenObjectsMapInvalid(*map_id))?;
+
+        let pointer_offset = maps_offset + map_ids.len() + 1 + (index * 0x02);
+        let hidden_objects_offset =
+            offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(pointer_offset);
+
+        Ok((pointer_offset, hidden_objects_offset))
+    }
+}
+
+/// Hidden object (hidden item or event)
+///
+/// Hidden items use `item_id` as the item ID. Other hidden objects pass it to
+/// their routine, e.g. as a text ID.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let rom = vec![0x04, 0x0E, 0x2D, 0x1D, 0xEE, 0x6E];
+/// let hidden_object = HiddenObject::from(&rom[..]);
+///
+/// assert_eq!(
+///     hidden_object,
+///     HiddenObject {
+///         x: 14,
+///         y: 4,
+///         item_id: 0x2D,
+///         routine_bank_id: 0x1D,
+///         routine_pointer: 0x6EEE,
+///     }
+/// );
+/// assert_eq!(hidden_object.to_raw(), rom);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct HiddenObject {
+    pub x: u8,
+    pub y: u8,
+    pub item_id: u8,
+    pub routine_bank_id: u8,
+    pub routine_pointer: u16,
+}
+
+impl From<&[u8]> for HiddenObject {
+    /// Convert &[u8] to HiddenObject
+    fn from(rom: &[u8]) -> Self {
+        HiddenObject {
+            x: rom[1],
+            y: rom[0],
+            item_id: rom[2],
+            routine_bank_id: rom[3],
+            routine_pointer: u16::from_le_bytes([rom[4], rom[5]]),
+        }
+    }
+}
+
+impl HiddenObject {
+    pub fn to_raw(&self) -> Vec<u8> {
+        [
+            vec![self.y, self.x, self.item_id, self.routine_bank_id],
+            self.routine_pointer.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -28,7 +29,7 @@ impl PkmnapiDB {
     pub fn get_hm_move(&self, hm_id: &u8) -> Result<HMMove> {
         let _max_id = self.hm_id_validate(hm_id)?;
 
-        let offset_base = 0x3052;
+        let offset_base = self.offsets.hm_moves;
         let offset = offset_base + ((*hm_id as usize) - 1);
 
         let hm_move = HMMove::from(self.rom[offset]);
@@ -64,7 +65,7 @@ impl PkmnapiDB {
     pub fn set_hm_move(&self, hm_id: &u8, hm: &HMMove) -> Result<Patch> {
         let _max_id = self.hm_id_validate(hm_id)?;
 
-        let offset_base = 0x3052;
+        let offset_base = self.offsets.hm_moves;
         let offset = offset_base + ((*hm_id as usize) - 1);
 
         Ok(Patch::new(&offset, &hm.to_raw()))
@@ -82,7 +83,7 @@ impl PkmnapiDB {
 ///
 /// assert_eq!(hm_move, HMMove { move_id: 0x01 });
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct HMMove {
     pub move_id: u8,
 }
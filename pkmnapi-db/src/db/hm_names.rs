@@ -29,7 +29,7 @@ impl PkmnapiDB {
     pub fn get_hm_name(&self, hm_id: &u8) -> Result<HMName> {
         let _max_id = self.hm_id_validate(hm_id)?;
 
-        let offset = 0x303E;
+        let offset = self.offsets.hm_name;
         let hm_prefix = ROMString::new(&self.rom[offset..(offset + 2)]);
 
         let hm_name = HMName {
@@ -1,12 +1,16 @@
-use crate::error::Result;
+use crate::error::{self, Result};
 use crate::img::*;
+use crate::patch::*;
 use crate::PkmnapiDB;
 use std::cmp;
 
 impl PkmnapiDB {
-    fn get_icon_frame(&self, icon_id: &u8, frame_index: &u8) -> Result<Img> {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = offset_base + 0x17C0;
+    /// Returns the pointer and tile count of each tile run that makes up an
+    /// icon frame
+    fn get_icon_frame_data(&self, icon_id: &u8, frame_index: &u8) -> Vec<(usize, usize)> {
+        let (bank_id, offset) = self.offsets.icon;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let frame_index = cmp::min(*frame_index as usize, 1);
 
@@ -23,8 +27,10 @@ impl PkmnapiDB {
             })
             .collect();
 
-        let icon_datum = if *icon_id == 2 {
-            vec![((PkmnapiDB::ROM_PAGE * 0x04) + 0x1180, 4)]
+        if *icon_id == 2 {
+            let (bank_id, offset) = self.offsets.icon_ball;
+
+            vec![((PkmnapiDB::ROM_PAGE * bank_id) + offset, 4)]
         } else if *icon_id < 6 {
             let icon_data_index = if *icon_id >= 3 {
                 (*icon_id as usize) - 1
@@ -37,7 +43,12 @@ impl PkmnapiDB {
             let icon_data_index = (5 + (((*icon_id as usize) - 6) * 2)) + (frame_index * 14);
 
             vec![icon_data[icon_data_index], icon_data[icon_data_index + 1]]
-        };
+        }
+    }
+
+    fn get_icon_frame(&self, icon_id: &u8, frame_index: &u8) -> Result<Img> {
+        let icon_datum = self.get_icon_frame_data(icon_id, frame_index);
+        let frame_index = cmp::min(*frame_index as usize, 1);
 
         let mut tiles: Vec<Vec<u8>> = icon_datum
             .iter()
@@ -123,4 +134,152 @@ impl PkmnapiDB {
 
         Ok(gif)
     }
+
+    /// Set icon by icon ID
+    ///
+    /// Takes both animation frames, each 2x2 tiles. Every icon but icon 2
+    /// only stores its left half and mirrors it, so the right half of those
+    /// frames must be a mirror of the left half. The second frame of icons 1
+    /// and 2 is drawn one pixel higher. Frames that share tiles must agree
+    /// on them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let icon = db.get_icon(&7).unwrap();
+    /// let patch_set = db.set_icon(&7, &icon.frames).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_icon(&7).unwrap(), icon);
+    /// ```
+    pub fn set_icon(&self, icon_id: &u8, frames: &Vec<Img>) -> Result<PatchSet> {
+        let (_min_id, _max_id) = self.icon_id_validate(icon_id)?;
+
+        if frames.len() != 2 {
+            return Err(error::Error::IconFramesWrongSize(2, frames.len()));
+        }
+
+        let mut patches: Vec<Patch> = vec![];
+
+        for (frame_index, frame) in frames.iter().enumerate() {
+            for patch in self.get_icon_frame_patches(icon_id, &(frame_index as u8), frame)? {
+                match patches.iter().find(|other| other.offset == patch.offset) {
+                    Some(other) if other.data != patch.data => {
+                        return Err(error::Error::IconFramesMismatch(*icon_id));
+                    }
+                    Some(_) => {}
+                    None => patches.push(patch),
+                }
+            }
+        }
+
+        Ok(PatchSet::from(patches))
+    }
+
+    /// Set one icon frame by icon ID
+    ///
+    /// Frames follow the same rules as `set_icon`. Icons whose frames share
+    /// tiles have both frames updated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let icon = db.get_icon(&2).unwrap();
+    /// let patch_set = db.set_icon_frame(&2, &1, &icon.frames[1]).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_icon(&2).unwrap(), icon);
+    /// ```
+    pub fn set_icon_frame(&self, icon_id: &u8, frame_index: &u8, frame: &Img) -> Result<PatchSet> {
+        let (_min_id, _max_id) = self.icon_id_validate(icon_id)?;
+
+        let patches = self.get_icon_frame_patches(icon_id, frame_index, frame)?;
+
+        Ok(PatchSet::from(patches))
+    }
+
+    /// Returns the patches that write an icon frame's tiles
+    fn get_icon_frame_patches(
+        &self,
+        icon_id: &u8,
+        frame_index: &u8,
+        frame: &Img,
+    ) -> Result<Vec<Patch>> {
+        if frame.width != 2 || frame.height != 2 {
+            return Err(error::Error::IconImgWrongSize(frame.width, frame.height));
+        }
+
+        let mut frame = frame.clone();
+
+        if [1, 2].contains(icon_id) && *frame_index >= 1 {
+            let split = frame.pixels.len() - 16;
+
+            frame.pixels = [
+                frame.pixels[split..].to_vec(),
+                frame.pixels[..split].to_vec(),
+            ]
+            .concat();
+        }
+
+        let mirrored = frame.pixels.chunks(16).all(|row| {
+            let mut mirrored_row = row.to_vec();
+
+            mirrored_row.reverse();
+
+            row == &mirrored_row[..]
+        });
+
+        if *icon_id != 2 && !mirrored {
+            return Err(error::Error::IconNotMirrored(*icon_id));
+        }
+
+        let tiles: Vec<Vec<u8>> = frame
+            .to_2bpp()?
+            .chunks(0x10)
+            .map(|tile| tile.to_vec())
+            .collect();
+
+        let tile_offsets: Vec<usize> = self
+            .get_icon_frame_data(icon_id, frame_index)
+            .iter()
+            .map(|(pointer, tile_count)| (0..*tile_count).map(move |i| pointer + (i * 0x10)))
+            .flatten()
+            .collect();
+
+        // Pairs of frame tile index and stored tile index
+        let tile_indices = if *icon_id == 2 {
+            vec![(0, 0), (1, 1), (2, 2), (3, 3)]
+        } else if *icon_id < 6 {
+            vec![(0, 0), (2, 2)]
+        } else {
+            vec![(0, 0), (2, 1)]
+        };
+
+        let patches = tile_indices
+            .iter()
+            .map(|(tile_index, tile_offset_index)| {
+                Patch::new(&tile_offsets[*tile_offset_index], &tiles[*tile_index])
+            })
+            .collect();
+
+        Ok(patches)
+    }
 }
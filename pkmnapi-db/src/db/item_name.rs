@@ -35,8 +35,9 @@ impl PkmnapiDB {
     pub fn get_item_name(&self, item_id: &u8) -> Result<ItemName> {
         let (min_id, max_id) = self.item_id_validate(item_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE;
-        let offset_base = offset_base + 0x072B;
+        let (bank_id, offset) = self.offsets.item_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_base = offset_base + offset;
         let offset = self.rom[offset_base..]
             .iter()
             .enumerate()
@@ -113,8 +114,9 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE;
-        let offset_base = offset_base + 0x072B;
+        let (bank_id, offset) = self.offsets.item_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_base = offset_base + offset;
         let offset = self.rom[offset_base..]
             .iter()
             .enumerate()
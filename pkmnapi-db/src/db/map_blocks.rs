@@ -0,0 +1,99 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::PkmnapiDB;
+
+impl PkmnapiDB {
+    /// Get map blocks by map ID
+    ///
+    /// Blocks are returned as rows of block IDs, `height` rows of `width`
+    /// blocks each.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let map_blocks = db.get_map_blocks(&0).unwrap();
+    ///
+    /// assert_eq!(map_blocks.len(), 9);
+    /// assert_eq!(map_blocks[0].len(), 10);
+    /// ```
+    pub fn get_map_blocks(&self, map_id: &u8) -> Result<Vec<Vec<u8>>> {
+        let (offset, width, height) = self.get_map_blocks_offset(map_id)?;
+
+        let map_blocks = self.rom[offset..(offset + (width * height))]
+            .chunks(width)
+            .map(|row| row.to_vec())
+            .collect();
+
+        Ok(map_blocks)
+    }
+
+    /// Set map blocks by map ID
+    ///
+    /// The grid must match the dimensions in the map's header. Maps sharing
+    /// the same block data are updated together.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let map_blocks = vec![vec![0x0B; 10]; 9];
+    ///
+    /// let patch = db.set_map_blocks(&0, &map_blocks).unwrap();
+    ///
+    /// assert_eq!(patch.length, 90);
+    ///
+    /// db.apply_patch(patch).unwrap();
+    ///
+    /// assert_eq!(db.get_map_blocks(&0).unwrap(), map_blocks);
+    /// ```
+    pub fn set_map_blocks(&self, map_id: &u8, map_blocks: &Vec<Vec<u8>>) -> Result<Patch> {
+        let (offset, width, height) = self.get_map_blocks_offset(map_id)?;
+
+        let blocks_height = map_blocks.len();
+        let blocks_width = map_blocks.iter().map(|row| row.len()).max().unwrap_or(0);
+
+        if blocks_height != height || map_blocks.iter().any(|row| row.len() != width) {
+            return Err(error::Error::MapBlocksWrongSize(
+                width,
+                height,
+                blocks_width,
+                blocks_height,
+            ));
+        }
+
+        let map_blocks_data: Vec<u8> = map_blocks.iter().flatten().copied().collect();
+
+        Ok(Patch::new(&offset, &map_blocks_data))
+    }
+
+    /// Returns the offset of the map's blocks and its width and height
+    fn get_map_blocks_offset(&self, map_id: &u8) -> Result<(usize, usize, usize)> {
+        let (bank_id, _) = self.get_map_header_offset(map_id)?;
+        let map_header = self.get_map_header(map_id)?;
+
+        let bank = (bank_id - 0x01) * PkmnapiDB::ROM_PAGE;
+        let offset = bank + (map_header.blocks_pointer as usize);
+
+        Ok((
+            offset,
+            map_header.width as usize,
+            map_header.height as usize,
+        ))
+    }
+}
@@ -0,0 +1,359 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::PkmnapiDB;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+impl PkmnapiDB {
+    /// Get all valid map headers
+    ///
+    /// Unused map IDs are skipped.
+    pub fn get_map_header_all(&self, map_ids: &Vec<u8>) -> Result<HashMap<u8, MapHeader>> {
+        let mut map_headers = HashMap::new();
+
+        for map_id in map_ids {
+            match self.get_map_header(map_id) {
+                Ok(map_header) => {
+                    map_headers.insert(*map_id, map_header);
+                }
+                Err(error::Error::MapInvalid(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(map_headers)
+    }
+
+    /// Get map header by map ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let map_header = db.get_map_header(&0).unwrap();
+    ///
+    /// assert_eq!(map_header.tileset_id, 0x00);
+    /// assert_eq!(map_header.height, 9);
+    /// assert_eq!(map_header.width, 10);
+    ///
+    /// let north = map_header.connections.north.unwrap();
+    ///
+    /// assert_eq!(north.map_id, 0x0C);
+    /// assert_eq!(north.destination_pointer, 0xC6EB);
+    /// assert_eq!(north.length, 10);
+    /// assert_eq!(north.width, 10);
+    /// assert_eq!(north.y_alignment, 35);
+    /// assert_eq!(north.x_alignment, 0);
+    /// assert_eq!(north.window_pointer, 0xC809);
+    ///
+    /// let south = map_header.connections.south.unwrap();
+    ///
+    /// assert_eq!(south.map_id, 0x20);
+    /// assert_eq!(map_header.connections.east, None);
+    /// ```
+    pub fn get_map_header(&self, map_id: &u8) -> Result<MapHeader> {
+        let (_, header_offset) = self.get_map_header_offset(map_id)?;
+
+        let map_header = MapHeader::from(&self.rom[header_offset..]);
+
+        Ok(map_header)
+    }
+
+    /// Set map header by map ID
+    ///
+    /// The header is written in place when it fits, otherwise it is moved to
+    /// free space in the map's bank and the header pointer is updated.
+    ///
+    /// The width and height must match the current header, since the map's
+    /// blocks are not moved or resized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let mut map_header = db.get_map_header(&0).unwrap();
+    ///
+    /// map_header.tileset_id = 0x01;
+    ///
+    /// let patch_set = db.set_map_header(&0, &map_header).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_map_header(&0).unwrap(), map_header);
+    /// ```
+    pub fn set_map_header(&self, map_id: &u8, map_header: &MapHeader) -> Result<PatchSet> {
+        let (bank_id, header_offset) = self.get_map_header_offset(map_id)?;
+
+        self.tileset_id_validate(&map_header.tileset_id)?;
+
+        let old_map_header = self.get_map_header(map_id)?;
+
+        if map_header.width != old_map_header.width || map_header.height != old_map_header.height {
+            return Err(error::Error::MapBlocksWrongSize(
+                old_map_header.width as usize,
+                old_map_header.height as usize,
+                map_header.width as usize,
+                map_header.height as usize,
+            ));
+        }
+
+        let old_map_header_data = old_map_header.to_raw();
+        let map_header_data = map_header.to_raw();
+
+        let mut patch_set = PatchSet::new(&vec![]);
+
+        if map_header_data.len() <= old_map_header_data.len() {
+            patch_set.push(Patch::new(&header_offset, &map_header_data));
+
+            return Ok(patch_set);
+        }
+
+        let new_header_offset = self.get_free_space(&bank_id, &map_header_data.len())?;
+        let header_pointer_offset = self.offsets.map_header_pointers + ((*map_id as usize) * 0x02);
+
+        patch_set.push(Patch::new(&new_header_offset, &map_header_data));
+        patch_set.push(Patch::new(
+            &header_pointer_offset,
+            &PkmnapiDB::offset_to_pointer(new_header_offset),
+        ));
+
+        Ok(patch_set)
+    }
+
+    /// Returns the map's bank ID and the offset of its header
+    pub(crate) fn get_map_header_offset(&self, map_id: &u8) -> Result<(usize, usize)> {
+        self.map_id_validate(map_id)?;
+
+        let (bank_id, offset) = self.offsets.map_header_banks;
+        let bank_offset = (PkmnapiDB::ROM_PAGE * bank_id) + offset + (*map_id as usize);
+        let bank_id = self.rom[bank_offset] as usize;
+
+        if bank_id <= 0x01 {
+            return Err(error::Error::MapInvalid(*map_id));
+        }
+
+        let bank = (bank_id - 0x01) * PkmnapiDB::ROM_PAGE;
+
+        let header_pointer_offset = self.offsets.map_header_pointers + ((*map_id as usize) * 0x02);
+        let header_offset = bank + self.get_pointer(header_pointer_offset);
+
+        let tileset = self.rom[header_offset];
+
+        if tileset == 0x40 || tileset == 0xC7 {
+            return Err(error::Error::MapInvalid(*map_id));
+        }
+
+        Ok((bank_id, header_offset))
+    }
+}
+
+/// Map header
+///
+/// Pointers are kept as the banked addresses stored in the ROM.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let rom = vec![
+///     0x00, 0x09, 0x0A, 0x03, 0x40, 0x55, 0x40, 0x77, 0x40, 0x08, 0x0C, 0x14, 0x4B, 0xEB,
+///     0xC6, 0x0A, 0x0A, 0x23, 0x00, 0xD9, 0xC9, 0x99, 0x40,
+/// ];
+/// let map_header = MapHeader::from(&rom[..]);
+///
+/// assert_eq!(map_header.blocks_pointer, 0x4003);
+/// assert_eq!(map_header.objects_pointer, 0x4099);
+/// assert_eq!(map_header.to_raw(), rom);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapHeader {
+    pub tileset_id: u8,
+    pub height: u8,
+    pub width: u8,
+    pub blocks_pointer: u16,
+    pub text_pointer: u16,
+    pub script_pointer: u16,
+    pub connections: MapConnections,
+    pub objects_pointer: u16,
+}
+
+impl From<&[u8]> for MapHeader {
+    /// Convert &[u8] to MapHeader
+    fn from(rom: &[u8]) -> Self {
+        let mut cursor = Cursor::new(rom);
+
+        let tileset_id = cursor.read_u8().unwrap_or(0);
+        let height = cursor.read_u8().unwrap_or(0);
+        let width = cursor.read_u8().unwrap_or(0);
+        let blocks_pointer = cursor.read_u16::<LittleEndian>().unwrap_or(0);
+        let text_pointer = cursor.read_u16::<LittleEndian>().unwrap_or(0);
+        let script_pointer = cursor.read_u16::<LittleEndian>().unwrap_or(0);
+        let connection_flags = cursor.read_u8().unwrap_or(0);
+
+        let mut next_connection = |flag: u8| -> Option<MapConnection> {
+            if connection_flags & flag == 0x00 {
+                return None;
+            }
+
+            let position = cursor.position() as usize;
+
+            cursor.set_position((position + MapConnection::SIZE) as u64);
+
+            Some(MapConnection::from(&rom[position..]))
+        };
+
+        let connections = MapConnections {
+            north: next_connection(MapConnections::NORTH),
+            south: next_connection(MapConnections::SOUTH),
+            west: next_connection(MapConnections::WEST),
+            east: next_connection(MapConnections::EAST),
+        };
+
+        let objects_pointer = cursor.read_u16::<LittleEndian>().unwrap_or(0);
+
+        MapHeader {
+            tileset_id,
+            height,
+            width,
+            blocks_pointer,
+            text_pointer,
+            script_pointer,
+            connections,
+            objects_pointer,
+        }
+    }
+}
+
+impl MapHeader {
+    pub fn to_raw(&self) -> Vec<u8> {
+        let connections: Vec<u8> = [
+            &self.connections.north,
+            &self.connections.south,
+            &self.connections.west,
+            &self.connections.east,
+        ]
+        .iter()
+        .filter_map(|connection| connection.as_ref())
+        .map(|connection| connection.to_raw())
+        .flatten()
+        .collect();
+
+        [
+            vec![self.tileset_id, self.height, self.width],
+            self.blocks_pointer.to_le_bytes().to_vec(),
+            self.text_pointer.to_le_bytes().to_vec(),
+            self.script_pointer.to_le_bytes().to_vec(),
+            vec![self.connections.flags()],
+            connections,
+            self.objects_pointer.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+}
+
+/// Map connections in each direction
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapConnections {
+    pub north: Option<MapConnection>,
+    pub south: Option<MapConnection>,
+    pub west: Option<MapConnection>,
+    pub east: Option<MapConnection>,
+}
+
+impl MapConnections {
+    pub const NORTH: u8 = 0x08;
+    pub const SOUTH: u8 = 0x04;
+    pub const WEST: u8 = 0x02;
+    pub const EAST: u8 = 0x01;
+
+    /// Connection flags byte as stored in the map header
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    ///
+    /// let connections = MapConnections {
+    ///     north: None,
+    ///     south: None,
+    ///     west: None,
+    ///     east: None,
+    /// };
+    ///
+    /// assert_eq!(connections.flags(), 0x00);
+    /// ```
+    pub fn flags(&self) -> u8 {
+        [
+            (&self.north, MapConnections::NORTH),
+            (&self.south, MapConnections::SOUTH),
+            (&self.west, MapConnections::WEST),
+            (&self.east, MapConnections::EAST),
+        ]
+        .iter()
+        .filter(|(connection, _)| connection.is_some())
+        .fold(0x00, |flags, (_, flag)| flags | flag)
+    }
+}
+
+/// Connection to a neighbouring map
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapConnection {
+    pub map_id: u8,
+    pub source_pointer: u16,
+    pub destination_pointer: u16,
+    pub length: u8,
+    pub width: u8,
+    pub y_alignment: u8,
+    pub x_alignment: u8,
+    pub window_pointer: u16,
+}
+
+impl MapConnection {
+    pub const SIZE: usize = 0x0B;
+
+    pub fn to_raw(&self) -> Vec<u8> {
+        [
+            vec![self.map_id],
+            self.source_pointer.to_le_bytes().to_vec(),
+            self.destination_pointer.to_le_bytes().to_vec(),
+            vec![self.length, self.width, self.y_alignment, self.x_alignment],
+            self.window_pointer.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+}
+
+impl From<&[u8]> for MapConnection {
+    /// Convert &[u8] to MapConnection
+    fn from(rom: &[u8]) -> Self {
+        let mut cursor = Cursor::new(rom);
+
+        MapConnection {
+            map_id: cursor.read_u8().unwrap_or(0),
+            source_pointer: cursor.read_u16::<LittleEndian>().unwrap_or(0),
+            destination_pointer: cursor.read_u16::<LittleEndian>().unwrap_or(0),
+            length: cursor.read_u8().unwrap_or(0),
+            width: cursor.read_u8().unwrap_or(0),
+            y_alignment: cursor.read_u8().unwrap_or(0),
+            x_alignment: cursor.read_u8().unwrap_or(0),
+            window_pointer: cursor.read_u16::<LittleEndian>().unwrap_or(0),
+        }
+    }
+}
@@ -0,0 +1,579 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl PkmnapiDB {
+    /// Get all valid map objects
+    ///
+    /// Unused map IDs are skipped.
+    pub fn get_map_objects_all(&self, map_ids: &Vec<u8>) -> Result<HashMap<u8, MapObjects>> {
+        let mut map_objects = HashMap::new();
+
+        for map_id in map_ids {
+            match self.get_map_objects(map_id) {
+                Ok(map_object) => {
+                    map_objects.insert(*map_id, map_object);
+                }
+                Err(error::Error::MapInvalid(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(map_objects)
+    }
+
+    /// Get map objects by map ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let map_objects = db.get_map_objects(&0).unwrap();
+    ///
+    /// assert_eq!(map_objects.border_block, 0x0B);
+    /// assert_eq!(map_objects.warps.len(), 3);
+    /// assert_eq!(
+    ///     map_objects.warps[0],
+    ///     MapWarp {
+    ///         x: 5,
+    ///         y: 5,
+    ///         destination_warp_id: 0,
+    ///         destination_map_id: 0x25,
+    ///     }
+    /// );
+    /// assert_eq!(map_objects.signs.len(), 4);
+    /// assert_eq!(map_objects.objects.len(), 3);
+    /// assert_eq!(map_objects.objects[0].x, 8);
+    /// assert_eq!(map_objects.objects[0].y, 5);
+    /// assert_eq!(map_objects.objects[0].text_id, 1);
+    /// assert_eq!(map_objects.objects[0].object_type, MapObjectType::NPC);
+    /// ```
+    pub fn get_map_objects(&self, map_id: &u8) -> Result<MapObjects> {
+        let (_, objects_offset, _) = self.get_map_objects_offset(map_id)?;
+
+        let map_objects = MapObjects::from(&self.rom[objects_offset..]);
+        let map_objects = MapObjects {
+            objects: map_objects
+                .objects
+                .iter()
+                .map(|object| match &object.object_type {
+                    MapObjectType::POKEMON(pokemon) => MapObject {
+                        object_type: MapObjectType::POKEMON(MapObjectPokemon {
+                            pokedex_id: self
+                                .internal_id_to_pokedex_id(&pokemon.internal_id)
+                                .unwrap(),
+                            internal_id: 0,
+                            ..*pokemon
+                        }),
+                        ..object.clone()
+                    },
+                    _ => object.clone(),
+                })
+                .collect(),
+            ..map_objects
+        };
+
+        Ok(map_objects)
+    }
+
+    /// Set map objects by map ID
+    ///
+    /// Warp destinations used by the overworld engine are regenerated from the
+    /// map width. Objects that no longer fit are moved to free space in the
+    /// map's bank and the map header's objects pointer is updated.
+    ///
+    /// Object coordinates must be 0-251, since the engine stores them offset
+    /// by 4, and trainer objects must use a valid trainer ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let mut map_objects = db.get_map_objects(&0).unwrap();
+    ///
+    /// map_objects.objects.push(MapObject {
+    ///     sprite_id: 0x3D,
+    ///     x: 4,
+    ///     y: 4,
+    ///     movement: 0xFF,
+    ///     direction: 0x00,
+    ///     text_id: 4,
+    ///     object_type: MapObjectType::ITEM(0x01),
+    /// });
+    ///
+    /// let patch_set = db.set_map_objects(&0, &map_objects).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_map_objects(&0).unwrap(), map_objects);
+    /// ```
+    pub fn set_map_objects(&self, map_id: &u8, map_objects: &MapObjects) -> Result<PatchSet> {
+        let (bank_id, objects_offset, objects_pointer_offset) =
+            self.get_map_objects_offset(map_id)?;
+        let map_header = self.get_map_header(map_id)?;
+
+        let old_map_objects = MapObjects::from(&self.rom[objects_offset..]);
+        let old_map_objects_data_len =
+            old_map_objects.to_raw().len() + (old_map_objects.warps.len() * 0x04);
+
+        for object in map_objects.objects.iter() {
+            if object.x > u8::MAX - MapObject::COORDINATE_OFFSET
+                || object.y > u8::MAX - MapObject::COORDINATE_OFFSET
+            {
+                return Err(error::Error::MapObjectCoordinatesInvalid(
+                    object.x, object.y,
+                ));
+            }
+
+            if let MapObjectType::TRAINER(trainer) = &object.object_type {
+                self.trainer_id_validate(&trainer.trainer_id)?;
+            }
+        }
+
+        let map_objects = MapObjects {
+            objects: map_objects
+                .objects
+                .iter()
+                .map(|object| match &object.object_type {
+                    MapObjectType::POKEMON(pokemon) => Ok(MapObject {
+                        object_type: MapObjectType::POKEMON(MapObjectPokemon {
+                            internal_id: self.pokedex_id_to_internal_id(&pokemon.pokedex_id)?,
+                            ..*pokemon
+                        }),
+                        ..object.clone()
+                    }),
+                    _ => Ok(object.clone()),
+                })
+                .collect::<Result<Vec<MapObject>>>()?,
+            ..map_objects.clone()
+        };
+
+        let map_objects_data = [
+            map_objects.to_raw(),
+            map_objects.warp_to_raw(&map_header.width),
+        ]
+        .concat();
+
+        if map_objects_data.len() <= old_map_objects_data_len {
+            return Ok(PatchSet::from(Patch::new(
+                &objects_offset,
+                &map_objects_data,
+            )));
+        }
+
+        let new_objects_offset = self.get_free_space(&bank_id, &map_objects_data.len())?;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&new_objects_offset, &map_objects_data),
+            Patch::new(
+                &objects_pointer_offset,
+                &PkmnapiDB::offset_to_pointer(new_objects_offset),
+            ),
+        ]))
+    }
+
+    /// Returns the map's bank ID, the offset of its objects and the offset of
+    /// the objects pointer in its header
+    pub(crate) fn get_map_objects_offset(&self, map_id: &u8) -> Result<(usize, usize, usize)> {
+        let (bank_id, header_offset) = self.get_map_header_offset(map_id)?;
+
+        let bank = (bank_id - 0x01) * PkmnapiDB::ROM_PAGE;
+
+        let connections = self.rom[header_offset + 9];
+        let objects_pointer_offset =
+            header_offset + 10 + ((connections & 0x0F).count_ones() as usize * MapConnection::SIZE);
+        let objects_offset = bank + self.get_pointer(objects_pointer_offset);
+
+        Ok((bank_id, objects_offset, objects_pointer_offset))
+    }
+}
+
+/// Map objects
+///
+/// Coordinates are in map tiles, with the engine's offset of 4 on object
+/// coordinates removed.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let rom = vec![
+///     0x0B, 0x01, 0x05, 0x05, 0x00, 0x25, 0x01, 0x0D, 0x07, 0x04, 0x02, 0x03, 0x09, 0x0C,
+///     0xFF, 0xFF, 0x01, 0x3D, 0x08, 0x08, 0xFF, 0x00, 0x82, 0x01,
+/// ];
+/// let map_objects = MapObjects::from(&rom[..]);
+///
+/// assert_eq!(map_objects.border_block, 0x0B);
+/// assert_eq!(
+///     map_objects.signs,
+///     vec![MapSign {
+///         x: 7,
+///         y: 13,
+///         text_id: 4,
+///     }]
+/// );
+/// assert_eq!(
+///     map_objects.objects[1],
+///     MapObject {
+///         sprite_id: 0x3D,
+///         x: 4,
+///         y: 4,
+///         movement: 0xFF,
+///         direction: 0x00,
+///         text_id: 2,
+///         object_type: MapObjectType::ITEM(0x01),
+///     }
+/// );
+/// assert_eq!(map_objects.to_raw(), rom);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapObjects {
+    pub border_block: u8,
+    pub warps: Vec<MapWarp>,
+    pub signs: Vec<MapSign>,
+    pub objects: Vec<MapObject>,
+}
+
+impl From<&[u8]> for MapObjects {
+    /// Convert &[u8] to MapObjects
+    fn from(rom: &[u8]) -> Self {
+        let border_block = rom[0];
+
+        let warp_count = rom[1] as usize;
+        let warps = rom[2..(2 + (warp_count * 0x04))]
+            .chunks(0x04)
+            .map(|chunk| MapWarp::from(chunk))
+            .collect();
+
+        let sign_offset = 2 + (warp_count * 0x04);
+        let sign_count = rom[sign_offset] as usize;
+        let signs = rom[(sign_offset + 1)..(sign_offset + 1 + (sign_count * 0x03))]
+            .chunks(0x03)
+            .map(|chunk| MapSign::from(chunk))
+            .collect();
+
+        let object_offset = sign_offset + 1 + (sign_count * 0x03);
+        let object_count = rom[object_offset] as usize;
+        let mut objects = vec![];
+        let mut i = object_offset + 1;
+
+        for _ in 0..object_count {
+            let object = MapObject::from(&rom[i..]);
+
+            i += object.to_raw().len();
+
+            objects.push(object);
+        }
+
+        MapObjects {
+            border_block,
+            warps,
+            signs,
+            objects,
+        }
+    }
+}
+
+impl MapObjects {
+    pub fn to_raw(&self) -> Vec<u8> {
+        let warps: Vec<u8> = self
+            .warps
+            .iter()
+            .map(|warp| warp.to_raw())
+            .flatten()
+            .collect();
+        let signs: Vec<u8> = self
+            .signs
+            .iter()
+            .map(|sign| sign.to_raw())
+            .flatten()
+            .collect();
+        let objects: Vec<u8> = self
+            .objects
+            .iter()
+            .map(|object| object.to_raw())
+            .flatten()
+            .collect();
+
+        [
+            vec![self.border_block, self.warps.len() as u8],
+            warps,
+            vec![self.signs.len() as u8],
+            signs,
+            vec![self.objects.len() as u8],
+            objects,
+        ]
+        .concat()
+    }
+
+    /// Warp destinations for a map of the given width
+    ///
+    /// Each warp is stored as its position in the overworld map buffer,
+    /// followed by its Y and X coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    ///
+    /// let map_objects = MapObjects {
+    ///     border_block: 0x0B,
+    ///     warps: vec![MapWarp {
+    ///         x: 5,
+    ///         y: 5,
+    ///         destination_warp_id: 0,
+    ///         destination_map_id: 0x25,
+    ///     }],
+    ///     signs: vec![],
+    ///     objects: vec![],
+    /// };
+    ///
+    /// assert_eq!(map_objects.warp_to_raw(&10), vec![0x1B, 0xC7, 0x05, 0x05]);
+    /// ```
+    pub fn warp_to_raw(&self, width: &u8) -> Vec<u8> {
+        let width = *width as u16;
+
+        self.warps
+            .iter()
+            .map(|warp| {
+                let pointer = 0xC6E8
+                    + 7
+                    + width
+                    + ((width + 6) * ((warp.y as u16) >> 1))
+                    + ((warp.x as u16) >> 1);
+
+                [pointer.to_le_bytes().to_vec(), vec![warp.y, warp.x]].concat()
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+/// Map warp
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapWarp {
+    pub x: u8,
+    pub y: u8,
+    pub destination_warp_id: u8,
+    pub destination_map_id: u8,
+}
+
+impl From<&[u8]> for MapWarp {
+    /// Convert &[u8] to MapWarp
+    fn from(rom: &[u8]) -> Self {
+        MapWarp {
+            x: rom[1],
+            y: rom[0],
+            destination_warp_id: rom[2],
+            destination_map_id: rom[3],
+        }
+    }
+}
+
+impl MapWarp {
+    pub fn to_raw(&self) -> Vec<u8> {
+        vec![
+            self.y,
+            self.x,
+            self.destination_warp_id,
+            self.destination_map_id,
+        ]
+    }
+}
+
+/// Map sign
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapSign {
+    pub x: u8,
+    pub y: u8,
+    pub text_id: u8,
+}
+
+impl From<&[u8]> for MapSign {
+    /// Convert &[u8] to MapSign
+    fn from(rom: &[u8]) -> Self {
+        MapSign {
+            x: rom[1],
+            y: rom[0],
+            text_id: rom[2],
+        }
+    }
+}
+
+impl MapSign {
+    pub fn to_raw(&self) -> Vec<u8> {
+        vec![self.y, self.x, self.text_id]
+    }
+}
+
+/// Map object (NPC, trainer, static Pokémon or item ball)
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let rom = vec![0x0E, 0x09, 0x0A, 0xFF, 0xD0, 0x41, 0xD1, 0x02];
+/// let map_object = MapObject::from(&rom[..]);
+///
+/// assert_eq!(
+///     map_object,
+///     MapObject {
+///         sprite_id: 0x0E,
+///         x: 6,
+///         y: 5,
+///         movement: 0xFF,
+///         direction: 0xD0,
+///         text_id: 1,
+///         object_type: MapObjectType::TRAINER(MapObjectTrainer {
+///             trainer_id: 9,
+///             party_id: 2,
+///         }),
+///     }
+/// );
+/// assert_eq!(map_object.to_raw(), rom);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapObject {
+    pub sprite_id: u8,
+    pub x: u8,
+    pub y: u8,
+    pub movement: u8,
+    pub direction: u8,
+    pub text_id: u8,
+    pub object_type: MapObjectType,
+}
+
+impl MapObject {
+    const TRAINER: u8 = 0x40;
+    const ITEM: u8 = 0x80;
+    const TRAINER_CLASS_OFFSET: u8 = 0xC8;
+    const COORDINATE_OFFSET: u8 = 0x04;
+
+    pub fn to_raw(&self) -> Vec<u8> {
+        let (flags, extra) = match &self.object_type {
+            MapObjectType::NPC => (0x00, vec![]),
+            MapObjectType::ITEM(item_id) => (MapObject::ITEM, vec![*item_id]),
+            MapObjectType::TRAINER(trainer) => (
+                MapObject::TRAINER,
+                vec![
+                    trainer
+                        .trainer_id
+                        .wrapping_add(MapObject::TRAINER_CLASS_OFFSET),
+                    trainer.party_id,
+                ],
+            ),
+            MapObjectType::POKEMON(pokemon) => (
+                MapObject::TRAINER,
+                vec![pokemon.internal_id.wrapping_add(1), pokemon.level],
+            ),
+        };
+
+        [
+            vec![
+                self.sprite_id,
+                self.y.wrapping_add(MapObject::COORDINATE_OFFSET),
+                self.x.wrapping_add(MapObject::COORDINATE_OFFSET),
+                self.movement,
+                self.direction,
+                (self.text_id & 0x3F) | flags,
+            ],
+            extra,
+        ]
+        .concat()
+    }
+}
+
+impl From<&[u8]> for MapObject {
+    /// Convert &[u8] to MapObject
+    fn from(rom: &[u8]) -> Self {
+        let text_id = rom[5];
+
+        let object_type = if text_id & MapObject::TRAINER != 0x00 {
+            if rom[6] >= MapObject::TRAINER_CLASS_OFFSET {
+                MapObjectType::TRAINER(MapObjectTrainer {
+                    trainer_id: rom[6] - MapObject::TRAINER_CLASS_OFFSET,
+                    party_id: rom[7],
+                })
+            } else {
+                MapObjectType::POKEMON(MapObjectPokemon {
+                    pokedex_id: 0,
+                    level: rom[7],
+                    internal_id: rom[6].wrapping_sub(1),
+                })
+            }
+        } else if text_id & MapObject::ITEM != 0x00 {
+            MapObjectType::ITEM(rom[6])
+        } else {
+            MapObjectType::NPC
+        };
+
+        MapObject {
+            sprite_id: rom[0],
+            x: rom[2].wrapping_sub(MapObject::COORDINATE_OFFSET),
+            y: rom[1].wrapping_sub(MapObject::COORDINATE_OFFSET),
+            movement: rom[3],
+            direction: rom[4],
+            text_id: text_id & 0x3F,
+            object_type,
+        }
+    }
+}
+
+/// Map object type
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let object_type = MapObjectType::ITEM(0x01);
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum MapObjectType {
+    NPC,
+    ITEM(u8),
+    TRAINER(MapObjectTrainer),
+    POKEMON(MapObjectPokemon),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapObjectTrainer {
+    pub trainer_id: u8,
+    pub party_id: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct MapObjectPokemon {
+    pub pokedex_id: u8,
+    pub level: u8,
+    #[serde(skip)]
+    pub(crate) internal_id: u8,
+}
+
+impl MapObjectPokemon {
+    pub fn new(pokedex_id: u8, level: u8) -> Self {
+        MapObjectPokemon {
+            pokedex_id,
+            level,
+            internal_id: 0,
+        }
+    }
+}
@@ -1,13 +1,15 @@
 use crate::error::{self, Result};
 use crate::map::*;
+use crate::patch::*;
 use crate::PkmnapiDB;
 
 impl PkmnapiDB {
     pub fn get_map_pic(&self, map_id: &u8) -> Result<Map> {
         self.map_id_validate(map_id)?;
 
-        let bank_offset_base = PkmnapiDB::ROM_PAGE * 0x03;
-        let bank_offset = (bank_offset_base + 0x23D) + (*map_id as usize);
+        let (bank_id, offset) = self.offsets.map_header_banks;
+        let bank_offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let bank_offset = (bank_offset_base + offset) + (*map_id as usize);
         let bank_id = self.rom[bank_offset];
 
         let bank = ((bank_id as usize) - 0x01) * PkmnapiDB::ROM_PAGE;
@@ -16,7 +18,7 @@ impl PkmnapiDB {
             return Err(error::Error::MapInvalid(*map_id));
         }
 
-        let header_offset = 0x01AE + ((*map_id as usize) * 0x02);
+        let header_offset = self.offsets.map_header_pointers + ((*map_id as usize) * 0x02);
         let header_pointer = bank + self.get_pointer(header_offset);
 
         let tileset = self.rom[header_pointer];
@@ -25,7 +27,7 @@ impl PkmnapiDB {
             return Err(error::Error::MapInvalid(*map_id));
         }
 
-        let tileset_bank_pointer = 0xC7BE + ((tileset as usize) * 0x0C);
+        let tileset_bank_pointer = self.offsets.tileset_headers + ((tileset as usize) * 0x0C);
         let tileset_bank = ((self.rom[tileset_bank_pointer] as usize) - 0x01) * PkmnapiDB::ROM_PAGE;
         let tileset_block_pointer = tileset_bank + self.get_pointer(tileset_bank_pointer + 1);
         let tileset_graphics_pointer = tileset_bank + self.get_pointer(tileset_bank_pointer + 3);
@@ -64,4 +66,107 @@ impl PkmnapiDB {
 
         Ok(map)
     }
+
+    /// Set map pic by map ID
+    ///
+    /// Each 32x32 region of the image is matched against the blocks of the
+    /// map's tileset. Images containing a region that matches no block are
+    /// rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let map = db.get_map_pic(&0).unwrap();
+    /// let patch = db.set_map_pic(&0, &map).unwrap();
+    ///
+    /// assert_eq!(
+    ///     patch.data,
+    ///     db.get_map_blocks(&0)
+    ///         .unwrap()
+    ///         .into_iter()
+    ///         .flatten()
+    ///         .collect::<Vec<u8>>()
+    /// );
+    /// ```
+    pub fn set_map_pic(&self, map_id: &u8, map: &Map) -> Result<Patch> {
+        let map_header = self.get_map_header(map_id)?;
+
+        let width = (map.width / 4) as usize;
+        let height = (map.height / 4) as usize;
+
+        if width != map_header.width as usize || height != map_header.height as usize {
+            return Err(error::Error::MapBlocksWrongSize(
+                map_header.width as usize,
+                map_header.height as usize,
+                width,
+                height,
+            ));
+        }
+
+        let tileset_blocks = self.get_tileset_block_pixels(&map_header.tileset_id)?;
+
+        let map_blocks = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        let block_pixels = map.block_pixels(x as u32, y as u32);
+
+                        match tileset_blocks
+                            .iter()
+                            .position(|tileset_block| *tileset_block == block_pixels)
+                        {
+                            Some(block_id) => Ok(block_id as u8),
+                            None => Err(error::Error::MapBlockNotFound(x, y)),
+                        }
+                    })
+                    .collect::<Result<Vec<u8>>>()
+            })
+            .collect::<Result<Vec<Vec<u8>>>>()?;
+
+        self.set_map_blocks(map_id, &map_blocks)
+    }
+
+    /// Pixels of every block in a tileset
+    ///
+    /// Blocks referring to tiles outside of the tileset's graphics are left
+    /// empty so they never match.
+    fn get_tileset_block_pixels(&self, tileset_id: &u8) -> Result<Vec<Vec<u8>>> {
+        let offsets = self.get_tileset_offsets(tileset_id)?;
+
+        let tiles = self.get_tiles(offsets.gfx, offsets.tile_count, false);
+
+        let tileset_block_pixels = (0..offsets.block_count)
+            .map(|block| {
+                let block_offset = offsets.blocks + (block * 0x10);
+                let tile_ids = &self.rom[block_offset..(block_offset + 0x10)];
+
+                if tile_ids
+                    .iter()
+                    .any(|tile_id| (*tile_id as usize) >= tiles.len())
+                {
+                    return vec![];
+                }
+
+                (0..(32 * 32))
+                    .map(|i| {
+                        let x = i % 32;
+                        let y = i / 32;
+                        let tile_id = tile_ids[((y / 8) * 4) + (x / 8)] as usize;
+
+                        tiles[tile_id][((y % 8) * 8) + (x % 8)]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(tileset_block_pixels)
+    }
 }
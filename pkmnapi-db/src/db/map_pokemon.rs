@@ -1,6 +1,7 @@
 use crate::error::{self, Result};
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -11,8 +12,9 @@ impl PkmnapiDB {
     pub fn get_map_pokemon(&self, map_id: &u8) -> Result<MapPokemon> {
         self.map_id_validate(map_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x03;
-        let offset = offset_base + 0x0EEB;
+        let (bank_id, offset) = self.offsets.wild_pokemon;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
         let pointer_offset = offset + ((*map_id as usize) * 0x02);
         let pointer = offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(pointer_offset);
 
@@ -99,8 +101,9 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x03;
-        let offset = offset_base + 0x0EEB;
+        let (bank_id, offset) = self.offsets.wild_pokemon;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
         let pointer_offset = offset + ((*map_id as usize) * 0x02);
         let pointer = offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(pointer_offset);
 
@@ -108,7 +111,7 @@ impl PkmnapiDB {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct MapPokemon {
     pub grass: MapPokemonArea,
     pub water: MapPokemonArea,
@@ -242,16 +245,17 @@ impl MapPokemon {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct MapPokemonArea {
     pub encounter_rate: u8,
     pub pokemon: Vec<MapPokemonInfo>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct MapPokemonInfo {
     pub level: u8,
     pub pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) internal_id: u8,
 }
 
@@ -1,6 +1,7 @@
 use crate::error::{self, Result};
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -35,27 +36,7 @@ impl PkmnapiDB {
     /// );
     /// ```
     pub fn get_mart_items(&self, mart_id: &u8) -> Result<Vec<MartItem>> {
-        let (min_id, max_id) = self.mart_id_validate(mart_id)?;
-
-        let offset_base = 0x2442;
-        let offset = self.rom[offset_base..]
-            .iter()
-            .enumerate()
-            .filter_map(|(i, x)| {
-                if *x == 0xFE {
-                    return Some(offset_base + i);
-                }
-
-                None
-            })
-            .take(max_id + 1)
-            .skip(*mart_id as usize)
-            .next();
-
-        let offset = match offset {
-            Some(offset) => offset,
-            None => return Err(error::Error::MartIDInvalid(*mart_id, min_id, max_id)),
-        };
+        let offset = self.get_mart_offset(mart_id)?;
 
         let mart_items = self.rom[(offset + 2)..]
             .iter()
@@ -66,7 +47,12 @@ impl PkmnapiDB {
         Ok(mart_items)
     }
 
-    /// Set item name by item ID
+    /// Set mart items by mart ID
+    ///
+    /// Marts that grow are moved to free space in the home bank. The map
+    /// text pointers referring to the mart are updated and the old mart is
+    /// replaced by `0xFE, 0xFF` followed by a pointer to the new location,
+    /// which keeps mart IDs stable.
     ///
     /// # Example
     ///
@@ -81,7 +67,7 @@ impl PkmnapiDB {
     /// let rom = fs::read(rom_path).unwrap();
     /// let db = PkmnapiDB::new(&rom).build().unwrap();
     ///
-    /// let patch = db
+    /// let patch_set = db
     ///     .set_mart_items(
     ///         &0,
     ///         &vec![
@@ -94,30 +80,74 @@ impl PkmnapiDB {
     ///     .unwrap();
     ///
     /// assert_eq!(
-    ///     patch,
-    ///     Patch {
+    ///     patch_set.patches,
+    ///     vec![Patch {
     ///         offset: 0x2444,
     ///         length: 0x04,
     ///         data: vec![0x01, 0x02, 0xC9, 0xCA]
-    ///     }
+    ///     }]
     /// );
     /// ```
-    pub fn set_mart_items(&self, mart_id: &u8, mart_items: &Vec<MartItem>) -> Result<Patch> {
-        let (min_id, max_id) = self.mart_id_validate(mart_id)?;
-
+    pub fn set_mart_items(&self, mart_id: &u8, mart_items: &Vec<MartItem>) -> Result<PatchSet> {
         let old_mart_items = self.get_mart_items(mart_id)?;
         let old_mart_items_len = old_mart_items.len();
         let mart_items_len = mart_items.len();
 
-        if old_mart_items_len != mart_items_len {
-            return Err(error::Error::MartItemsWrongSize(
-                old_mart_items_len,
-                mart_items_len,
-            ));
+        let offset = self.get_mart_offset(mart_id)?;
+
+        let mart_items_data: Vec<u8> = mart_items
+            .iter()
+            .map(|mart_item| (*mart_item).to_raw())
+            .flatten()
+            .collect();
+
+        if mart_items_len == old_mart_items_len {
+            return Ok(PatchSet::from(Patch::new(&(offset + 2), &mart_items_data)));
         }
 
-        let offset_base = 0x2442;
-        let offset = self.rom[offset_base..]
+        let data = [
+            vec![0xFE, mart_items_len as u8],
+            mart_items_data,
+            vec![0xFF],
+        ]
+        .concat();
+
+        if mart_items_len < old_mart_items_len {
+            return Ok(PatchSet::from(Patch::new(&offset, &data)));
+        }
+
+        let new_offset = self.get_free_space(&0x00, &(data.len() + 1))?;
+
+        // The low byte of the redirect pointer must not be mistaken for a mart
+        let new_offset = if new_offset & 0xFF == 0xFE {
+            new_offset + 1
+        } else {
+            new_offset
+        };
+
+        let mart_offset = self.get_mart_offsets(mart_id)?[*mart_id as usize];
+        let new_pointer = PkmnapiDB::offset_to_pointer(new_offset);
+
+        let mut patch_set = PatchSet::from(vec![
+            Patch::new(&new_offset, &data),
+            Patch::new(
+                &mart_offset,
+                &[vec![0xFE, 0xFF], new_pointer.to_vec()].concat(),
+            ),
+        ]);
+
+        for text_pointer_offset in self.get_mart_text_pointer_offsets(&offset) {
+            patch_set.push(Patch::new(&text_pointer_offset, &new_pointer));
+        }
+
+        Ok(patch_set)
+    }
+
+    fn get_mart_offsets(&self, mart_id: &u8) -> Result<Vec<usize>> {
+        let (min_id, max_id) = self.mart_id_validate(mart_id)?;
+
+        let offset_base = self.offsets.marts;
+        let offsets: Vec<usize> = self.rom[offset_base..]
             .iter()
             .enumerate()
             .filter_map(|(i, x)| {
@@ -128,21 +158,98 @@ impl PkmnapiDB {
                 None
             })
             .take(max_id + 1)
-            .skip(*mart_id as usize)
-            .next();
+            .collect();
 
-        let offset = match offset {
-            Some(offset) => offset,
-            None => return Err(error::Error::MartIDInvalid(*mart_id, min_id, max_id)),
-        } + 2;
+        if offsets.len() <= *mart_id as usize {
+            return Err(error::Error::MartIDInvalid(*mart_id, min_id, max_id));
+        }
 
-        let data = mart_items
-            .iter()
-            .map(|mart_item| (*mart_item).to_raw())
+        Ok(offsets)
+    }
+
+    fn get_mart_offset(&self, mart_id: &u8) -> Result<usize> {
+        let offset = self.get_mart_offsets(mart_id)?[*mart_id as usize];
+
+        if self.rom[offset + 1] == 0xFF {
+            return Ok(self.get_pointer(offset + 2));
+        }
+
+        Ok(offset)
+    }
+
+    fn get_mart_text_pointer_offsets(&self, offset: &usize) -> Vec<usize> {
+        let (_min_map_id, max_map_id) = self.map_id_bounds();
+
+        (0..=max_map_id)
+            .map(|map_id| {
+                let (bank_id, banks_offset) = self.offsets.map_header_banks;
+                let bank_offset = (PkmnapiDB::ROM_PAGE * bank_id) + banks_offset + map_id;
+                let bank_id = self.rom[bank_offset] as usize;
+
+                if bank_id <= 0x01 {
+                    return vec![];
+                }
+
+                let bank = (bank_id - 0x01) * PkmnapiDB::ROM_PAGE;
+
+                let header_offset = self.offsets.map_header_pointers + (map_id * 0x02);
+                let header_pointer = bank + self.get_pointer(header_offset);
+
+                let tileset = self.rom[header_pointer];
+
+                if tileset == 0x40 || tileset == 0xC7 {
+                    return vec![];
+                }
+
+                let text_pointer = bank + self.get_pointer(header_pointer + 5);
+                let connections = self.rom[header_pointer + 9];
+                let objects_pointer_offset =
+                    header_pointer + 10 + ((connections & 0x0F).count_ones() as usize * 0x0B);
+                let objects_pointer = bank + self.get_pointer(objects_pointer_offset);
+
+                let text_count = self.get_map_text_count(objects_pointer);
+
+                (0..text_count)
+                    .map(|i| text_pointer + (i * 0x02))
+                    .filter(|text_pointer_offset| self.get_pointer(*text_pointer_offset) == *offset)
+                    .collect()
+            })
             .flatten()
+            .collect()
+    }
+
+    fn get_map_text_count(&self, objects_pointer: usize) -> usize {
+        let warp_count = self.rom[objects_pointer + 1] as usize;
+
+        let sign_offset = objects_pointer + 2 + (warp_count * 0x04);
+        let sign_count = self.rom[sign_offset] as usize;
+        let sign_text_ids: Vec<usize> = (0..sign_count)
+            .map(|i| self.rom[sign_offset + 1 + (i * 0x03) + 2] as usize)
             .collect();
 
-        Ok(Patch::new(&offset, &data))
+        let object_offset = sign_offset + 1 + (sign_count * 0x03);
+        let object_count = self.rom[object_offset] as usize;
+        let mut object_text_ids = vec![];
+        let mut i = object_offset + 1;
+
+        for _ in 0..object_count {
+            let text_id = self.rom[i + 5];
+
+            object_text_ids.push((text_id & 0x3F) as usize);
+
+            i += match text_id {
+                text_id if text_id & 0x40 != 0x00 => 0x08,
+                text_id if text_id & 0x80 != 0x00 => 0x07,
+                _ => 0x06,
+            };
+        }
+
+        sign_text_ids
+            .iter()
+            .chain(object_text_ids.iter())
+            .max()
+            .map(|text_count| *text_count)
+            .unwrap_or(0)
     }
 }
 
@@ -161,7 +268,7 @@ impl PkmnapiDB {
 ///
 /// assert_eq!(mart_item, MartItem::TM(0x01));
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub enum MartItem {
     ITEM(u8),
     TM(u8),
@@ -1,8 +1,12 @@
 mod game_boy_img;
+mod hidden_objects;
 mod hm_moves;
 mod hm_names;
 mod icon;
 mod item_name;
+mod map_blocks;
+mod map_header;
+mod map_objects;
 mod map_pic;
 mod map_pokemon;
 mod mart_items;
@@ -22,10 +26,17 @@ mod pokemon_name;
 mod pokemon_pic;
 mod pokemon_stats;
 mod pokemon_title;
+mod project;
+mod sgb_palette;
+mod starters;
+mod static_pokemon;
+mod text;
+mod tileset;
 mod tm_moves;
 mod tm_names;
 mod tm_prices;
 mod town_map_img;
+mod town_map_location;
 mod trades;
 mod trainer_name;
 mod trainer_parties;
@@ -35,10 +46,14 @@ mod type_effect;
 mod type_name;
 
 pub use game_boy_img::*;
+pub use hidden_objects::*;
 pub use hm_moves::*;
 pub use hm_names::*;
 pub use icon::*;
 pub use item_name::*;
+pub use map_blocks::*;
+pub use map_header::*;
+pub use map_objects::*;
 pub use map_pic::*;
 pub use map_pokemon::*;
 pub use mart_items::*;
@@ -58,10 +73,17 @@ pub use pokemon_name::*;
 pub use pokemon_pic::*;
 pub use pokemon_stats::*;
 pub use pokemon_title::*;
+pub use project::*;
+pub use sgb_palette::*;
+pub use starters::*;
+pub use static_pokemon::*;
+pub use text::*;
+pub use tileset::*;
 pub use tm_moves::*;
 pub use tm_names::*;
 pub use tm_prices::*;
 pub use town_map_img::*;
+pub use town_map_location::*;
 pub use trades::*;
 pub use trainer_name::*;
 pub use trainer_parties::*;
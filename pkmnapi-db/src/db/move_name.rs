@@ -2,6 +2,7 @@ use crate::error::{self, Result};
 use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -35,7 +36,8 @@ impl PkmnapiDB {
     pub fn get_move_name(&self, move_id: &u8) -> Result<MoveName> {
         let (min_id, max_id) = self.move_id_validate(move_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x2C;
+        let (bank_id, offset) = self.offsets.move_names;
+        let offset_base = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
         let offset = self.rom[offset_base..]
             .iter()
             .enumerate()
@@ -112,7 +114,8 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x2C;
+        let (bank_id, offset) = self.offsets.move_names;
+        let offset_base = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
         let offset = self.rom[offset_base..]
             .iter()
             .enumerate()
@@ -158,7 +161,8 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct MoveName {
     pub name: ROMString,
 }
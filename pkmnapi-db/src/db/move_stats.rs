@@ -2,6 +2,7 @@ use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
 use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::Cursor;
 
@@ -40,7 +41,8 @@ impl PkmnapiDB {
     pub fn get_move_stats(&self, move_id: &u8) -> Result<MoveStats> {
         self.move_id_validate(move_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+        let (bank_id, offset) = self.offsets.move_stats;
+        let offset_base = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
         let offset = offset_base + (((*move_id as usize) - 1) * 0x06);
 
         let move_stats = MoveStats::from(&self.rom[offset..(offset + 6)]);
@@ -88,7 +90,8 @@ impl PkmnapiDB {
     pub fn set_move_stats(&self, move_id: &u8, move_stats: &MoveStats) -> Result<Patch> {
         self.move_id_validate(move_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+        let (bank_id, offset) = self.offsets.move_stats;
+        let offset_base = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
         let offset = offset_base + (((*move_id as usize) - 1) * 0x06);
 
         let move_stats_raw = move_stats.to_raw();
@@ -119,7 +122,7 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct MoveStats {
     pub move_id: u8,
     pub effect: u8,
@@ -5,15 +5,16 @@ use crate::PkmnapiDB;
 
 impl PkmnapiDB {
     pub fn get_player_names(&self) -> Result<PlayerNames> {
-        let offset_base = PkmnapiDB::ROM_PAGE;
-        let offset = offset_base + 0x2AA8;
+        let (bank_id, offset) = self.offsets.player_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let player_names = PlayerNames::from(&self.rom[offset..]);
 
         Ok(player_names)
     }
 
-    pub fn set_player_names(&self, player_names: &PlayerNames) -> Result<Patch> {
+    pub fn set_player_names(&self, player_names: &PlayerNames) -> Result<PatchSet> {
         let old_player_names = self.get_player_names()?;
         let old_player_names_data = old_player_names.to_raw();
         let old_player_names_data_len = old_player_names_data.len();
@@ -27,11 +28,11 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE;
-        let offset_a = offset_base + 0x2AA8;
-        let offset_b = offset_base + 0x2AF2;
-        let offset_raw_start = offset_a + player_names_data_len;
-        let offset_raw_len = offset_b - offset_a - player_names_data_len;
+        let (bank_id, offset) = self.offsets.player_names;
+        let offset_a = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
+
+        let (bank_id, offset) = self.offsets.player_names_menu;
+        let offset_b = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
 
         let player_names_data_b: Vec<u8> = player_names_data_a
             .iter()
@@ -44,14 +45,10 @@ impl PkmnapiDB {
             })
             .collect();
 
-        let player_names_data = [
-            player_names_data_a,
-            self.rom[offset_raw_start..(offset_raw_start + offset_raw_len)].to_vec(),
-            player_names_data_b,
-        ]
-        .concat();
-
-        Ok(Patch::new(&offset_a, &player_names_data))
+        Ok(PatchSet::from(vec![
+            Patch::new(&offset_a, &player_names_data_a),
+            Patch::new(&offset_b, &player_names_data_b),
+        ]))
     }
 }
 
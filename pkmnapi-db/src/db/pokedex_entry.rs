@@ -3,6 +3,7 @@ use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
 use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::Cursor;
 
@@ -39,8 +40,9 @@ impl PkmnapiDB {
     pub fn get_pokedex_entry(&self, pokedex_id: &u8) -> Result<PokedexEntry> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer_offset = (offset_base + 0x447E) + ((internal_id as usize) * 2);
+        let (bank_id, offset) = self.offsets.pokedex_entries;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_offset = (offset_base + offset) + ((internal_id as usize) * 2);
 
         let pointer = offset_base + self.get_pointer(pointer_offset);
 
@@ -99,8 +101,9 @@ impl PkmnapiDB {
 
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer_offset = (offset_base + 0x447E) + ((internal_id as usize) * 2);
+        let (bank_id, offset) = self.offsets.pokedex_entries;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_offset = (offset_base + offset) + ((internal_id as usize) * 2);
 
         let pointer = offset_base + self.get_pointer(pointer_offset);
 
@@ -130,7 +133,7 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct PokedexEntry {
     pub species: ROMString,
     pub height: u32,
@@ -2,6 +2,7 @@ use crate::error::{self, Result};
 use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -32,8 +33,9 @@ impl PkmnapiDB {
     pub fn get_pokedex_text(&self, pokedex_id: &u8) -> Result<PokedexText> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer_offset = (offset_base + 0x447E) + ((internal_id as usize) * 2);
+        let (bank_id, offset) = self.offsets.pokedex_entries;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_offset = (offset_base + offset) + ((internal_id as usize) * 2);
 
         let pointer = offset_base + self.get_pointer(pointer_offset);
 
@@ -51,6 +53,9 @@ impl PkmnapiDB {
 
     /// Set Pokédex entry text by Pokédex ID
     ///
+    /// Text longer than the old text is moved to free space, preferring the
+    /// bank of the old text, and the entry's text pointer is updated.
+    ///
     /// # Example
     ///
     /// ```
@@ -64,38 +69,36 @@ impl PkmnapiDB {
     /// let rom = fs::read(rom_path).unwrap();
     /// let db = PkmnapiDB::new(&rom).build().unwrap();
     ///
-    /// let patch = db
+    /// let patch_set = db
     ///     .set_pokedex_text(&1, &PokedexText {
     ///         text: ROMString::from("ABCDE"),
     ///     })
     ///     .unwrap();
     ///
     /// assert_eq!(
-    ///     patch,
-    ///     Patch {
+    ///     patch_set.patches,
+    ///     vec![Patch {
     ///         offset: 0xAEE81,
     ///         length: 0x07,
     ///         data: vec![0x00, 0x80, 0x81, 0x82, 0x83, 0x84, 0x5F]
-    ///     }
+    ///     }]
     /// );
     /// ```
-    pub fn set_pokedex_text(&self, pokedex_id: &u8, pokedex_text: &PokedexText) -> Result<Patch> {
+    pub fn set_pokedex_text(
+        &self,
+        pokedex_id: &u8,
+        pokedex_text: &PokedexText,
+    ) -> Result<PatchSet> {
         let old_pokedex_text = self.get_pokedex_text(pokedex_id)?;
-        let old_pokedex_text_len = old_pokedex_text.text.value.len();
-        let pokedex_text_raw = pokedex_text.text.to_string();
-        let pokedex_text_len = pokedex_text_raw.len();
-
-        if pokedex_text_len >= old_pokedex_text_len {
-            return Err(error::Error::PokedexTextWrongSize(
-                old_pokedex_text_len,
-                pokedex_text_len,
-            ));
-        }
+        let old_pokedex_text_data_len = old_pokedex_text.to_raw().len();
+        let pokedex_text_data = pokedex_text.to_raw();
+        let pokedex_text_data_len = pokedex_text_data.len();
 
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer_offset = (offset_base + 0x447E) + ((internal_id as usize) * 2);
+        let (bank_id, offset) = self.offsets.pokedex_entries;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_offset = (offset_base + offset) + ((internal_id as usize) * 2);
 
         let pointer = offset_base + self.get_pointer(pointer_offset);
 
@@ -103,10 +106,34 @@ impl PkmnapiDB {
             pointer + { self.rom[pointer..].iter().position(|&r| r == 0x50).unwrap() } + 0x06;
 
         let pointer = self.get_pointer(pointer_offset);
-        let pointer_base = PkmnapiDB::ROM_PAGE * (self.rom[pointer_offset + 2] as usize);
+        let pointer_bank = self.rom[pointer_offset + 2] as usize;
+        let pointer_base = PkmnapiDB::ROM_PAGE * pointer_bank;
         let pointer = pointer + pointer_base - PkmnapiDB::ROM_PAGE;
 
-        Ok(Patch::new(&pointer, &pokedex_text.to_raw()))
+        if pokedex_text_data_len <= old_pokedex_text_data_len {
+            return Ok(PatchSet::from(Patch::new(&pointer, &pokedex_text_data)));
+        }
+
+        let bank_count = self.rom.len() / PkmnapiDB::ROM_PAGE;
+        let new_pointer = match self.get_free_space(&pointer_bank, &pokedex_text_data_len) {
+            Ok(new_pointer) => new_pointer,
+            Err(_) => (0x01..bank_count)
+                .find_map(|bank_id| self.get_free_space(&bank_id, &pokedex_text_data_len).ok())
+                .ok_or(error::Error::FreeSpaceNotFound(pokedex_text_data_len))?,
+        };
+        let new_pointer_bank = (new_pointer / PkmnapiDB::ROM_PAGE) as u8;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&new_pointer, &pokedex_text_data),
+            Patch::new(
+                &pointer_offset,
+                &[
+                    PkmnapiDB::offset_to_pointer(new_pointer),
+                    vec![new_pointer_bank],
+                ]
+                .concat(),
+            ),
+        ]))
     }
 }
 
@@ -128,7 +155,8 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct PokedexText {
     pub text: ROMString,
 }
@@ -12,15 +12,17 @@ impl PkmnapiDB {
     pub fn get_pokemon_cry(&self, pokedex_id: &u8) -> Result<Cry> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = (offset_base + 0x1446) + ((internal_id as usize) * 0x03);
+        let (bank_id, offset) = self.offsets.pokemon_cries;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((internal_id as usize) * 0x03);
 
         let base = self.rom[offset];
         let pitch = self.rom[offset + 1];
         let length = self.rom[offset + 2];
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x02;
-        let offset = (offset_base + 0x3C) + ((base as usize) * 0x09);
+        let (bank_id, offset) = self.offsets.sfx_headers;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((base as usize) * 0x09);
 
         let cry: Cry = (0..3)
             .map(|i| {
@@ -29,7 +31,7 @@ impl PkmnapiDB {
                 self.get_pointer(cursor_offset)
             })
             .map(|channel_offset| {
-                let offset_base = PkmnapiDB::ROM_PAGE;
+                let offset_base = PkmnapiDB::ROM_PAGE * (bank_id - 1);
                 let offset = offset_base + channel_offset;
 
                 self.rom[offset..]
@@ -68,8 +70,9 @@ impl PkmnapiDB {
     pub fn set_pokemon_cry(&self, pokedex_id: &u8, pokemon_cry: &Cry) -> Result<Patch> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = (offset_base + 0x1446) + ((internal_id as usize) * 0x03);
+        let (bank_id, offset) = self.offsets.pokemon_cries;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((internal_id as usize) * 0x03);
 
         let pokemon_cry_data = pokemon_cry.to_raw();
 
@@ -1,6 +1,7 @@
-use crate::error::{self, Result};
+use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -34,8 +35,9 @@ impl PkmnapiDB {
     /// );
     /// ```
     pub fn get_pokemon_evolutions(&self, pokedex_id: &u8) -> Result<Vec<PokemonEvolution>> {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = offset_base + 0x305C;
+        let (bank_id, offset) = self.offsets.pokemon_evolutions;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
@@ -111,32 +113,24 @@ impl PkmnapiDB {
     /// let rom = fs::read(rom_path).unwrap();
     /// let db = PkmnapiDB::new(&rom).build().unwrap();
     ///
-    /// let patch = db.set_pokemon_evolutions(&1, &vec![
+    /// let patch_set = db.set_pokemon_evolutions(&1, &vec![
     ///     PokemonEvolutionLevel::new(2, 16)
     /// ]).unwrap();
     ///
     /// assert_eq!(
-    ///     patch,
-    ///     Patch {
+    ///     patch_set.patches,
+    ///     vec![Patch {
     ///         offset: 0x3B844,
     ///         length: 0x03,
     ///         data: vec![0x01, 0x10, 0x09]
-    ///     }
+    ///     }]
     /// );
     /// ```
     pub fn set_pokemon_evolutions(
         &self,
         pokedex_id: &u8,
         pokemon_evolutions: &Vec<PokemonEvolution>,
-    ) -> Result<Patch> {
-        let old_pokemon_evolutions = self.get_pokemon_evolutions(pokedex_id)?;
-        let old_pokemon_evolutions_data: Vec<u8> = old_pokemon_evolutions
-            .iter()
-            .map(|pokemon_evolution| pokemon_evolution.to_raw())
-            .flatten()
-            .collect();
-        let old_pokemon_evolutions_data_len = old_pokemon_evolutions_data.len();
-
+    ) -> Result<PatchSet> {
         let pokemon_evolutions_data: Vec<u8> = pokemon_evolutions
             .iter()
             .map(|pokemon_evolution| {
@@ -171,24 +165,83 @@ impl PkmnapiDB {
             })
             .flatten()
             .collect();
-        let pokemon_evolutions_data_len = pokemon_evolutions_data.len();
-
-        if old_pokemon_evolutions_data_len != pokemon_evolutions_data_len {
-            return Err(error::Error::PokemonEvolutionWrongSize(
-                old_pokemon_evolutions_data_len,
-                pokemon_evolutions_data_len,
-            ));
-        }
+        self.set_pokemon_evolutions_learnset_data(pokedex_id, Some(pokemon_evolutions_data), None)
+    }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = offset_base + 0x305C;
+    /// Write evolution and learnset data by Pokédex ID
+    ///
+    /// Evolutions and learnsets share a single block of
+    /// `[evolutions..., 0x00, learnset..., 0x00]`. When neither part changes
+    /// in size only the replaced part is patched. Otherwise the whole block
+    /// is rewritten in place if it fits, or moved to free space in the same
+    /// bank with its pointer updated.
+    pub(crate) fn set_pokemon_evolutions_learnset_data(
+        &self,
+        pokedex_id: &u8,
+        pokemon_evolutions_data: Option<Vec<u8>>,
+        pokemon_learnset_data: Option<Vec<u8>>,
+    ) -> Result<PatchSet> {
+        let (bank_id, offset) = self.offsets.pokemon_evolutions;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
         let pointer_offset = offset + ((internal_id as usize) * 0x02);
         let pointer = offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(pointer_offset);
 
-        Ok(Patch::new(&pointer, &pokemon_evolutions_data))
+        let old_pokemon_evolutions_data_len =
+            self.rom[pointer..].iter().position(|&r| r == 0x00).unwrap();
+        let learnset_pointer = pointer + old_pokemon_evolutions_data_len + 0x01;
+        let old_pokemon_learnset_data_len = self.rom[learnset_pointer..]
+            .chunks(2)
+            .take_while(|chunk| chunk[0] != 0x00)
+            .count()
+            * 0x02;
+
+        let set_pokemon_evolutions = pokemon_evolutions_data.is_some();
+        let pokemon_evolutions_data = pokemon_evolutions_data.unwrap_or_else(|| {
+            self.rom[pointer..(pointer + old_pokemon_evolutions_data_len)].to_vec()
+        });
+        let pokemon_learnset_data = pokemon_learnset_data.unwrap_or_else(|| {
+            self.rom[learnset_pointer..(learnset_pointer + old_pokemon_learnset_data_len)].to_vec()
+        });
+
+        if pokemon_evolutions_data.len() == old_pokemon_evolutions_data_len
+            && pokemon_learnset_data.len() == old_pokemon_learnset_data_len
+        {
+            if set_pokemon_evolutions {
+                return Ok(PatchSet::from(Patch::new(
+                    &pointer,
+                    &pokemon_evolutions_data,
+                )));
+            }
+
+            return Ok(PatchSet::from(Patch::new(
+                &learnset_pointer,
+                &pokemon_learnset_data,
+            )));
+        }
+
+        let old_data_len = old_pokemon_evolutions_data_len + old_pokemon_learnset_data_len + 0x02;
+        let data = [
+            pokemon_evolutions_data,
+            vec![0x00],
+            pokemon_learnset_data,
+            vec![0x00],
+        ]
+        .concat();
+
+        if data.len() <= old_data_len {
+            return Ok(PatchSet::from(Patch::new(&pointer, &data)));
+        }
+
+        let new_pointer = self.get_free_space(&0x0E, &data.len())?;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&new_pointer, &data),
+            Patch::new(&pointer_offset, &PkmnapiDB::offset_to_pointer(new_pointer)),
+        ]))
     }
 }
 
@@ -218,7 +271,7 @@ impl PkmnapiDB {
 ///     PokemonEvolution::TRADE(Default::default())
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub enum PokemonEvolution {
     LEVEL(PokemonEvolutionLevel),
     ITEM(PokemonEvolutionItem),
@@ -298,10 +351,11 @@ impl PokemonEvolution {
 ///     PokemonEvolution::LEVEL(Default::default())
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct PokemonEvolutionLevel {
     pub level: u8,
     pub pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) internal_id: u8,
 }
 
@@ -349,10 +403,11 @@ impl PokemonEvolutionLevel {
 ///     PokemonEvolution::ITEM(Default::default())
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct PokemonEvolutionItem {
     pub item_id: u8,
     pub pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) internal_id: u8,
 }
 
@@ -400,9 +455,10 @@ impl PokemonEvolutionItem {
 ///     PokemonEvolution::TRADE(Default::default())
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct PokemonEvolutionTrade {
     pub pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) internal_id: u8,
 }
 
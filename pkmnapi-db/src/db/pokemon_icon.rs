@@ -33,8 +33,9 @@ impl PkmnapiDB {
     pub fn get_pokemon_icon(&self, pokedex_id: &u8) -> Result<PokemonIcon> {
         let _internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = (offset_base + 0x190D) + ((((*pokedex_id - 1) as f32) / 2.0).floor() as usize);
+        let (bank_id, offset) = self.offsets.pokemon_icons;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((((*pokedex_id - 1) as f32) / 2.0).floor() as usize);
 
         let icon_id = if pokedex_id % 2 == 0 {
             self.rom[offset] & 0x0F
@@ -80,8 +81,9 @@ impl PkmnapiDB {
     pub fn set_pokemon_icon(&self, pokedex_id: &u8, pokemon_icon: &PokemonIcon) -> Result<Patch> {
         let _internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = (offset_base + 0x190D) + ((((*pokedex_id - 1) as f32) / 2.0).floor() as usize);
+        let (bank_id, offset) = self.offsets.pokemon_icons;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((((*pokedex_id - 1) as f32) / 2.0).floor() as usize);
 
         let data = if pokedex_id % 2 == 0 {
             vec![(self.rom[offset] & 0xF0) | pokemon_icon.value()]
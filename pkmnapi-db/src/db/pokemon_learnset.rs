@@ -1,6 +1,7 @@
-use crate::error::{self, Result};
+use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -40,8 +41,9 @@ impl PkmnapiDB {
     /// );
     /// ```
     pub fn get_pokemon_learnset(&self, pokedex_id: &u8) -> Result<Vec<PokemonLearnset>> {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = offset_base + 0x305C;
+        let (bank_id, offset) = self.offsets.pokemon_evolutions;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
@@ -73,7 +75,7 @@ impl PkmnapiDB {
     /// let rom = fs::read(rom_path).unwrap();
     /// let db = PkmnapiDB::new(&rom).build().unwrap();
     ///
-    /// let patch = db.set_pokemon_learnset(&1, &vec![
+    /// let patch_set = db.set_pokemon_learnset(&1, &vec![
     ///     PokemonLearnset::new(1, 2),
     ///     PokemonLearnset::new(1, 2),
     ///     PokemonLearnset::new(1, 2),
@@ -84,53 +86,26 @@ impl PkmnapiDB {
     /// ]).unwrap();
     ///
     /// assert_eq!(
-    ///     patch,
-    ///     Patch {
+    ///     patch_set.patches,
+    ///     vec![Patch {
     ///         offset: 0x3B848,
     ///         length: 0x0E,
     ///         data: vec![0x01, 0x02, 0x01, 0x02, 0x01, 0x02, 0x01, 0x02, 0x01, 0x02, 0x01, 0x02, 0x01, 0x02]
-    ///     }
+    ///     }]
     /// );
     /// ```
     pub fn set_pokemon_learnset(
         &self,
         pokedex_id: &u8,
         pokemon_learnset: &Vec<PokemonLearnset>,
-    ) -> Result<Patch> {
-        let old_pokemon_learnset = self.get_pokemon_learnset(pokedex_id)?;
-        let old_pokemon_learnset_data: Vec<u8> = old_pokemon_learnset
-            .iter()
-            .map(|pokemon_learnset| pokemon_learnset.to_raw())
-            .flatten()
-            .collect();
-        let old_pokemon_learnset_data_len = old_pokemon_learnset_data.len();
+    ) -> Result<PatchSet> {
         let pokemon_learnset_data: Vec<u8> = pokemon_learnset
             .iter()
             .map(|pokemon_learnset| pokemon_learnset.to_raw())
             .flatten()
             .collect();
-        let pokemon_learnset_data_len = pokemon_learnset_data.len();
-
-        if old_pokemon_learnset_data_len != pokemon_learnset_data_len {
-            return Err(error::Error::PokemonLearnsetWrongSize(
-                old_pokemon_learnset_data_len,
-                pokemon_learnset_data_len,
-            ));
-        }
-
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = offset_base + 0x305C;
-
-        let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
-
-        let pointer_offset = offset + ((internal_id as usize) * 0x02);
-        let pointer = offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(pointer_offset);
-
-        let position = self.rom[pointer..].iter().position(|&r| r == 0x00).unwrap() + 0x01;
-
-        let pointer = pointer + position;
 
-        Ok(Patch::new(&pointer, &pokemon_learnset_data))
+        self.set_pokemon_evolutions_learnset_data(pokedex_id, None, Some(pokemon_learnset_data))
     }
 }
 
@@ -149,7 +124,7 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct PokemonLearnset {
     pub level: u8,
     pub move_id: u8,
@@ -5,8 +5,9 @@ use crate::PkmnapiDB;
 
 impl PkmnapiDB {
     pub fn get_pokemon_logo_img(&self) -> Result<Img> {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x04;
-        let offset = offset_base + 0x1380;
+        let (bank_id, offset) = self.offsets.pokemon_logo_img;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let tiles = self.get_tiles(offset, 16 * 7, true);
 
@@ -29,8 +30,9 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x04;
-        let offset = offset_base + 0x1380;
+        let (bank_id, offset) = self.offsets.pokemon_logo_img;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         Ok(Patch::new(&offset, &pokemon_logo_data))
     }
@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
@@ -54,11 +55,12 @@ impl PkmnapiDB {
 
         let offset = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                0x425B
+                self.offsets.mew_stats
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
 
-                (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C)
+                (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C)
             }
         } + 0x14;
 
@@ -158,11 +160,12 @@ impl PkmnapiDB {
 
         let offset = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                0x425B
+                self.offsets.mew_stats
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
 
-                (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C)
+                (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C)
             }
         } + 0x14;
 
@@ -213,7 +216,7 @@ impl PkmnapiDB {
     }
 }
 
-#[derive(Clone, Debug, Eq)]
+#[derive(Clone, Debug, Eq, Deserialize, Serialize)]
 pub enum PokemonMachine {
     TM(u8),
     HM(u8),
@@ -37,11 +37,12 @@ impl PkmnapiDB {
 
         let offset = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                0x425B
+                self.offsets.mew_stats
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
 
-                (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C)
+                (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C)
             }
         } + 0x0F;
 
@@ -103,11 +104,12 @@ impl PkmnapiDB {
 
         let offset = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                0x425B
+                self.offsets.mew_stats
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
 
-                (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C)
+                (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C)
             }
         } + 0x0F;
 
@@ -2,6 +2,7 @@ use crate::error::Result;
 use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -35,8 +36,9 @@ impl PkmnapiDB {
     pub fn get_pokemon_name(&self, pokedex_id: &u8) -> Result<PokemonName> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x07;
-        let offset = (offset_base + 0x021E) + ((internal_id as usize) * 0x0A);
+        let (bank_id, offset) = self.offsets.pokemon_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((internal_id as usize) * 0x0A);
 
         let pokemon_name = PokemonName::from(&self.rom[offset..(offset + 0x0A)]);
 
@@ -79,8 +81,9 @@ impl PkmnapiDB {
     pub fn set_pokemon_name(&self, pokedex_id: &u8, pokemon_name: &PokemonName) -> Result<Patch> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x07;
-        let offset = (offset_base + 0x021E) + ((internal_id as usize) * 0x0A);
+        let (bank_id, offset) = self.offsets.pokemon_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((internal_id as usize) * 0x0A);
 
         let pokemon_name_len = pokemon_name.name.value.len();
         let pokemon_name_raw = pokemon_name.to_raw();
@@ -109,7 +112,8 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct PokemonName {
     pub name: ROMString,
 }
@@ -1,4 +1,4 @@
-use crate::error::{self, Result};
+use crate::error::Result;
 use crate::patch::*;
 use crate::pic::*;
 use crate::PkmnapiDB;
@@ -28,52 +28,20 @@ impl PkmnapiDB {
         pokedex_id: &u8,
         pokemon_pic_face: &PokemonPicFace,
     ) -> Result<Pic> {
-        let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
-        let (_, max_pokedex_id) = self.pokedex_id_bounds();
+        let (_, offset, _) = self.get_pokemon_pic_offset(pokedex_id, pokemon_pic_face)?;
 
-        let (offset, bank_offset) = {
-            if pokedex_id == &(max_pokedex_id as u8) {
-                let offset_base = PkmnapiDB::ROM_PAGE;
-                let offset = offset_base + 0x025B;
-                let bank_offset = (self.rom[0x163A] - 1) * 0x02;
-
-                (offset, bank_offset as usize)
-            } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-                let offset = (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C);
-
-                let bank_offset = match internal_id {
-                    _ if internal_id < self.rom[0x1646] - 1 => self.rom[0x1648],
-                    _ if internal_id < self.rom[0x164D] - 1 => self.rom[0x164F],
-                    _ if internal_id < self.rom[0x1654] - 1 => self.rom[0x1656],
-                    _ if internal_id < self.rom[0x165B] - 1 => self.rom[0x165D],
-                    _ => self.rom[0x1661],
-                };
-                let bank_offset = bank_offset - 1;
-
-                (offset, bank_offset as usize)
-            }
-        };
-
-        let pointer_front = self.get_pointer(offset + 11);
-        let pointer_back = self.get_pointer(offset + 13);
-
-        let offset_base = PkmnapiDB::ROM_PAGE * bank_offset;
-        let offset_front = offset_base + pointer_front;
-        let offset_back = offset_base + pointer_back;
-
-        let pointer = match pokemon_pic_face {
-            PokemonPicFace::FRONT => offset_front,
-            PokemonPicFace::BACK => offset_back,
-        };
-
-        let pic = Pic::new(&self.rom[pointer..])?;
+        let pic = Pic::new(&self.rom[offset..])?;
 
         Ok(pic)
     }
 
     /// Set Pokémon pic by Pokédex ID
     ///
+    /// Without an encoding method, every method is tried and the smallest
+    /// result is kept. Pics that no longer fit are moved to free space in the
+    /// same bank and the pointer in the Pokémon's base stats is updated.
+    /// Front pics also update the sprite dimensions in the base stats.
+    ///
     /// # Example
     ///
     /// ```
@@ -88,15 +56,22 @@ impl PkmnapiDB {
     /// let db = PkmnapiDB::new(&rom).build().unwrap();
     ///
     /// let pokemon_pic = Pic::new(&vec![0x55]).unwrap();
-    /// let patch = db.set_pokemon_pic(&1, &PokemonPicFace::FRONT, &pokemon_pic, PicEncodingMethod::THREE(0x01)).unwrap();
+    /// let patch_set = db.set_pokemon_pic(&1, &PokemonPicFace::FRONT, &pokemon_pic, Some(PicEncodingMethod::THREE(0x01))).unwrap();
     ///
     /// assert_eq!(
-    ///     patch,
-    ///     Patch {
-    ///         offset: 0x34000,
-    ///         length: 0x07,
-    ///         data: vec![0x55, 0xBF, 0xD2, 0x1D, 0xFE, 0x90, 0x80]
-    ///     }
+    ///     patch_set.patches,
+    ///     vec![
+    ///         Patch {
+    ///             offset: 0x34000,
+    ///             length: 0x07,
+    ///             data: vec![0x55, 0xBF, 0xD2, 0x1D, 0xFE, 0x90, 0x80]
+    ///         },
+    ///         Patch {
+    ///             offset: 0x383E8,
+    ///             length: 0x01,
+    ///             data: vec![0x55]
+    ///         }
+    ///     ]
     /// );
     /// ```
     pub fn set_pokemon_pic(
@@ -104,35 +79,53 @@ impl PkmnapiDB {
         pokedex_id: &u8,
         pokemon_pic_face: &PokemonPicFace,
         pic: &Pic,
-        encoding_method: PicEncodingMethod,
-    ) -> Result<Patch> {
-        let old_pixels = self.get_pokemon_pic(pokedex_id, pokemon_pic_face)?;
-        let pixels = pic.encode(encoding_method);
+        encoding_method: Option<PicEncodingMethod>,
+    ) -> Result<PatchSet> {
+        let (bank_id, offset, pointer_offset) =
+            self.get_pokemon_pic_offset(pokedex_id, pokemon_pic_face)?;
+
+        let mut patch_set =
+            self.set_pic(&bank_id, &offset, &pointer_offset, pic, encoding_method)?;
 
-        if pixels.len() > old_pixels.bytes + 1 {
-            return Err(error::Error::PicTooLarge);
+        if pokemon_pic_face == &PokemonPicFace::FRONT {
+            let dimensions_offset = pointer_offset - 1;
+            let dimensions = (pic.width << 0x04) | pic.height;
+
+            patch_set.push(Patch::new(&dimensions_offset, &vec![dimensions]));
         }
 
+        Ok(patch_set)
+    }
+
+    /// Returns the pic's bank ID, the offset of the pic and the offset of its
+    /// pointer in the Pokémon's base stats
+    fn get_pokemon_pic_offset(
+        &self,
+        pokedex_id: &u8,
+        pokemon_pic_face: &PokemonPicFace,
+    ) -> Result<(usize, usize, usize)> {
         let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
         let (_, max_pokedex_id) = self.pokedex_id_bounds();
 
+        let banks = self.offsets.pokemon_pic_banks;
+
         let (offset, bank_offset) = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                let offset_base = PkmnapiDB::ROM_PAGE;
-                let offset = offset_base + 0x025B;
-                let bank_offset = (self.rom[0x163A] - 1) * 0x02;
+                let offset = self.offsets.mew_stats;
+                let bank_offset = (self.rom[banks] - 1) * 0x02;
 
                 (offset, bank_offset as usize)
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-                let offset = (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C);
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+                let offset = (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C);
 
                 let bank_offset = match internal_id {
-                    _ if internal_id < self.rom[0x1646] - 1 => self.rom[0x1648],
-                    _ if internal_id < self.rom[0x164D] - 1 => self.rom[0x164F],
-                    _ if internal_id < self.rom[0x1654] - 1 => self.rom[0x1656],
-                    _ if internal_id < self.rom[0x165B] - 1 => self.rom[0x165D],
-                    _ => self.rom[0x1661],
+                    _ if internal_id < self.rom[banks + 0x0C] - 1 => self.rom[banks + 0x0E],
+                    _ if internal_id < self.rom[banks + 0x13] - 1 => self.rom[banks + 0x15],
+                    _ if internal_id < self.rom[banks + 0x1A] - 1 => self.rom[banks + 0x1C],
+                    _ if internal_id < self.rom[banks + 0x21] - 1 => self.rom[banks + 0x23],
+                    _ => self.rom[banks + 0x27],
                 };
                 let bank_offset = bank_offset - 1;
 
@@ -140,19 +133,15 @@ impl PkmnapiDB {
             }
         };
 
-        let pointer_front = self.get_pointer(offset + 11);
-        let pointer_back = self.get_pointer(offset + 13);
+        let pointer_offset = match pokemon_pic_face {
+            PokemonPicFace::FRONT => offset + 11,
+            PokemonPicFace::BACK => offset + 13,
+        };
 
         let offset_base = PkmnapiDB::ROM_PAGE * bank_offset;
-        let offset_front = offset_base + pointer_front;
-        let offset_back = offset_base + pointer_back;
-
-        let pointer = match pokemon_pic_face {
-            PokemonPicFace::FRONT => offset_front,
-            PokemonPicFace::BACK => offset_back,
-        };
+        let pic_offset = offset_base + self.get_pointer(pointer_offset);
 
-        Ok(Patch::new(&pointer, &pixels))
+        Ok((bank_offset + 1, pic_offset, pointer_offset))
     }
 }
 
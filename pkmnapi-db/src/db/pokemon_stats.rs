@@ -2,6 +2,7 @@ use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
 use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{Cursor, Read, Seek, SeekFrom};
 
@@ -50,11 +51,12 @@ impl PkmnapiDB {
 
         let offset = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                0x425B
+                self.offsets.mew_stats
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
 
-                (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C)
+                (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C)
             }
         };
 
@@ -114,11 +116,12 @@ impl PkmnapiDB {
 
         let offset = {
             if pokedex_id == &(max_pokedex_id as u8) {
-                0x425B
+                self.offsets.mew_stats
             } else {
-                let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+                let (bank_id, offset) = self.offsets.pokemon_stats;
+                let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
 
-                (offset_base + 0x03DE) + (((*pokedex_id as usize) - 1) * 0x1C)
+                (offset_base + offset) + (((*pokedex_id as usize) - 1) * 0x1C)
             }
         };
 
@@ -164,7 +167,7 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct PokemonStats {
     pub pokedex_id: u8,
     pub base_hp: u8,
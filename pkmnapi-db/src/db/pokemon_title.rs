@@ -67,8 +67,9 @@ impl PkmnapiDB {
     /// );
     /// ```
     pub fn get_pokemon_title(&self) -> Result<Vec<u8>> {
-        let offset_base = PkmnapiDB::ROM_PAGE;
-        let offset = offset_base + 0x0588;
+        let (bank_id, offset) = self.offsets.pokemon_title;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let pokemon_title = self.rom[offset..(offset + 0x10)].to_vec();
 
@@ -118,8 +119,9 @@ impl PkmnapiDB {
     /// );
     /// ```
     pub fn set_pokemon_title(&self, pokemon_title: &Vec<u8>) -> Result<Patch> {
-        let offset_base = PkmnapiDB::ROM_PAGE;
-        let offset = offset_base + 0x0588;
+        let (bank_id, offset) = self.offsets.pokemon_title;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let data = pokemon_title.to_vec();
         let data_len = data.len();
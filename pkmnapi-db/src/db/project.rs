@@ -0,0 +1,416 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+macro_rules! import_value {
+    ($db:ident, $get:ident, $set:ident, $id:expr, $value:expr) => {
+        if &$db.$get($id)? != $value {
+            let patch = $db.$set($id, $value)?;
+
+            $db.apply_patch(patch)?;
+        }
+    };
+}
+
+impl PkmnapiDB {
+    /// Export all editable ROM data as a project
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let project = db.export_project().unwrap();
+    ///
+    /// assert_eq!(project.version, Project::VERSION);
+    /// assert_eq!(project.pokemon.len(), 151);
+    /// assert_eq!(project.pokemon[&1].name, db.get_pokemon_name(&1).unwrap());
+    /// assert_eq!(project.moves.len(), 165);
+    /// assert_eq!(project.trainers.len(), 47);
+    /// ```
+    pub fn export_project(&self) -> Result<Project> {
+        let pokedex_ids = PkmnapiDB::project_ids(self.pokedex_id_bounds());
+        let move_ids = PkmnapiDB::project_ids(self.move_id_bounds());
+        let type_ids = PkmnapiDB::project_ids(self.type_id_bounds());
+        let type_effect_ids = PkmnapiDB::project_ids(self.type_effect_id_bounds());
+        let trainer_ids = PkmnapiDB::project_ids(self.trainer_id_bounds());
+        let mart_ids = PkmnapiDB::project_ids(self.mart_id_bounds());
+        let trade_ids = PkmnapiDB::project_ids(self.trade_id_bounds());
+        let map_ids = PkmnapiDB::project_ids(self.map_id_bounds());
+        let tm_ids = PkmnapiDB::project_ids(self.tm_id_bounds());
+        let hm_ids = PkmnapiDB::project_ids(self.hm_id_bounds());
+
+        let mut pokemon_names = self.get_pokemon_name_all(&pokedex_ids)?;
+        let mut pokemon_stats = self.get_pokemon_stats_all(&pokedex_ids)?;
+        let mut pokemon_learnsets = self.get_pokemon_learnset_all(&pokedex_ids)?;
+        let mut pokemon_movesets = self.get_pokemon_moveset_all(&pokedex_ids)?;
+        let mut pokemon_evolutions = self.get_pokemon_evolutions_all(&pokedex_ids)?;
+        let mut pokemon_machines = self.get_pokemon_machines_all(&pokedex_ids)?;
+        let mut pokedex_entries = self.get_pokedex_entry_all(&pokedex_ids)?;
+        let mut pokedex_texts = self.get_pokedex_text_all(&pokedex_ids)?;
+
+        let pokemon = pokedex_ids
+            .iter()
+            .map(|pokedex_id| {
+                let project_pokemon = ProjectPokemon {
+                    name: pokemon_names.remove(pokedex_id).unwrap(),
+                    stats: pokemon_stats.remove(pokedex_id).unwrap(),
+                    learnset: pokemon_learnsets.remove(pokedex_id).unwrap(),
+                    moveset: pokemon_movesets.remove(pokedex_id).unwrap(),
+                    evolutions: pokemon_evolutions.remove(pokedex_id).unwrap(),
+                    machines: pokemon_machines.remove(pokedex_id).unwrap(),
+                    pokedex_entry: pokedex_entries.remove(pokedex_id).unwrap(),
+                    pokedex_text: pokedex_texts.remove(pokedex_id).unwrap(),
+                };
+
+                (*pokedex_id, project_pokemon)
+            })
+            .collect();
+
+        let mut move_names = self.get_move_name_all(&move_ids)?;
+        let mut move_stats = self.get_move_stats_all(&move_ids)?;
+
+        let moves = move_ids
+            .iter()
+            .map(|move_id| {
+                let project_move = ProjectMove {
+                    name: move_names.remove(move_id).unwrap(),
+                    stats: move_stats.remove(move_id).unwrap(),
+                };
+
+                (*move_id, project_move)
+            })
+            .collect();
+
+        let mut trainer_names = self.get_trainer_name_all(&trainer_ids)?;
+        let mut trainer_parties = self.get_trainer_parties_all(&trainer_ids)?;
+        let mut trainer_rewards = self.get_trainer_reward_all(&trainer_ids)?;
+
+        let trainers = trainer_ids
+            .iter()
+            .map(|trainer_id| {
+                let project_trainer = ProjectTrainer {
+                    name: trainer_names.remove(trainer_id).unwrap(),
+                    parties: trainer_parties.remove(trainer_id).unwrap(),
+                    reward: trainer_rewards.remove(trainer_id).unwrap(),
+                };
+
+                (*trainer_id, project_trainer)
+            })
+            .collect();
+
+        let mut tm_moves = self.get_tm_move_all(&tm_ids)?;
+        let mut tm_prices = self.get_tm_price_all(&tm_ids)?;
+
+        let tms = tm_ids
+            .iter()
+            .map(|tm_id| {
+                let project_tm = ProjectTM {
+                    tm_move: tm_moves.remove(tm_id).unwrap(),
+                    tm_price: tm_prices.remove(tm_id).unwrap(),
+                };
+
+                (*tm_id, project_tm)
+            })
+            .collect();
+
+        Ok(Project {
+            version: Project::VERSION,
+            pokemon,
+            moves,
+            types: PkmnapiDB::project_map(self.get_type_name_all(&type_ids)?),
+            type_effects: PkmnapiDB::project_map(self.get_type_effect_all(&type_effect_ids)?),
+            trainers,
+            marts: PkmnapiDB::project_map(self.get_mart_items_all(&mart_ids)?),
+            trades: PkmnapiDB::project_map(self.get_trade_all(&trade_ids)?),
+            map_pokemon: PkmnapiDB::project_map(self.get_map_pokemon_all(&map_ids)?),
+            tms,
+            hms: PkmnapiDB::project_map(self.get_hm_move_all(&hm_ids)?),
+        })
+    }
+
+    /// Import a project, returning the patches needed to turn the ROM into it
+    ///
+    /// Only entries that differ from the ROM are written. Entries missing
+    /// from the project are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let mut project = db.export_project().unwrap();
+    ///
+    /// let patch_set = db.import_project(&project).unwrap();
+    ///
+    /// assert_eq!(patch_set.patches.len(), 0);
+    ///
+    /// project.types.insert(
+    ///     0,
+    ///     TypeName {
+    ///         name: ROMString::from("BORING"),
+    ///     },
+    /// );
+    ///
+    /// let patch_set = db.import_project(&project).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(
+    ///     db.get_type_name(&0).unwrap(),
+    ///     TypeName {
+    ///         name: ROMString::from("BORING"),
+    ///     }
+    /// );
+    /// ```
+    pub fn import_project(&self, project: &Project) -> Result<PatchSet> {
+        if project.version != Project::VERSION {
+            return Err(error::Error::ProjectVersionInvalid(
+                Project::VERSION,
+                project.version,
+            ));
+        }
+
+        let mut db = self.clone();
+
+        for (pokedex_id, pokemon) in project.pokemon.iter() {
+            import_value!(
+                db,
+                get_pokemon_name,
+                set_pokemon_name,
+                pokedex_id,
+                &pokemon.name
+            );
+            import_value!(
+                db,
+                get_pokemon_stats,
+                set_pokemon_stats,
+                pokedex_id,
+                &pokemon.stats
+            );
+            import_value!(
+                db,
+                get_pokemon_evolutions,
+                set_pokemon_evolutions,
+                pokedex_id,
+                &pokemon.evolutions
+            );
+            import_value!(
+                db,
+                get_pokemon_learnset,
+                set_pokemon_learnset,
+                pokedex_id,
+                &pokemon.learnset
+            );
+            import_value!(
+                db,
+                get_pokemon_moveset,
+                set_pokemon_moveset,
+                pokedex_id,
+                &pokemon.moveset
+            );
+            import_value!(
+                db,
+                get_pokemon_machines,
+                set_pokemon_machines,
+                pokedex_id,
+                &pokemon.machines
+            );
+            import_value!(
+                db,
+                get_pokedex_entry,
+                set_pokedex_entry,
+                pokedex_id,
+                &pokemon.pokedex_entry
+            );
+            import_value!(
+                db,
+                get_pokedex_text,
+                set_pokedex_text,
+                pokedex_id,
+                &pokemon.pokedex_text
+            );
+        }
+
+        for (move_id, project_move) in project.moves.iter() {
+            import_value!(
+                db,
+                get_move_name,
+                set_move_name,
+                move_id,
+                &project_move.name
+            );
+            import_value!(
+                db,
+                get_move_stats,
+                set_move_stats,
+                move_id,
+                &project_move.stats
+            );
+        }
+
+        for (type_id, type_name) in project.types.iter() {
+            import_value!(db, get_type_name, set_type_name, type_id, type_name);
+        }
+
+        for (type_effect_id, type_effect) in project.type_effects.iter() {
+            import_value!(
+                db,
+                get_type_effect,
+                set_type_effect,
+                type_effect_id,
+                type_effect
+            );
+        }
+
+        for (trainer_id, trainer) in project.trainers.iter() {
+            import_value!(
+                db,
+                get_trainer_name,
+                set_trainer_name,
+                trainer_id,
+                &trainer.name
+            );
+            import_value!(
+                db,
+                get_trainer_parties,
+                set_trainer_parties,
+                trainer_id,
+                &trainer.parties
+            );
+            import_value!(
+                db,
+                get_trainer_reward,
+                set_trainer_reward,
+                trainer_id,
+                &trainer.reward
+            );
+        }
+
+        for (mart_id, mart_items) in project.marts.iter() {
+            import_value!(db, get_mart_items, set_mart_items, mart_id, mart_items);
+        }
+
+        for (trade_id, trade) in project.trades.iter() {
+            import_value!(db, get_trade, set_trade, trade_id, trade);
+        }
+
+        for (map_id, map_pokemon) in project.map_pokemon.iter() {
+            import_value!(db, get_map_pokemon, set_map_pokemon, map_id, map_pokemon);
+        }
+
+        for (tm_id, tm) in project.tms.iter() {
+            import_value!(db, get_tm_move, set_tm_move, tm_id, &tm.tm_move);
+            import_value!(db, get_tm_price, set_tm_price, tm_id, &tm.tm_price);
+        }
+
+        for (hm_id, hm_move) in project.hms.iter() {
+            import_value!(db, get_hm_move, set_hm_move, hm_id, hm_move);
+        }
+
+        Ok(PatchSet::from_diff(&self.rom, &db.rom))
+    }
+
+    fn project_ids((min_id, max_id): (usize, usize)) -> Vec<u8> {
+        (min_id..=max_id).map(|id| id as u8).collect()
+    }
+
+    fn project_map<T>(values: HashMap<u8, T>) -> BTreeMap<u8, T> {
+        values.into_iter().collect()
+    }
+}
+
+/// Versioned dump of all editable ROM data
+///
+/// Every collection is keyed by the same ID used by the matching
+/// `get_*`/`set_*` methods.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+/// use std::fs;
+/// # use std::env;
+/// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+///
+/// let rom = fs::read(rom_path).unwrap();
+/// let db = PkmnapiDB::new(&rom).build().unwrap();
+///
+/// let project = db.export_project().unwrap();
+///
+/// assert_eq!(project.version, 1);
+/// ```
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Project {
+    pub version: u32,
+    #[serde(default)]
+    pub pokemon: BTreeMap<u8, ProjectPokemon>,
+    #[serde(default)]
+    pub moves: BTreeMap<u8, ProjectMove>,
+    #[serde(default)]
+    pub types: BTreeMap<u8, TypeName>,
+    #[serde(default)]
+    pub type_effects: BTreeMap<u8, TypeEffect>,
+    #[serde(default)]
+    pub trainers: BTreeMap<u8, ProjectTrainer>,
+    #[serde(default)]
+    pub marts: BTreeMap<u8, Vec<MartItem>>,
+    #[serde(default)]
+    pub trades: BTreeMap<u8, Trade>,
+    #[serde(default)]
+    pub map_pokemon: BTreeMap<u8, MapPokemon>,
+    #[serde(default)]
+    pub tms: BTreeMap<u8, ProjectTM>,
+    #[serde(default)]
+    pub hms: BTreeMap<u8, HMMove>,
+}
+
+impl Project {
+    pub const VERSION: u32 = 1;
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct ProjectPokemon {
+    pub name: PokemonName,
+    pub stats: PokemonStats,
+    pub learnset: Vec<PokemonLearnset>,
+    pub moveset: Vec<u8>,
+    pub evolutions: Vec<PokemonEvolution>,
+    pub machines: Vec<PokemonMachine>,
+    pub pokedex_entry: PokedexEntry,
+    pub pokedex_text: PokedexText,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct ProjectMove {
+    pub name: MoveName,
+    pub stats: MoveStats,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct ProjectTrainer {
+    pub name: TrainerName,
+    pub parties: Vec<Party>,
+    pub reward: u32,
+}
+
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct ProjectTM {
+    pub tm_move: TMMove,
+    pub tm_price: TMPrice,
+}
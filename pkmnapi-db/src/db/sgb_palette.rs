@@ -0,0 +1,278 @@
+use crate::error::Result;
+use crate::img::*;
+use crate::PkmnapiDB;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+impl PkmnapiDB {
+    pub fn get_sgb_palette_all(
+        &self,
+        sgb_palette_ids: &Vec<u8>,
+    ) -> Result<HashMap<u8, SGBPalette>> {
+        self.get_all(sgb_palette_ids, |id| self.get_sgb_palette(id))
+    }
+
+    /// Get SGB palette by SGB palette ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let sgb_palette = db.get_sgb_palette(&0).unwrap();
+    ///
+    /// assert_eq!(sgb_palette.colors.len(), 4);
+    /// assert_eq!(sgb_palette.colors[0], SGBColor { r: 31, g: 29, b: 31 });
+    /// ```
+    pub fn get_sgb_palette(&self, sgb_palette_id: &u8) -> Result<SGBPalette> {
+        let _ = self.sgb_palette_id_validate(sgb_palette_id)?;
+
+        let (bank_id, offset) = self.offsets.sgb_palettes;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((*sgb_palette_id as usize) * 0x08);
+
+        let mut cursor = Cursor::new(&self.rom[offset..(offset + 0x08)]);
+
+        let colors = (0..4)
+            .map(|_| SGBColor::from(cursor.read_u16::<LittleEndian>().unwrap_or(0)))
+            .collect();
+
+        Ok(SGBPalette { colors })
+    }
+
+    /// Get SGB palette ID by Pokédex ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let sgb_palette_id = db.get_pokemon_sgb_palette_id(&1).unwrap();
+    ///
+    /// assert_eq!(sgb_palette_id, 0x16);
+    /// ```
+    pub fn get_pokemon_sgb_palette_id(&self, pokedex_id: &u8) -> Result<u8> {
+        let _ = self.pokedex_id_validate(pokedex_id)?;
+
+        Ok(self.rom[self.get_monster_palettes_offset() + (*pokedex_id as usize)])
+    }
+
+    /// Get SGB palette ID by trainer ID
+    ///
+    /// Trainers share the palette of the first entry of the Pokémon palette
+    /// table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let sgb_palette_id = db.get_trainer_sgb_palette_id(&1).unwrap();
+    ///
+    /// assert_eq!(sgb_palette_id, 0x10);
+    /// ```
+    pub fn get_trainer_sgb_palette_id(&self, trainer_id: &u8) -> Result<u8> {
+        let _ = self.trainer_id_validate(trainer_id)?;
+
+        Ok(self.rom[self.get_monster_palettes_offset()])
+    }
+
+    /// Get SGB palette ID by icon ID
+    ///
+    /// Icons are only shown in the party menu, which always uses the same
+    /// palette.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let sgb_palette_id = db.get_icon_sgb_palette_id(&0).unwrap();
+    ///
+    /// assert_eq!(sgb_palette_id, 0x10);
+    /// ```
+    pub fn get_icon_sgb_palette_id(&self, icon_id: &u8) -> Result<u8> {
+        let _ = self.icon_id_validate(icon_id)?;
+
+        Ok(SGB_PALETTE_MEWMON)
+    }
+
+    /// Get SGB palette ID by map ID
+    ///
+    /// Towns have their own palettes and routes share one. Caves, Pokémon
+    /// Tower and some Elite Four rooms are special cased. Every other map
+    /// takes the palette of the last outdoor map the player was on, which
+    /// is not known here, so the route palette is used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let sgb_palette_id = db.get_map_sgb_palette_id(&0).unwrap();
+    ///
+    /// assert_eq!(sgb_palette_id, 0x01);
+    ///
+    /// let sgb_palette_id = db.get_map_sgb_palette_id(&12).unwrap();
+    ///
+    /// assert_eq!(sgb_palette_id, 0x00);
+    /// ```
+    pub fn get_map_sgb_palette_id(&self, map_id: &u8) -> Result<u8> {
+        let map_header = self.get_map_header(map_id)?;
+
+        let sgb_palette_id = match (map_header.tileset_id, *map_id) {
+            (0x0F, _) => SGB_PALETTE_GRAYMON,
+            (0x11, _) => SGB_PALETTE_CAVE,
+            (_, 0x00..=0x0A) => map_id + 1,
+            (_, 0xE2..=0xE4) | (_, 0xF6) => SGB_PALETTE_CAVE,
+            (_, 0xF5) => SGB_PALETTE_PALLET,
+            _ => SGB_PALETTE_ROUTE,
+        };
+
+        Ok(sgb_palette_id)
+    }
+
+    /// Returns the offset of the Pokémon palette table
+    ///
+    /// The table is indexed by Pokédex ID. Entry 0 is used by trainers.
+    fn get_monster_palettes_offset(&self) -> usize {
+        let (bank_id, offset) = self.offsets.monster_palettes;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+
+        offset_base + offset
+    }
+}
+
+const SGB_PALETTE_ROUTE: u8 = 0x00;
+const SGB_PALETTE_PALLET: u8 = 0x01;
+const SGB_PALETTE_MEWMON: u8 = 0x10;
+const SGB_PALETTE_GRAYMON: u8 = 0x19;
+const SGB_PALETTE_CAVE: u8 = 0x23;
+
+/// SGB palette
+///
+/// Four colors, one for each shade from lightest to darkest.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let sgb_palette = SGBPalette {
+///     colors: vec![
+///         SGBColor::from(0x7FFF),
+///         SGBColor::from(0x001F),
+///         SGBColor::from(0x000F),
+///         SGBColor::from(0x0000),
+///     ],
+/// };
+///
+/// assert_eq!(
+///     sgb_palette.to_palette(),
+///     [
+///         [0xFF, 0xFF, 0xFF],
+///         [0xFF, 0x00, 0x00],
+///         [0x7B, 0x00, 0x00],
+///         [0x00, 0x00, 0x00]
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SGBPalette {
+    pub colors: Vec<SGBColor>,
+}
+
+impl SGBPalette {
+    /// Convert to RGB colors for rendering
+    pub fn to_palette(&self) -> Palette {
+        let mut palette = [[0x00; 3]; 4];
+
+        for (i, color) in self.colors.iter().take(4).enumerate() {
+            palette[i] = color.to_rgb();
+        }
+
+        palette
+    }
+}
+
+/// SGB color
+///
+/// Each channel is 5 bits, 0-31.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SGBColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl SGBColor {
+    /// Convert to 8-bit RGB
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    ///
+    /// let color = SGBColor { r: 31, g: 16, b: 0 };
+    ///
+    /// assert_eq!(color.to_rgb(), [0xFF, 0x84, 0x00]);
+    /// ```
+    pub fn to_rgb(&self) -> [u8; 3] {
+        let channel = |value: u8| (value << 3) | (value >> 2);
+
+        [channel(self.r), channel(self.g), channel(self.b)]
+    }
+}
+
+impl From<u16> for SGBColor {
+    /// Convert 15-bit BGR to SGBColor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    ///
+    /// let color = SGBColor::from(0x7C1F);
+    ///
+    /// assert_eq!(color, SGBColor { r: 31, g: 0, b: 31 });
+    /// ```
+    fn from(color: u16) -> Self {
+        SGBColor {
+            r: (color & 0x1F) as u8,
+            g: ((color >> 5) & 0x1F) as u8,
+            b: ((color >> 10) & 0x1F) as u8,
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::*;
+use std::collections::BTreeMap;
+
+impl PkmnapiDB {
+    /// Get starters
+    ///
+    /// Starters are returned in the order of the Poké Balls in Oak's lab,
+    /// from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let starters = db.get_starters().unwrap();
+    ///
+    /// assert_eq!(starters, vec![4, 7, 1]);
+    /// ```
+    pub fn get_starters(&self) -> Result<Vec<u8>> {
+        self.offsets
+            .starters
+            .iter()
+            .map(|offsets| {
+                let internal_id = self.rom[offsets[0]] - 1;
+
+                self.internal_id_to_pokedex_id(&internal_id)
+            })
+            .collect()
+    }
+
+    /// Set starters
+    ///
+    /// Updates the Poké Balls in Oak's lab, the rival's choice of starter,
+    /// the rival's parties and the Pokédex shown after choosing a starter.
+    /// The rival's parties have each Pokémon of an old starter's evolution
+    /// line replaced by the new starter's Pokémon of the same stage.
+    ///
+    /// The Pokédex shown after choosing a starter marks the starters as owned
+    /// one byte of `wPokedexOwned` at a time. When the starters need more
+    /// bytes than the current code sets, the code is moved to free space in
+    /// the same bank and replaced with a jump.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let patch_set = db.set_starters(&vec![25, 133, 147]).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_starters().unwrap(), vec![25, 133, 147]);
+    ///
+    /// let rival_parties = db.get_trainer_parties(&25).unwrap();
+    ///
+    /// assert_eq!(rival_parties[0].pokemon[0].pokedex_id, 133);
+    /// ```
+    pub fn set_starters(&self, starters: &Vec<u8>) -> Result<PatchSet> {
+        if starters.len() != self.offsets.starters.len() {
+            return Err(error::Error::StartersWrongSize(
+                self.offsets.starters.len(),
+                starters.len(),
+            ));
+        }
+
+        let old_starters = self.get_starters()?;
+        let mut patch_set = PatchSet::new(&vec![]);
+
+        for (offsets, pokedex_id) in self.offsets.starters.iter().zip(starters.iter()) {
+            let internal_id = self.pokedex_id_to_internal_id(pokedex_id)?;
+
+            for offset in offsets.iter() {
+                patch_set.push(Patch::new(offset, &vec![internal_id + 1]));
+            }
+        }
+
+        if let Some((offset, byte_count)) = self.get_starter_dex_offset() {
+            for patch in self
+                .set_starter_dex(&offset, &byte_count, starters)?
+                .patches
+            {
+                patch_set.push(patch);
+            }
+        }
+
+        let old_evolution_lines: Vec<Vec<u8>> = old_starters
+            .iter()
+            .map(|pokedex_id| self.get_evolution_line(pokedex_id))
+            .collect::<Result<_>>()?;
+        let evolution_lines: Vec<Vec<u8>> = starters
+            .iter()
+            .map(|pokedex_id| self.get_evolution_line(pokedex_id))
+            .collect::<Result<_>>()?;
+
+        for trainer_id in self.offsets.rival_trainer_ids.iter() {
+            let rival_parties: Vec<Party> = self
+                .get_trainer_parties(trainer_id)?
+                .iter()
+                .map(|party| Party {
+                    level_type: party.level_type,
+                    pokemon: party
+                        .pokemon
+                        .iter()
+                        .map(|party_pokemon| {
+                            let pokedex_id = old_evolution_lines
+                                .iter()
+                                .zip(evolution_lines.iter())
+                                .find_map(|(old_evolution_line, evolution_line)| {
+                                    old_evolution_line
+                                        .iter()
+                                        .position(|&x| x == party_pokemon.pokedex_id)
+                                        .map(|stage| {
+                                            evolution_line[stage.min(evolution_line.len() - 1)]
+                                        })
+                                })
+                                .unwrap_or(party_pokemon.pokedex_id);
+
+                            PartyPokemon::new(party_pokemon.level, pokedex_id)
+                        })
+                        .collect(),
+                })
+                .collect();
+
+            let rival_parties_patch_set = self.set_trainer_parties(trainer_id, &rival_parties)?;
+
+            for patch in rival_parties_patch_set.patches {
+                patch_set.push(patch);
+            }
+        }
+
+        Ok(patch_set)
+    }
+
+    /// Returns the Pokédex IDs of a Pokémon and its first two evolutions
+    fn get_evolution_line(&self, pokedex_id: &u8) -> Result<Vec<u8>> {
+        let mut evolution_line = vec![*pokedex_id];
+
+        while evolution_line.len() < 3 {
+            let pokemon_evolutions = self.get_pokemon_evolutions(evolution_line.last().unwrap())?;
+
+            let pokedex_id = match pokemon_evolutions.first() {
+                Some(PokemonEvolution::LEVEL(evolution)) => evolution.pokedex_id,
+                Some(PokemonEvolution::ITEM(evolution)) => evolution.pokedex_id,
+                Some(PokemonEvolution::TRADE(evolution)) => evolution.pokedex_id,
+                None => break,
+            };
+
+            if evolution_line.contains(&pokedex_id) {
+                break;
+            }
+
+            evolution_line.push(pokedex_id);
+        }
+
+        Ok(evolution_line)
+    }
+
+    /// Returns the offset of the code that marks the starters as owned
+    /// before showing their Pokédex data, and the number of bytes of
+    /// `wPokedexOwned` it sets
+    ///
+    /// The code is `ld a, <flags>` and `ld [wPokedexOwned + n], a` for each
+    /// byte, `predef ShowPokedexData`, `xor a`, `ld [wPokedexOwned + n], a`
+    /// for each byte and `ret`. The original code sets a single byte.
+    fn get_starter_dex_offset(&self) -> Option<(usize, usize)> {
+        (0..self.rom.len()).find_map(|offset| {
+            (1..=self.offsets.starters.len())
+                .find(|byte_count| self.is_starter_dex(&offset, byte_count))
+                .map(|byte_count| (offset, byte_count))
+        })
+    }
+
+    /// Checks for the code that marks the starters as owned at `offset`
+    fn is_starter_dex(&self, offset: &usize, byte_count: &usize) -> bool {
+        let code = match self
+            .rom
+            .get(*offset..(offset + starter_dex_len(byte_count)))
+        {
+            Some(code) => code,
+            None => return false,
+        };
+        let predef = &code[(byte_count * 0x05)..];
+        let clear = &predef[0x06..];
+
+        (0..*byte_count).all(|i| {
+            let set = &code[(i * 0x05)..];
+            let pointer = u16::from_le_bytes([set[3], set[4]]);
+
+            set[0] == 0x3E
+                && set[2] == 0xEA
+                && (self.offsets.pokedex_owned_pointer..(self.offsets.pokedex_owned_pointer + 19))
+                    .contains(&pointer)
+                && clear[i * 0x03] == 0xEA
+                && clear[((i * 0x03) + 1)..((i * 0x03) + 3)] == set[3..5]
+        }) && predef[0] == 0x3E
+            && predef[2] == 0xCD
+            && predef[5] == 0xAF
+            && clear[byte_count * 0x03] == 0xC9
+    }
+
+    /// Rewrites the code that marks the starters as owned, moving it to free
+    /// space when it no longer fits
+    fn set_starter_dex(
+        &self,
+        offset: &usize,
+        byte_count: &usize,
+        starters: &Vec<u8>,
+    ) -> Result<PatchSet> {
+        let pokedex_owned: BTreeMap<u16, u8> =
+            starters
+                .iter()
+                .fold(BTreeMap::new(), |mut pokedex_owned, pokedex_id| {
+                    let pointer =
+                        self.offsets.pokedex_owned_pointer + (((pokedex_id - 1) / 8) as u16);
+
+                    *pokedex_owned.entry(pointer).or_insert(0x00) |= 0x01 << ((pokedex_id - 1) % 8);
+
+                    pokedex_owned
+                });
+
+        let predef_offset = offset + (byte_count * 0x05);
+        let predef = &self.rom[predef_offset..(predef_offset + 0x05)];
+
+        let set: Vec<u8> = pokedex_owned
+            .iter()
+            .map(|(pointer, flags)| {
+                [vec![0x3E, *flags, 0xEA], pointer.to_le_bytes().to_vec()].concat()
+            })
+            .flatten()
+            .collect();
+        let clear: Vec<u8> = pokedex_owned
+            .keys()
+            .map(|pointer| [vec![0xEA], pointer.to_le_bytes().to_vec()].concat())
+            .flatten()
+            .collect();
+
+        let starter_dex_data = [set, predef.to_vec(), vec![0xAF], clear, vec![0xC9]].concat();
+
+        if starter_dex_data.len() <= starter_dex_len(byte_count) {
+            return Ok(PatchSet::from(Patch::new(offset, &starter_dex_data)));
+        }
+
+        let bank_id = offset / PkmnapiDB::ROM_PAGE;
+        let new_offset = self.get_free_space(&bank_id, &starter_dex_data.len())?;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&new_offset, &starter_dex_data),
+            Patch::new(
+                offset,
+                &[vec![0xC3], PkmnapiDB::offset_to_pointer(new_offset)].concat(),
+            ),
+        ]))
+    }
+}
+
+/// Length of the code that marks the starters as owned when it sets
+/// `byte_count` bytes of `wPokedexOwned`
+fn starter_dex_len(byte_count: &usize) -> usize {
+    (byte_count * 0x08) + 0x07
+}
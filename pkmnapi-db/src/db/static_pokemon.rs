@@ -0,0 +1,383 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl PkmnapiDB {
+    pub fn get_static_pokemon_all(
+        &self,
+        static_pokemon_ids: &Vec<u8>,
+    ) -> Result<HashMap<u8, StaticPokemon>> {
+        self.get_all(static_pokemon_ids, |id| self.get_static_pokemon(id))
+    }
+
+    /// Get static Pokémon by static Pokémon ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let static_pokemon = db.get_static_pokemon(&0).unwrap();
+    ///
+    /// match static_pokemon.static_pokemon_type {
+    ///     StaticPokemonType::ENCOUNTER(map_id) => assert!(map_id > 0),
+    ///     StaticPokemonType::GIFT => unreachable!(),
+    /// };
+    /// ```
+    pub fn get_static_pokemon(&self, static_pokemon_id: &u8) -> Result<StaticPokemon> {
+        self.static_pokemon_id_validate(static_pokemon_id)?;
+
+        let location = &self.get_static_pokemon_locations()[*static_pokemon_id as usize];
+
+        let internal_id = self.rom[location.species_offset] - 1;
+        let pokedex_id = self.internal_id_to_pokedex_id(&internal_id)?;
+        let level = self.rom[location.level_offset];
+
+        let static_pokemon = StaticPokemon {
+            pokedex_id,
+            level,
+            static_pokemon_type: location.static_pokemon_type.clone(),
+        };
+
+        Ok(static_pokemon)
+    }
+
+    /// Set static Pokémon by static Pokémon ID
+    ///
+    /// Only the species and level can be changed, so `static_pokemon_type`
+    /// must match the current static Pokémon. Levels must be 1-100.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let static_pokemon = db.get_static_pokemon(&0).unwrap();
+    /// let static_pokemon = StaticPokemon {
+    ///     pokedex_id: 151,
+    ///     level: 5,
+    ///     ..static_pokemon
+    /// };
+    ///
+    /// let patch_set = db.set_static_pokemon(&0, &static_pokemon).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_static_pokemon(&0).unwrap(), static_pokemon);
+    /// ```
+    pub fn set_static_pokemon(
+        &self,
+        static_pokemon_id: &u8,
+        static_pokemon: &StaticPokemon,
+    ) -> Result<PatchSet> {
+        self.static_pokemon_id_validate(static_pokemon_id)?;
+
+        let location = &self.get_static_pokemon_locations()[*static_pokemon_id as usize];
+
+        if static_pokemon.static_pokemon_type != location.static_pokemon_type {
+            return Err(error::Error::StaticPokemonTypeInvalid(*static_pokemon_id));
+        }
+
+        if !(1..=100).contains(&static_pokemon.level) {
+            return Err(error::Error::StaticPokemonLevelInvalid(
+                static_pokemon.level,
+            ));
+        }
+
+        let internal_id = self.pokedex_id_to_internal_id(&static_pokemon.pokedex_id)?;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&location.species_offset, &vec![internal_id + 1]),
+            Patch::new(&location.level_offset, &vec![static_pokemon.level]),
+        ]))
+    }
+
+    /// Returns the location of every static Pokémon
+    ///
+    /// Static encounters are ordered by map ID. Each map's Pokémon objects
+    /// come first, followed by the battles its map script starts by loading
+    /// `wCurOpponent` and `wCurEnemyLVL` (such as Snorlax on Routes 12 and 16).
+    ///
+    /// Gift Pokémon are ordered by the offset of their species. Most are given
+    /// with `lb bc, <species>, <level>` followed by `call GivePokemon`. The
+    /// revived fossils and the Fighting Dojo's Hitmonlee and Hitmonchan load
+    /// their species separately, and the three fossils share one level.
+    pub(crate) fn get_static_pokemon_locations(&self) -> Vec<StaticPokemonLocation> {
+        let mut locations: Vec<StaticPokemonLocation> = vec![];
+
+        for location in self
+            .get_static_encounter_locations()
+            .into_iter()
+            .chain(self.get_gift_pokemon_locations())
+        {
+            if !locations
+                .iter()
+                .any(|other| other.species_offset == location.species_offset)
+            {
+                locations.push(location);
+            }
+        }
+
+        locations
+    }
+
+    fn get_static_encounter_locations(&self) -> Vec<StaticPokemonLocation> {
+        let (min_map_id, max_map_id) = self.map_id_bounds();
+        let map_ids: Vec<u8> = (min_map_id..=max_map_id)
+            .map(|map_id| map_id as u8)
+            .collect();
+
+        let script_offsets: Vec<(u8, usize)> = map_ids
+            .iter()
+            .filter_map(|map_id| {
+                let (bank_id, header_offset) = self.get_map_header_offset(map_id).ok()?;
+                let bank = (bank_id - 0x01) * PkmnapiDB::ROM_PAGE;
+
+                Some((*map_id, bank + self.get_pointer(header_offset + 7)))
+            })
+            .collect();
+
+        let pokemon_internal_max = self.pokemon_internal_max();
+        let cur_opponent_pointer = self.offsets.cur_opponent_pointer.to_le_bytes();
+        let cur_enemy_level_pointer = self.offsets.cur_enemy_level_pointer.to_le_bytes();
+
+        let script_battles: Vec<(u8, usize)> = self
+            .rom
+            .windows(0x0A)
+            .enumerate()
+            .filter(|(_, code)| {
+                code[0] == 0x3E
+                    && (1..=pokemon_internal_max).contains(&(code[1] as usize))
+                    && code[2] == 0xEA
+                    && code[3..5] == cur_opponent_pointer
+                    && code[5] == 0x3E
+                    && (1..=100).contains(&code[6])
+                    && code[7] == 0xEA
+                    && code[8..] == cur_enemy_level_pointer
+            })
+            .filter_map(|(offset, _)| {
+                let (map_id, _) = script_offsets
+                    .iter()
+                    .filter(|(_, script_offset)| {
+                        *script_offset <= offset
+                            && script_offset / PkmnapiDB::ROM_PAGE == offset / PkmnapiDB::ROM_PAGE
+                    })
+                    .min_by_key(|(map_id, script_offset)| (offset - script_offset, *map_id))?;
+
+                Some((*map_id, offset))
+            })
+            .collect();
+
+        let mut locations: Vec<StaticPokemonLocation> = vec![];
+
+        for map_id in map_ids {
+            let objects_offset = match self.get_map_objects_offset(&map_id) {
+                Ok((_, objects_offset, _)) => objects_offset,
+                Err(_) => continue,
+            };
+
+            let warp_count = self.rom[objects_offset + 1] as usize;
+            let sign_offset = objects_offset + 2 + (warp_count * 0x04);
+            let sign_count = self.rom[sign_offset] as usize;
+            let object_offset = sign_offset + 1 + (sign_count * 0x03);
+            let object_count = self.rom[object_offset] as usize;
+            let mut i = object_offset + 1;
+
+            for _ in 0..object_count {
+                let object = MapObject::from(&self.rom[i..]);
+
+                if let MapObjectType::POKEMON(_) = object.object_type {
+                    locations.push(StaticPokemonLocation {
+                        static_pokemon_type: StaticPokemonType::ENCOUNTER(map_id),
+                        species_offset: i + 6,
+                        level_offset: i + 7,
+                    });
+                }
+
+                i += object.to_raw().len();
+            }
+
+            let battles = script_battles
+                .iter()
+                .filter(|(battle_map_id, _)| *battle_map_id == map_id)
+                .map(|(_, offset)| StaticPokemonLocation {
+                    static_pokemon_type: StaticPokemonType::ENCOUNTER(map_id),
+                    species_offset: offset + 1,
+                    level_offset: offset + 6,
+                });
+
+            locations.extend(battles);
+        }
+
+        locations
+    }
+
+    fn get_gift_pokemon_locations(&self) -> Vec<StaticPokemonLocation> {
+        let give_pokemon_pointer = self.offsets.give_pokemon_pointer.to_le_bytes();
+        let pokemon_internal_max = self.pokemon_internal_max();
+        let is_species = |species: &u8| (1..=pokemon_internal_max).contains(&(*species as usize));
+
+        let mut locations: Vec<StaticPokemonLocation> = self
+            .rom
+            .windows(0x06)
+            .enumerate()
+            .filter(|(_, code)| {
+                code[0] == 0x01
+                    && (1..=100).contains(&code[1])
+                    && is_species(&code[2])
+                    && code[3] == 0xCD
+                    && code[4..] == give_pokemon_pointer
+            })
+            .map(|(offset, _)| StaticPokemonLocation {
+                static_pokemon_type: StaticPokemonType::GIFT,
+                species_offset: offset + 2,
+                level_offset: offset + 1,
+            })
+            .collect();
+
+        // ld a, [<species>]
+        // ld b, a
+        // ld c, <level>
+        // call GivePokemon
+        let loaded_gifts: Vec<(usize, [u8; 2])> = self
+            .rom
+            .windows(0x09)
+            .enumerate()
+            .filter(|(_, code)| {
+                code[0] == 0xFA
+                    && code[3] == 0x47
+                    && code[4] == 0x0E
+                    && (1..=100).contains(&code[5])
+                    && code[6] == 0xCD
+                    && code[7..] == give_pokemon_pointer
+            })
+            .map(|(offset, code)| (offset, [code[1], code[2]]))
+            .collect();
+
+        // ld b, <species> / jr .fossilSelected (once per fossil)
+        // ld [wFossilItem], a
+        // ld a, b
+        // ld [wFossilMon], a
+        let fossils = self
+            .rom
+            .windows(0x11)
+            .enumerate()
+            .filter(|(_, code)| {
+                code[0] == 0x06
+                    && is_species(&code[1])
+                    && code[2..4] == [0x18, 0x06]
+                    && code[4] == 0x06
+                    && is_species(&code[5])
+                    && code[6..8] == [0x18, 0x02]
+                    && code[8] == 0x06
+                    && is_species(&code[9])
+                    && code[10] == 0xEA
+                    && code[13] == 0x78
+                    && code[14] == 0xEA
+            })
+            .flat_map(|(offset, code)| {
+                let fossil_mon_pointer = [code[15], code[16]];
+
+                loaded_gifts
+                    .iter()
+                    .filter(move |(_, pointer)| *pointer == fossil_mon_pointer)
+                    .flat_map(move |(gift_offset, _)| {
+                        [0x01, 0x05, 0x09]
+                            .iter()
+                            .map(move |i| StaticPokemonLocation {
+                                static_pokemon_type: StaticPokemonType::GIFT,
+                                species_offset: offset + i,
+                                level_offset: gift_offset + 5,
+                            })
+                    })
+            })
+            .collect::<Vec<StaticPokemonLocation>>();
+
+        // ld a, <species>
+        // call DisplayPokedex
+        // ld hl, <text>
+        // call PrintText
+        // call YesNoChoice
+        // ld a, [wCurrentMenuItem]
+        // and a
+        // jr nz, .done
+        let displayed_gifts = loaded_gifts
+            .iter()
+            .filter(|(offset, pointer)| {
+                let offset = *offset;
+
+                *pointer == self.offsets.cf91_pointer.to_le_bytes()
+                    && offset >= 0x14
+                    && self.rom[offset - 0x14] == 0x3E
+                    && is_species(&self.rom[offset - 0x13])
+                    && self.rom[offset - 0x12] == 0xCD
+                    && self.rom[offset - 0x0F] == 0x21
+                    && self.rom[offset - 0x0C] == 0xCD
+                    && self.rom[offset - 0x09] == 0xCD
+                    && self.rom[offset - 0x06] == 0xFA
+                    && self.rom[offset - 0x03] == 0xA7
+                    && self.rom[offset - 0x02] == 0x20
+            })
+            .map(|(offset, _)| StaticPokemonLocation {
+                static_pokemon_type: StaticPokemonType::GIFT,
+                species_offset: offset - 0x13,
+                level_offset: offset + 5,
+            })
+            .collect::<Vec<StaticPokemonLocation>>();
+
+        locations.extend(fossils);
+        locations.extend(displayed_gifts);
+        locations.sort_by_key(|location| location.species_offset);
+
+        locations
+    }
+}
+
+pub(crate) struct StaticPokemonLocation {
+    pub(crate) static_pokemon_type: StaticPokemonType,
+    pub(crate) species_offset: usize,
+    pub(crate) level_offset: usize,
+}
+
+/// Static Pokémon (static encounter or gift Pokémon)
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::*;
+///
+/// let static_pokemon = StaticPokemon {
+///     pokedex_id: 143,
+///     level: 30,
+///     static_pokemon_type: StaticPokemonType::ENCOUNTER(23),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StaticPokemon {
+    pub pokedex_id: u8,
+    pub level: u8,
+    pub static_pokemon_type: StaticPokemonType,
+}
+
+/// Static Pokémon type
+///
+/// Static encounters are Pokémon objects on the map with the given map ID.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum StaticPokemonType {
+    ENCOUNTER(u8),
+    GIFT,
+}
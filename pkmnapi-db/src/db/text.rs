@@ -0,0 +1,104 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::text::*;
+use crate::PkmnapiDB;
+
+impl PkmnapiDB {
+    /// Get text by bank ID and pointer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let text = db.get_text(&0x2B, &0x6E81).unwrap();
+    ///
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from_markup("A strange seed was\nplanted on its\nback at birth.¶The plant sprouts\nand grows with\nthis #MON<DEXEND>").unwrap()
+    /// );
+    /// ```
+    pub fn get_text(&self, bank_id: &u8, pointer: &u16) -> Result<Text> {
+        let offset = self.get_text_offset(bank_id, pointer)?;
+
+        Text::new(&self.rom[offset..])
+    }
+
+    /// Set text by bank ID and pointer
+    ///
+    /// Text is written in place, so it cannot be longer than the current
+    /// text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::text::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let patch = db
+    ///     .set_text(&0x2B, &0x6E81, &Text::from_markup("ABCDE<DEXEND>").unwrap())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     patch,
+    ///     Patch {
+    ///         offset: 0xAEE81,
+    ///         length: 0x07,
+    ///         data: vec![0x00, 0x80, 0x81, 0x82, 0x83, 0x84, 0x5F]
+    ///     }
+    /// );
+    /// ```
+    pub fn set_text(&self, bank_id: &u8, pointer: &u16, text: &Text) -> Result<Patch> {
+        let offset = self.get_text_offset(bank_id, pointer)?;
+
+        let old_text_data_len = self.get_text(bank_id, pointer)?.to_raw().len();
+        let text_data = text.to_raw();
+        let text_data_len = text_data.len();
+
+        if text_data_len > old_text_data_len {
+            return Err(error::Error::TextTooLarge(old_text_data_len, text_data_len));
+        }
+
+        Ok(Patch::new(&offset, &text_data))
+    }
+
+    /// Converts a bank ID and pointer to a ROM offset
+    ///
+    /// Bank 0 is always mapped to 0x0000-0x3FFF and every other bank to
+    /// 0x4000-0x7FFF.
+    fn get_text_offset(&self, bank_id: &u8, pointer: &u16) -> Result<usize> {
+        let bank_count = self.rom.len() / PkmnapiDB::ROM_PAGE;
+        let pointer_range = match bank_id {
+            0x00 => 0x0000..PkmnapiDB::ROM_PAGE,
+            _ => PkmnapiDB::ROM_PAGE..(PkmnapiDB::ROM_PAGE * 2),
+        };
+
+        if *bank_id as usize >= bank_count || !pointer_range.contains(&(*pointer as usize)) {
+            return Err(error::Error::TextPointerInvalid(*bank_id, *pointer));
+        }
+
+        let offset = match bank_id {
+            0x00 => *pointer as usize,
+            _ => {
+                (PkmnapiDB::ROM_PAGE * (*bank_id as usize)) - PkmnapiDB::ROM_PAGE
+                    + (*pointer as usize)
+            }
+        };
+
+        Ok(offset)
+    }
+}
@@ -0,0 +1,353 @@
+use crate::error::{self, Result};
+use crate::img::*;
+use crate::patch::*;
+use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl PkmnapiDB {
+    pub fn get_tileset_all(&self, tileset_ids: &Vec<u8>) -> Result<HashMap<u8, Tileset>> {
+        self.get_all(tileset_ids, |id| self.get_tileset(id))
+    }
+
+    /// Get tileset by tileset ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let tileset = db.get_tileset(&0).unwrap();
+    ///
+    /// assert_eq!(tileset.blocks.len(), 0x80);
+    /// assert_eq!(tileset.blocks[0].len(), 0x10);
+    /// assert_eq!(tileset.counter_tiles, vec![]);
+    /// assert_eq!(tileset.grass_tile, Some(0x52));
+    /// ```
+    pub fn get_tileset(&self, tileset_id: &u8) -> Result<Tileset> {
+        let offsets = self.get_tileset_offsets(tileset_id)?;
+        let header = &self.rom[offsets.header..(offsets.header + 0x0C)];
+
+        let blocks = self.rom[offsets.blocks..(offsets.blocks + (offsets.block_count * 0x10))]
+            .chunks(0x10)
+            .map(|block| block.to_vec())
+            .collect();
+
+        let collision_tiles = self.rom[offsets.collision..]
+            .iter()
+            .take_while(|tile_id| **tile_id != 0xFF)
+            .copied()
+            .collect();
+
+        let counter_tiles = header[7..10]
+            .iter()
+            .filter(|tile_id| **tile_id != 0xFF)
+            .copied()
+            .collect();
+
+        let grass_tile = match header[10] {
+            0xFF => None,
+            grass_tile => Some(grass_tile),
+        };
+
+        Ok(Tileset {
+            blocks,
+            collision_tiles,
+            counter_tiles,
+            grass_tile,
+        })
+    }
+
+    /// Set tileset by tileset ID
+    ///
+    /// The number of blocks must match the tileset's blockset. Collision
+    /// tiles that no longer fit, or that other tilesets also use, are moved
+    /// to free space in the home bank.
+    ///
+    /// Blocks that other tilesets also use cannot be changed, as their size
+    /// is only known from the data that follows them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let mut db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let mut tileset = db.get_tileset(&0).unwrap();
+    ///
+    /// tileset.blocks[0] = vec![0x01; 0x10];
+    /// tileset.counter_tiles = vec![0x20];
+    /// tileset.grass_tile = None;
+    ///
+    /// let patch_set = db.set_tileset(&0, &tileset).unwrap();
+    ///
+    /// db.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(db.get_tileset(&0).unwrap(), tileset);
+    /// ```
+    pub fn set_tileset(&self, tileset_id: &u8, tileset: &Tileset) -> Result<PatchSet> {
+        let offsets = self.get_tileset_offsets(tileset_id)?;
+        let old_tileset = self.get_tileset(tileset_id)?;
+
+        if tileset.blocks.len() != offsets.block_count
+            || tileset.blocks.iter().any(|block| block.len() != 0x10)
+        {
+            return Err(error::Error::TilesetBlocksWrongSize(
+                offsets.block_count,
+                tileset.blocks.len(),
+            ));
+        }
+
+        if tileset.counter_tiles.len() > 3 {
+            return Err(error::Error::TilesetCounterTilesWrongSize(
+                3,
+                tileset.counter_tiles.len(),
+            ));
+        }
+
+        if tileset.blocks != old_tileset.blocks {
+            if let Some(shared_tileset_id) = self.get_tileset_shared_ids(tileset_id, 1).first() {
+                return Err(error::Error::TilesetBlocksShared(
+                    *tileset_id,
+                    *shared_tileset_id,
+                ));
+            }
+        }
+
+        let blocks_data: Vec<u8> = tileset.blocks.iter().flatten().copied().collect();
+
+        let counter_tiles_data: Vec<u8> = (0..3)
+            .map(|i| *tileset.counter_tiles.get(i).unwrap_or(&0xFF))
+            .collect();
+        let grass_tile_data = vec![tileset.grass_tile.unwrap_or(0xFF)];
+
+        let mut patch_set = PatchSet::from(vec![
+            Patch::new(&offsets.blocks, &blocks_data),
+            Patch::new(
+                &(offsets.header + 7),
+                &[counter_tiles_data, grass_tile_data].concat(),
+            ),
+        ]);
+
+        let collision_data = [tileset.collision_tiles.to_vec(), vec![0xFF]].concat();
+
+        if tileset.collision_tiles == old_tileset.collision_tiles {
+            return Ok(patch_set);
+        }
+
+        if collision_data.len() <= old_tileset.collision_tiles.len() + 1
+            && self.get_tileset_shared_ids(tileset_id, 5).is_empty()
+        {
+            patch_set.push(Patch::new(&offsets.collision, &collision_data));
+
+            return Ok(patch_set);
+        }
+
+        let new_collision_offset = self.get_free_space(&0x00, &collision_data.len())?;
+
+        patch_set.push(Patch::new(&new_collision_offset, &collision_data));
+        patch_set.push(Patch::new(
+            &(offsets.header + 5),
+            &PkmnapiDB::offset_to_pointer(new_collision_offset),
+        ));
+
+        Ok(patch_set)
+    }
+
+    /// Get tileset graphics by tileset ID
+    ///
+    /// Tiles are laid out 16 to a row. Rows that are not full are padded with
+    /// empty tiles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let img = db.get_tileset_img(&0).unwrap();
+    ///
+    /// assert_eq!((img.width, img.height), (16, 6));
+    /// ```
+    pub fn get_tileset_img(&self, tileset_id: &u8) -> Result<Img> {
+        let offsets = self.get_tileset_offsets(tileset_id)?;
+
+        let height = (offsets.tile_count + 0x0F) / 0x10;
+
+        let mut tiles = self.get_tiles(offsets.gfx, offsets.tile_count, true);
+
+        tiles.resize(height * 0x10, vec![0x00; 0x40]);
+
+        let img = Img::new(&16, &(height as u32), &tiles)?;
+
+        Ok(img)
+    }
+
+    /// Set tileset graphics by tileset ID
+    ///
+    /// The image must have the same dimensions as the one returned by
+    /// `get_tileset_img`. Padding tiles are not written. Graphics that other
+    /// tilesets also use cannot be changed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let img = db.get_tileset_img(&0).unwrap();
+    /// let patch = db.set_tileset_img(&0, &img).unwrap();
+    ///
+    /// assert_eq!(patch.length, 0x600);
+    /// ```
+    pub fn set_tileset_img(&self, tileset_id: &u8, img: &Img) -> Result<Patch> {
+        let offsets = self.get_tileset_offsets(tileset_id)?;
+
+        let old_img_data_len = self.get_tileset_img(tileset_id)?.to_2bpp()?.len();
+        let img_data = img.to_2bpp()?;
+        let img_data_len = img_data.len();
+
+        if img.width != 16 || old_img_data_len != img_data_len {
+            return Err(error::Error::TilesetImgWrongSize(
+                old_img_data_len,
+                img_data_len,
+            ));
+        }
+
+        let img_data = img_data[..(offsets.tile_count * 0x10)].to_vec();
+
+        if img_data[..] != self.rom[offsets.gfx..(offsets.gfx + img_data.len())] {
+            if let Some(shared_tileset_id) = self.get_tileset_shared_ids(tileset_id, 3).first() {
+                return Err(error::Error::TilesetImgShared(
+                    *tileset_id,
+                    *shared_tileset_id,
+                ));
+            }
+        }
+
+        Ok(Patch::new(&offsets.gfx, &img_data))
+    }
+
+    /// Returns the offsets of a tileset's header, graphics, blocks and
+    /// collision tiles
+    ///
+    /// Tileset graphics and blocks are not sized in the header, so each is
+    /// assumed to end where the next tileset data in the same bank begins.
+    pub(crate) fn get_tileset_offsets(&self, tileset_id: &u8) -> Result<TilesetOffsets> {
+        let (min_id, max_id) = self.tileset_id_validate(tileset_id)?;
+
+        let header_offset = |tileset_id: usize| self.offsets.tileset_headers + (tileset_id * 0x0C);
+
+        let header = header_offset(*tileset_id as usize);
+        let bank_id = self.rom[header];
+        let bank = ((bank_id as usize) - 0x01) * PkmnapiDB::ROM_PAGE;
+
+        let bank_pointers: Vec<usize> = (min_id..=max_id)
+            .map(|tileset_id| header_offset(tileset_id))
+            .filter(|header| self.rom[*header] == bank_id)
+            .map(|header| vec![self.get_pointer(header + 1), self.get_pointer(header + 3)])
+            .flatten()
+            .collect();
+
+        let next_pointer = |pointer: usize| {
+            bank_pointers
+                .iter()
+                .filter(|next_pointer| **next_pointer > pointer)
+                .min()
+                .copied()
+        };
+
+        let blocks_pointer = self.get_pointer(header + 1);
+        let gfx_pointer = self.get_pointer(header + 3);
+        let collision_pointer = self.get_pointer(header + 5);
+
+        let block_count = match next_pointer(blocks_pointer) {
+            Some(next_pointer) => ((next_pointer - blocks_pointer) / 0x10).min(0x80),
+            None => 0x80,
+        };
+
+        let tile_count = match next_pointer(gfx_pointer) {
+            Some(next_pointer) => ((next_pointer - gfx_pointer) / 0x10).min(0x60),
+            None => 0x60,
+        };
+
+        Ok(TilesetOffsets {
+            header,
+            gfx: bank + gfx_pointer,
+            tile_count,
+            blocks: bank + blocks_pointer,
+            block_count,
+            collision: collision_pointer,
+        })
+    }
+
+    /// Returns the IDs of the other tilesets whose header has the same
+    /// pointer at `pointer_offset` (1 for blocks, 3 for graphics and 5 for
+    /// collision tiles)
+    ///
+    /// Blocks and graphics are only shared within the same bank. Collision
+    /// tiles are all in the home bank.
+    fn get_tileset_shared_ids(&self, tileset_id: &u8, pointer_offset: usize) -> Vec<u8> {
+        let (min_id, max_id) = self.tileset_id_bounds();
+
+        let header_offset = |tileset_id: usize| self.offsets.tileset_headers + (tileset_id * 0x0C);
+
+        let header = header_offset(*tileset_id as usize);
+        let pointer = self.get_pointer(header + pointer_offset);
+
+        (min_id..=max_id)
+            .filter(|other_tileset_id| *other_tileset_id != *tileset_id as usize)
+            .filter(|other_tileset_id| {
+                let other_header = header_offset(*other_tileset_id);
+
+                self.get_pointer(other_header + pointer_offset) == pointer
+                    && (pointer_offset == 5 || self.rom[other_header] == self.rom[header])
+            })
+            .map(|other_tileset_id| other_tileset_id as u8)
+            .collect()
+    }
+}
+
+/// Tileset
+///
+/// Each block is 16 tile IDs, a 4x4 grid in row-major order. Counter tiles
+/// are the tiles the player can talk across.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Tileset {
+    pub blocks: Vec<Vec<u8>>,
+    pub collision_tiles: Vec<u8>,
+    pub counter_tiles: Vec<u8>,
+    pub grass_tile: Option<u8>,
+}
+
+/// Offsets of a tileset's data
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TilesetOffsets {
+    pub header: usize,
+    pub gfx: usize,
+    pub tile_count: usize,
+    pub blocks: usize,
+    pub block_count: usize,
+    pub collision: usize,
+}
@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -28,8 +29,9 @@ impl PkmnapiDB {
     pub fn get_tm_move(&self, tm_id: &u8) -> Result<TMMove> {
         let _max_id = self.tm_id_validate(tm_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x04;
-        let offset = (offset_base + 0x3773) + ((*tm_id as usize) - 1);
+        let (bank_id, offset) = self.offsets.tm_moves;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((*tm_id as usize) - 1);
 
         let tm_move = TMMove::from(self.rom[offset]);
 
@@ -64,8 +66,9 @@ impl PkmnapiDB {
     pub fn set_tm_move(&self, tm_id: &u8, tm: &TMMove) -> Result<Patch> {
         let _max_id = self.tm_id_validate(tm_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x04;
-        let offset = (offset_base + 0x3773) + ((*tm_id as usize) - 1);
+        let (bank_id, offset) = self.offsets.tm_moves;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((*tm_id as usize) - 1);
 
         Ok(Patch::new(&offset, &tm.to_raw()))
     }
@@ -82,7 +85,7 @@ impl PkmnapiDB {
 ///
 /// assert_eq!(tm_move, TMMove { move_id: 0x01 });
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct TMMove {
     pub move_id: u8,
 }
@@ -29,7 +29,7 @@ impl PkmnapiDB {
     pub fn get_tm_name(&self, tm_id: &u8) -> Result<TMName> {
         let _max_id = self.tm_id_validate(tm_id)?;
 
-        let offset = 0x303C;
+        let offset = self.offsets.tm_name;
         let tm_prefix = ROMString::new(&self.rom[offset..(offset + 2)]);
 
         let tm_name = TMName {
@@ -1,6 +1,7 @@
 use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -28,8 +29,9 @@ impl PkmnapiDB {
     pub fn get_tm_price(&self, tm_id: &u8) -> Result<TMPrice> {
         let _max_id = self.tm_id_validate(tm_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1E;
-        let offset = (offset_base + 0x3FA7) + (((*tm_id as usize - 1) as f32 / 2.0) as usize);
+        let (bank_id, offset) = self.offsets.tm_prices;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + (((*tm_id as usize - 1) as f32 / 2.0) as usize);
         let value = {
             if ((tm_id - 1) % 2) == 0 {
                 (self.rom[offset] & 0xF0) >> 4
@@ -71,8 +73,9 @@ impl PkmnapiDB {
     pub fn set_tm_price(&self, tm_id: &u8, tm_price: &TMPrice) -> Result<Patch> {
         let _max_id = self.tm_id_validate(tm_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1E;
-        let offset = (offset_base + 0x3FA7) + ((((*tm_id as usize) - 1) as f32 / 2.0) as usize);
+        let (bank_id, offset) = self.offsets.tm_prices;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((((*tm_id as usize) - 1) as f32 / 2.0) as usize);
         let value = {
             if ((tm_id - 1) % 2) == 0 {
                 (self.rom[offset] & 0x0F) | (tm_price.to_raw()[0] << 0x04)
@@ -96,7 +99,7 @@ impl PkmnapiDB {
 ///
 /// assert_eq!(tm, TMPrice { value: 1000 });
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct TMPrice {
     pub value: u32,
 }
@@ -1,16 +1,13 @@
-use crate::error::Result;
+use crate::error::{self, Result};
 use crate::img::*;
+use crate::patch::*;
 use crate::PkmnapiDB;
 
 impl PkmnapiDB {
     pub fn get_town_map_img(&self) -> Result<Img> {
-        let graphics_offset_base = PkmnapiDB::ROM_PAGE * 0x04;
-        let graphics_offset = graphics_offset_base + 0x25A8;
+        let graphics_tiles = self.get_town_map_graphics_tiles();
 
-        let graphics_tiles = self.get_tiles(graphics_offset, 4 * 4, false);
-
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = offset_base + 0x1100;
+        let offset = self.get_town_map_offset();
 
         let tiles: Vec<Vec<u8>> = self.rom[offset..]
             .iter()
@@ -30,4 +27,147 @@ impl PkmnapiDB {
 
         Ok(town_map)
     }
+
+    /// Set town map
+    ///
+    /// The town map is a run-length encoded layout of 16 tiles. Every tile
+    /// of the img must match one of those tiles. Layouts that no longer fit
+    /// are moved to free space in the same bank and `LoadTownMap` is pointed
+    /// at them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let town_map = db.get_town_map_img().unwrap();
+    ///
+    /// let patch_set = db.set_town_map_img(&town_map).unwrap();
+    ///
+    /// assert_eq!(patch_set.patches.len(), 1);
+    ///
+    /// let patch = &patch_set.patches[0];
+    ///
+    /// assert_eq!(patch.offset, 0x71100);
+    /// assert_eq!(patch.data[patch.length - 1], 0x00);
+    /// ```
+    pub fn set_town_map_img(&self, town_map: &Img) -> Result<PatchSet> {
+        if town_map.width != 20 || town_map.height != 18 {
+            return Err(error::Error::TownMapImgWrongSize(
+                town_map.width,
+                town_map.height,
+            ));
+        }
+
+        let graphics_tiles = self.get_town_map_graphics_tiles();
+
+        let tile_ids = (0..town_map.height)
+            .map(|tile_y| (0..town_map.width).map(move |tile_x| (tile_x, tile_y)))
+            .flatten()
+            .map(|(tile_x, tile_y)| {
+                let tile: Vec<u8> = (0..8)
+                    .map(|pixel_y| {
+                        let row_index = ((((tile_y * 8) + pixel_y) * town_map.width * 8)
+                            + (tile_x * 8)) as usize;
+
+                        town_map.pixels[row_index..(row_index + 8)].to_vec()
+                    })
+                    .flatten()
+                    .collect();
+
+                match graphics_tiles
+                    .iter()
+                    .position(|graphics_tile| graphics_tile == &tile)
+                {
+                    Some(tile_id) => Ok(tile_id as u8),
+                    None => Err(error::Error::TownMapTileNotFound(tile_x, tile_y)),
+                }
+            })
+            .collect::<Result<Vec<u8>>>()?;
+
+        let mut town_map_data: Vec<u8> = vec![];
+
+        for tile_id in tile_ids {
+            match town_map_data.last_mut() {
+                Some(byte) if (*byte >> 0x04) == tile_id && (*byte & 0x0F) < 0x0F => *byte += 1,
+                _ => town_map_data.push((tile_id << 0x04) | 0x01),
+            }
+        }
+
+        town_map_data.push(0x00);
+
+        let offset = self.get_town_map_offset();
+        let old_town_map_data_len = self.rom[offset..]
+            .iter()
+            .position(|&x| x == 0x00)
+            .unwrap_or(0)
+            + 1;
+        let town_map_data_len = town_map_data.len();
+
+        if town_map_data_len <= old_town_map_data_len {
+            return Ok(PatchSet::from(Patch::new(&offset, &town_map_data)));
+        }
+
+        let pointer_offset = match self.get_town_map_pointer_offset() {
+            Some(pointer_offset) => pointer_offset,
+            None => {
+                return Err(error::Error::TownMapTooLarge(
+                    old_town_map_data_len,
+                    town_map_data_len,
+                ))
+            }
+        };
+
+        let (bank_id, _) = self.offsets.town_map;
+        let new_offset = self.get_free_space(&bank_id, &town_map_data_len)?;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&new_offset, &town_map_data),
+            Patch::new(&pointer_offset, &PkmnapiDB::offset_to_pointer(new_offset)),
+        ]))
+    }
+
+    fn get_town_map_graphics_tiles(&self) -> Vec<Vec<u8>> {
+        let (bank_id, offset) = self.offsets.town_map_tiles;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
+
+        self.get_tiles(offset, 4 * 4, false)
+    }
+
+    fn get_town_map_offset(&self) -> usize {
+        let (bank_id, offset) = self.offsets.town_map;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+
+        match self.get_town_map_pointer_offset() {
+            Some(pointer_offset) => {
+                offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(pointer_offset)
+            }
+            None => offset_base + offset,
+        }
+    }
+
+    /// Returns the offset of the town map pointer in `LoadTownMap`
+    ///
+    /// The town map is read with `hlcoord 0, 0`, `ld de, <town map>`,
+    /// `ld a, [de]`, `and a` and `jr z`.
+    fn get_town_map_pointer_offset(&self) -> Option<usize> {
+        let (bank_id, _) = self.offsets.town_map;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+
+        self.rom[offset_base..(offset_base + PkmnapiDB::ROM_PAGE)]
+            .windows(0x09)
+            .position(|code| {
+                code[0..3] == [0x21, 0xA0, 0xC3]
+                    && code[3] == 0x11
+                    && code[6..] == [0x1A, 0xA7, 0x28]
+            })
+            .map(|position| offset_base + position + 4)
+    }
 }
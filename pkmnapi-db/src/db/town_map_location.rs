@@ -0,0 +1,282 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::string::*;
+use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl PkmnapiDB {
+    pub fn get_town_map_location_all(
+        &self,
+        town_map_location_ids: &Vec<u8>,
+    ) -> Result<HashMap<u8, TownMapLocation>> {
+        self.get_all(town_map_location_ids, |id| self.get_town_map_location(id))
+    }
+
+    /// Get town map location by town map location ID
+    ///
+    /// The first 37 locations belong to the outdoor maps with the same map
+    /// ID. Every other location covers a range of indoor maps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let town_map_location = db.get_town_map_location(&0).unwrap();
+    ///
+    /// assert_eq!(
+    ///     town_map_location,
+    ///     TownMapLocation {
+    ///         name: ROMString::from("PALLET TOWN"),
+    ///         x: 2,
+    ///         y: 11,
+    ///         map_ids: vec![0]
+    ///     }
+    /// );
+    /// ```
+    pub fn get_town_map_location(&self, town_map_location_id: &u8) -> Result<TownMapLocation> {
+        let (offset, first_map_id, last_map_id) =
+            self.get_town_map_location_entry(town_map_location_id)?;
+
+        let coordinates = self.rom[offset];
+        let name_offset = self.get_town_map_location_name_offset(offset);
+        let name: Vec<u8> = self.rom[name_offset..]
+            .iter()
+            .take_while(|&x| *x != 0x50)
+            .map(|x| *x)
+            .collect();
+
+        let town_map_location = TownMapLocation {
+            name: ROMString::new(&name),
+            x: coordinates & 0x0F,
+            y: coordinates >> 0x04,
+            map_ids: (first_map_id..=last_map_id).collect(),
+        };
+
+        Ok(town_map_location)
+    }
+
+    /// Set town map location by town map location ID
+    ///
+    /// Names may be shared by several locations, so renaming one location
+    /// renames all of them. Names that no longer fit are moved to free space
+    /// in the same bank and every location sharing the name is pointed at it.
+    ///
+    /// Outdoor locations always cover their own map. Indoor locations cover
+    /// consecutive map IDs starting after the previous location, so changing
+    /// the last map ID also moves the start of the next location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::string::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let patch = db
+    ///     .set_town_map_location(
+    ///         &0,
+    ///         &TownMapLocation {
+    ///             name: ROMString::from("ABC TOWN"),
+    ///             x: 3,
+    ///             y: 12,
+    ///             map_ids: vec![0],
+    ///         },
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(patch.patches.len(), 2);
+    /// assert_eq!(patch.patches[0].data, vec![0xC3]);
+    /// assert_eq!(
+    ///     patch.patches[1].data,
+    ///     vec![0x80, 0x81, 0x82, 0x7F, 0x93, 0x8E, 0x96, 0x8D, 0x50]
+    /// );
+    /// ```
+    pub fn set_town_map_location(
+        &self,
+        town_map_location_id: &u8,
+        town_map_location: &TownMapLocation,
+    ) -> Result<PatchSet> {
+        let (offset, first_map_id, _) = self.get_town_map_location_entry(town_map_location_id)?;
+
+        if town_map_location.x > 0x0F || town_map_location.y > 0x0F {
+            return Err(error::Error::TownMapLocationCoordinatesInvalid(
+                town_map_location.x,
+                town_map_location.y,
+            ));
+        }
+
+        let max_map_id = self.get_town_map_location_max_map_id(town_map_location_id);
+        let map_ids_valid = match town_map_location.map_ids.last() {
+            Some(last_map_id) => {
+                *last_map_id <= max_map_id
+                    && town_map_location.map_ids
+                        == (first_map_id..=*last_map_id).collect::<Vec<u8>>()
+            }
+            None => false,
+        };
+
+        if !map_ids_valid {
+            return Err(error::Error::TownMapLocationMapIDsInvalid(
+                *town_map_location_id,
+                first_map_id,
+                max_map_id,
+            ));
+        }
+
+        let name_offset = self.get_town_map_location_name_offset(offset);
+        let old_name_len = self.rom[name_offset..]
+            .iter()
+            .position(|&x| x == 0x50)
+            .unwrap_or(0);
+        let name = town_map_location.name.value.to_vec();
+        let name_len = name.len();
+
+        let mut patches = vec![Patch::new(
+            &offset,
+            &vec![(town_map_location.y << 0x04) | town_map_location.x],
+        )];
+
+        if *town_map_location_id as usize >= TOWN_MAP_EXTERNAL_COUNT {
+            let last_map_id = town_map_location.map_ids[town_map_location.map_ids.len() - 1];
+
+            patches.push(Patch::new(&(offset - 1), &vec![last_map_id + 1]));
+        }
+
+        let name_data = [name, vec![0x50]].concat();
+
+        if name_len <= old_name_len {
+            patches.push(Patch::new(&name_offset, &name_data));
+
+            return Ok(PatchSet::from(patches));
+        }
+
+        let (bank_id, _) = self.offsets.town_map_external_locations;
+        let new_name_offset = self.get_free_space(&bank_id, &name_data.len())?;
+        let new_name_pointer = PkmnapiDB::offset_to_pointer(new_name_offset);
+        let (min_id, max_id) = self.town_map_location_id_bounds();
+
+        patches.push(Patch::new(&new_name_offset, &name_data));
+
+        for other_town_map_location_id in min_id..=max_id {
+            let (other_offset, _, _) =
+                self.get_town_map_location_entry(&(other_town_map_location_id as u8))?;
+
+            if self.get_town_map_location_name_offset(other_offset) == name_offset {
+                patches.push(Patch::new(&(other_offset + 1), &new_name_pointer));
+            }
+        }
+
+        Ok(PatchSet::from(patches))
+    }
+
+    /// Returns the offset of the coordinates of a location along with the
+    /// first and last map IDs it covers
+    fn get_town_map_location_entry(&self, town_map_location_id: &u8) -> Result<(usize, u8, u8)> {
+        self.town_map_location_id_validate(town_map_location_id)?;
+
+        let town_map_location_id = *town_map_location_id as usize;
+
+        if town_map_location_id < TOWN_MAP_EXTERNAL_COUNT {
+            let offset = self.get_town_map_external_offset() + (town_map_location_id * 0x03);
+
+            return Ok((
+                offset,
+                town_map_location_id as u8,
+                town_map_location_id as u8,
+            ));
+        }
+
+        let index = town_map_location_id - TOWN_MAP_EXTERNAL_COUNT;
+        let offset = self.get_town_map_internal_offset() + (index * 0x04);
+        let first_map_id = if index == 0 {
+            TOWN_MAP_EXTERNAL_COUNT as u8
+        } else {
+            self.rom[offset - 0x04]
+        };
+        let last_map_id = self.rom[offset] - 1;
+
+        Ok((offset + 1, first_map_id, last_map_id))
+    }
+
+    /// Returns the highest map ID a location may cover without leaving the
+    /// next location empty
+    fn get_town_map_location_max_map_id(&self, town_map_location_id: &u8) -> u8 {
+        let town_map_location_id = *town_map_location_id as usize;
+
+        if town_map_location_id < TOWN_MAP_EXTERNAL_COUNT {
+            return town_map_location_id as u8;
+        }
+
+        let index = town_map_location_id - TOWN_MAP_EXTERNAL_COUNT;
+        let next_offset = self.get_town_map_internal_offset() + ((index + 1) * 0x04);
+
+        match self.rom[next_offset] {
+            0xFF => 0xFD,
+            next_bound => next_bound - 2,
+        }
+    }
+
+    fn get_town_map_location_name_offset(&self, offset: usize) -> usize {
+        let (bank_id, _) = self.offsets.town_map_external_locations;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+
+        offset_base - PkmnapiDB::ROM_PAGE + self.get_pointer(offset + 1)
+    }
+
+    pub(crate) fn get_town_map_external_offset(&self) -> usize {
+        let (bank_id, offset) = self.offsets.town_map_external_locations;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+
+        offset_base + offset
+    }
+
+    pub(crate) fn get_town_map_internal_offset(&self) -> usize {
+        let (bank_id, offset) = self.offsets.town_map_internal_locations;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+
+        offset_base + offset
+    }
+}
+
+/// Number of outdoor maps, each with its own town map location
+pub(crate) const TOWN_MAP_EXTERNAL_COUNT: usize = 0x25;
+
+/// Town map location
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::string::*;
+/// use pkmnapi_db::*;
+///
+/// let town_map_location = TownMapLocation {
+///     name: ROMString::from("PALLET TOWN"),
+///     x: 2,
+///     y: 11,
+///     map_ids: vec![0],
+/// };
+///
+/// assert_eq!(town_map_location.name.to_string(), "PALLET TOWN");
+/// ```
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct TownMapLocation {
+    pub name: ROMString,
+    pub x: u8,
+    pub y: u8,
+    pub map_ids: Vec<u8>,
+}
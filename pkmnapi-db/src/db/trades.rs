@@ -2,6 +2,7 @@ use crate::error::Result;
 use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -27,14 +28,15 @@ impl PkmnapiDB {
     ///
     /// assert_eq!(
     ///     trade,
-    ///     Trade::new(33, 30, "TERRY")
+    ///     Trade::new(33, 30, ROMString::from("TERRY"))
     /// );
     /// ```
     pub fn get_trade(&self, trade_id: &u8) -> Result<Trade> {
         self.trade_id_validate(trade_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = (offset_base + 0x1B7B) + ((*trade_id * 0x0E) as usize);
+        let (bank_id, offset) = self.offsets.trades;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((*trade_id * 0x0E) as usize);
 
         let trade = Trade::from(&self.rom[offset..]);
 
@@ -70,7 +72,7 @@ impl PkmnapiDB {
     /// let patch = db
     ///     .set_trade(
     ///         &0,
-    ///         &Trade::new(4, 6, "CHARCHAR")
+    ///         &Trade::new(4, 6, ROMString::from("CHARCHAR"))
     ///     )
     ///     .unwrap();
     ///
@@ -86,8 +88,9 @@ impl PkmnapiDB {
     pub fn set_trade(&self, trade_id: &u8, trade: &Trade) -> Result<Patch> {
         self.trade_id_validate(trade_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = (offset_base + 0x1B7B) + ((*trade_id * 0x0E) as usize);
+        let (bank_id, offset) = self.offsets.trades;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + ((*trade_id * 0x0E) as usize);
 
         let give_internal_id = self.pokedex_id_to_internal_id(&trade.give_pokedex_id)?;
         let get_internal_id = self.pokedex_id_to_internal_id(&trade.get_pokedex_id)?;
@@ -121,11 +124,13 @@ impl PkmnapiDB {
 ///
 /// assert_eq!(trade.nickname, ROMString::from("CHARCHAR"));
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct Trade {
     pub give_pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) give_internal_id: u8,
     pub get_pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) get_internal_id: u8,
     pub nickname: ROMString,
 }
@@ -167,13 +172,13 @@ impl From<&[u8]> for Trade {
 }
 
 impl Trade {
-    pub fn new<S: Into<String>>(give_pokedex_id: u8, get_pokedex_id: u8, nickname: S) -> Self {
+    pub fn new(give_pokedex_id: u8, get_pokedex_id: u8, nickname: ROMString) -> Self {
         Trade {
             give_pokedex_id,
             give_internal_id: 0,
             get_pokedex_id,
             get_internal_id: 0,
-            nickname: ROMString::from(nickname),
+            nickname,
         }
     }
 
@@ -182,9 +187,10 @@ impl Trade {
     /// # Example
     ///
     /// ```
+    /// use pkmnapi_db::string::*;
     /// use pkmnapi_db::*;
     ///
-    /// let trade = Trade::new(4, 6, "CHARCHAR");
+    /// let trade = Trade::new(4, 6, ROMString::from("CHARCHAR"));
     ///
     /// let raw = trade.to_raw();
     ///
@@ -2,6 +2,7 @@ use crate::error::{self, Result};
 use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -33,8 +34,9 @@ impl PkmnapiDB {
     /// );
     /// ```
     pub fn get_trainer_name(&self, trainer_id: &u8) -> Result<TrainerName> {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset_base = offset_base + 0x19FF;
+        let (bank_id, offset) = self.offsets.trainer_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_base = offset_base + offset;
 
         let (min_id, max_id) = self.trainer_id_validate(trainer_id)?;
         let offset = self.rom[offset_base..]
@@ -113,8 +115,9 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset_base = offset_base + 0x19FF;
+        let (bank_id, offset) = self.offsets.trainer_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_base = offset_base + offset;
         let offset = self.rom[offset_base..]
             .iter()
             .enumerate()
@@ -160,7 +163,8 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct TrainerName {
     pub name: ROMString,
 }
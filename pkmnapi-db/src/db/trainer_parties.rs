@@ -1,6 +1,7 @@
 use crate::error::{self, Result};
 use crate::patch::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -14,84 +15,70 @@ impl PkmnapiDB {
     pub fn get_trainer_parties(&self, trainer_id: &u8) -> Result<Vec<Party>> {
         let (_min_id, max_id) = self.trainer_id_validate(trainer_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = offset_base + 0x1D3B;
+        let trainer_party_pointers = self.get_trainer_party_pointers(&max_id);
 
-        let pointer_min_offset = offset + ((*trainer_id as usize) - 1) * 0x02;
-        let pointer_min =
-            (offset_base - PkmnapiDB::ROM_PAGE) + self.get_pointer(pointer_min_offset);
+        let pointer = trainer_party_pointers[(*trainer_id as usize) - 1];
 
-        let pointer_max_offset = offset + (*trainer_id as usize) * 0x02;
-        let pointer_max =
-            (offset_base - PkmnapiDB::ROM_PAGE) + self.get_pointer(pointer_max_offset);
+        if trainer_id != &(max_id as u8) && trainer_party_pointers[*trainer_id as usize] == pointer
+        {
+            return Ok(vec![]);
+        }
+
+        let pointer_max = trainer_party_pointers
+            .iter()
+            .filter(|&trainer_party_pointer| trainer_party_pointer > &pointer)
+            .min()
+            .map(|trainer_party_pointer| *trainer_party_pointer)
+            .unwrap_or((pointer / PkmnapiDB::ROM_PAGE + 1) * PkmnapiDB::ROM_PAGE);
 
-        let data_size = if trainer_id == &(max_id as u8) {
-            self.rom[pointer_min..]
+        let mut trainer_parties = vec![];
+        let mut party_offset = pointer;
+
+        while party_offset < pointer_max && self.rom[party_offset] != 0x00 {
+            let party_size = match self.rom[party_offset..pointer_max]
                 .iter()
-                .position(|r| r == &0x00)
-                .unwrap()
-                + 0x01
-        } else {
-            pointer_max - pointer_min
-        };
+                .position(|&x| x == 0x00)
+            {
+                Some(position) => position + 1,
+                None => break,
+            };
+
+            let mut party = Party::from(&self.rom[party_offset..(party_offset + party_size)]);
 
-        let trainer_party_offsets: Vec<usize> = [
-            vec![0x00],
-            self.rom[pointer_min..(pointer_min + data_size)]
+            party.pokemon = party
+                .pokemon
                 .iter()
-                .enumerate()
-                .filter_map(|(i, x)| {
-                    let offset = i + 1;
-
-                    if offset == data_size {
-                        return None;
-                    }
-
-                    if x == &0x00 {
-                        Some(i + 1)
-                    } else {
-                        None
-                    }
+                .map(|party_pokemon| {
+                    PartyPokemon::new(
+                        party_pokemon.level,
+                        self.internal_id_to_pokedex_id(&party_pokemon.internal_id)
+                            .unwrap(),
+                    )
                 })
-                .collect::<Vec<usize>>(),
-        ]
-        .concat();
+                .collect();
 
-        if data_size == 0x00 {
-            return Ok(vec![]);
-        }
+            trainer_parties.push(party);
 
-        let trainer_parties: Vec<Party> = trainer_party_offsets
-            .iter()
-            .map(|trainer_party_offset| {
-                let mut party = Party::from(
-                    &self.rom[(pointer_min + trainer_party_offset)..(pointer_min + data_size)],
-                );
+            if trainer_id == &(max_id as u8) {
+                break;
+            }
 
-                party.pokemon = party
-                    .pokemon
-                    .iter()
-                    .map(|party_pokemon| {
-                        PartyPokemon::new(
-                            party_pokemon.level,
-                            self.internal_id_to_pokedex_id(&party_pokemon.internal_id)
-                                .unwrap(),
-                        )
-                    })
-                    .collect();
-
-                party
-            })
-            .collect();
+            party_offset += party_size;
+        }
 
         Ok(trainer_parties)
     }
 
+    /// Set trainer parties by trainer ID
+    ///
+    /// Parties that fit into the space of the old parties are written in
+    /// place. Larger parties are moved to free space in the same bank and
+    /// the trainer's party pointer is updated.
     pub fn set_trainer_parties(
         &self,
         trainer_id: &u8,
         trainer_parties: &Vec<Party>,
-    ) -> Result<Patch> {
+    ) -> Result<PatchSet> {
         let old_trainer_parties = self.get_trainer_parties(trainer_id)?;
         let old_trainer_parties_len = old_trainer_parties.len();
         let old_trainer_parties_data: Vec<u8> = old_trainer_parties
@@ -133,24 +120,49 @@ impl PkmnapiDB {
             ));
         }
 
-        if old_trainer_parties_data_len != trainer_parties_data_len {
-            return Err(error::Error::TrainerPartiesWrongDataSize(
-                old_trainer_parties_data_len,
-                trainer_parties_data_len,
-            ));
-        }
-
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = offset_base + 0x1D3B;
+        let (bank_id, offset) = self.offsets.trainer_parties;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let pointer_offset = offset + ((*trainer_id as usize) - 1) * 0x02;
         let pointer = (offset_base - PkmnapiDB::ROM_PAGE) + self.get_pointer(pointer_offset);
 
-        Ok(Patch::new(&pointer, &trainer_parties_data))
+        if trainer_parties_data_len == old_trainer_parties_data_len {
+            return Ok(PatchSet::from(Patch::new(&pointer, &trainer_parties_data)));
+        }
+
+        if trainer_parties_data_len < old_trainer_parties_data_len {
+            let trainer_parties_data = [trainer_parties_data, vec![0x00]].concat();
+
+            return Ok(PatchSet::from(Patch::new(&pointer, &trainer_parties_data)));
+        }
+
+        let new_pointer = self.get_free_space(&0x0E, &(trainer_parties_data_len + 1))?;
+        let trainer_parties_data = [trainer_parties_data, vec![0x00]].concat();
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&pointer, &vec![0x00; old_trainer_parties_data_len]),
+            Patch::new(&new_pointer, &trainer_parties_data),
+            Patch::new(&pointer_offset, &PkmnapiDB::offset_to_pointer(new_pointer)),
+        ]))
+    }
+
+    fn get_trainer_party_pointers(&self, max_id: &usize) -> Vec<usize> {
+        let (bank_id, offset) = self.offsets.trainer_parties;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
+
+        (0..*max_id)
+            .map(|i| {
+                let pointer_offset = offset + (i * 0x02);
+
+                (offset_base - PkmnapiDB::ROM_PAGE) + self.get_pointer(pointer_offset)
+            })
+            .collect()
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct Party {
     pub level_type: PartyLevelType,
     pub pokemon: Vec<PartyPokemon>,
@@ -233,10 +245,11 @@ impl Party {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
 pub struct PartyPokemon {
     pub level: u8,
     pub pokedex_id: u8,
+    #[serde(skip)]
     pub(crate) internal_id: u8,
 }
 
@@ -258,7 +271,7 @@ impl PartyPokemon {
 /// let party_level_type_same = PartyLevelType::SAME(42);
 /// let party_level_type_different = PartyLevelType::DIFFERENT;
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 pub enum PartyLevelType {
     SAME(u8),
     DIFFERENT,
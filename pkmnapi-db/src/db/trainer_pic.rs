@@ -5,41 +5,53 @@ use crate::PkmnapiDB;
 
 impl PkmnapiDB {
     pub fn get_trainer_pic(&self, trainer_id: &u8) -> Result<Pic> {
-        self.trainer_id_validate(trainer_id)?;
-
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset_base = offset_base + 0x1914;
-
-        let offset = offset_base + (((*trainer_id - 1) as usize) * 0x05);
+        let (_, offset, _) = self.get_trainer_pic_offset(trainer_id)?;
 
-        let pointer_base = PkmnapiDB::ROM_PAGE * 0x12;
-        let pointer = pointer_base + self.get_pointer(offset);
-
-        let pic = Pic::new(&self.rom[pointer..])?;
+        let pic = Pic::new(&self.rom[offset..])?;
 
         Ok(pic)
     }
 
+    /// Set trainer pic by trainer ID
+    ///
+    /// Without an encoding method, every method is tried and the smallest
+    /// result is kept. Pics that no longer fit are moved to free space in the
+    /// same bank and the trainer's pic pointer is updated.
+    ///
+    /// Trainer pics have no dimensions byte and are always loaded as 7x7
+    /// tiles, so smaller pics are rejected.
     pub fn set_trainer_pic(
         &self,
         trainer_id: &u8,
         pic: &Pic,
-        encoding_method: PicEncodingMethod,
-    ) -> Result<Patch> {
-        let old_pixels = self.get_trainer_pic(trainer_id)?;
-        let pixels = pic.encode(encoding_method);
-
-        if pixels.len() > old_pixels.bytes + 1 {
-            return Err(error::Error::PicTooLarge);
+        encoding_method: Option<PicEncodingMethod>,
+    ) -> Result<PatchSet> {
+        let (bank_id, offset, pointer_offset) = self.get_trainer_pic_offset(trainer_id)?;
+
+        if pic.width != 7 || pic.height != 7 {
+            return Err(error::Error::PicDimensionsInvalid(
+                7, 7, pic.width, pic.height,
+            ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset_base = offset_base + 0x1914;
-        let offset = offset_base + (((*trainer_id - 1) as usize) * 0x05);
+        self.set_pic(&bank_id, &offset, &pointer_offset, pic, encoding_method)
+    }
+
+    /// Returns the pic's bank ID, the offset of the pic and the offset of its
+    /// pointer
+    fn get_trainer_pic_offset(&self, trainer_id: &u8) -> Result<(usize, usize, usize)> {
+        self.trainer_id_validate(trainer_id)?;
+
+        let (bank_id, offset) = self.offsets.trainer_pics;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_base = offset_base + offset;
+
+        let pointer_offset = offset_base + (((*trainer_id - 1) as usize) * 0x05);
 
-        let pointer_base = PkmnapiDB::ROM_PAGE * 0x12;
-        let pointer = pointer_base + self.get_pointer(offset);
+        let bank_id = self.offsets.trainer_pic_bank;
+        let pointer_base = PkmnapiDB::ROM_PAGE * (bank_id - 1);
+        let offset = pointer_base + self.get_pointer(pointer_offset);
 
-        Ok(Patch::new(&pointer, &pixels))
+        Ok((bank_id, offset, pointer_offset))
     }
 }
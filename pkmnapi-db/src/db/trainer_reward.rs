@@ -32,8 +32,9 @@ impl PkmnapiDB {
     pub fn get_trainer_reward(&self, trainer_id: &u8) -> Result<u32> {
         self.trainer_id_validate(trainer_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = (offset_base + 0x1914) + (((*trainer_id - 1) as usize) * 0x05);
+        let (bank_id, offset) = self.offsets.trainer_pics;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + (((*trainer_id - 1) as usize) * 0x05);
 
         let trainer_reward = vec![
             (self.rom[offset + 2] & 0xF0) >> 0x04,
@@ -86,8 +87,9 @@ impl PkmnapiDB {
     pub fn set_trainer_reward(&self, trainer_id: &u8, trainer_reward: &u32) -> Result<Patch> {
         self.trainer_id_validate(trainer_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
-        let offset = ((offset_base + 0x1914) + (((*trainer_id - 1) as usize) * 0x05)) + 0x02;
+        let (bank_id, offset) = self.offsets.trainer_pics;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = ((offset_base + offset) + (((*trainer_id - 1) as usize) * 0x05)) + 0x02;
 
         let data = (0..=5)
             .map(|i| ((*trainer_reward / 10u32.pow(5 - i)) % 10) as u8)
@@ -2,6 +2,7 @@ use crate::error::Result;
 use crate::patch::*;
 use crate::PkmnapiDB;
 use byteorder::ReadBytesExt;
+use serde::{Deserialize, Serialize};
 use std::cmp;
 use std::collections::HashMap;
 use std::io::Cursor;
@@ -41,8 +42,9 @@ impl PkmnapiDB {
     pub fn get_type_effect(&self, type_effect_id: &u8) -> Result<TypeEffect> {
         let _max_id = self.type_effect_id_validate(type_effect_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer = (offset_base + 0x2474) + ((*type_effect_id as usize) * 0x03);
+        let (bank_id, offset) = self.offsets.type_effects;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer = (offset_base + offset) + ((*type_effect_id as usize) * 0x03);
 
         let type_effect = TypeEffect::from(&self.rom[pointer..(pointer + 3)]);
 
@@ -86,8 +88,9 @@ impl PkmnapiDB {
     pub fn set_type_effect(&self, type_effect_id: &u8, type_effect: &TypeEffect) -> Result<Patch> {
         let _max_id = self.type_effect_id_validate(type_effect_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer = offset_base + 0x2474 + ((*type_effect_id as usize) * 3);
+        let (bank_id, offset) = self.offsets.type_effects;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer = offset_base + offset + ((*type_effect_id as usize) * 3);
 
         let type_effect_raw = type_effect.to_raw();
 
@@ -114,7 +117,7 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TypeEffect {
     pub attacking_type_id: u8,
     pub defending_type_id: u8,
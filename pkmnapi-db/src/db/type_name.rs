@@ -2,6 +2,7 @@ use crate::error::{self, Result};
 use crate::patch::*;
 use crate::string::*;
 use crate::PkmnapiDB;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 impl PkmnapiDB {
@@ -35,8 +36,9 @@ impl PkmnapiDB {
     pub fn get_type_name(&self, type_id: &u8) -> Result<TypeName> {
         let _max_id = self.type_id_validate(type_id)?;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x08;
-        let pointer_base = offset_base + 0x7DAE;
+        let (bank_id, offset) = self.offsets.type_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_base = offset_base + offset;
         let pointer_offset = pointer_base + ((*type_id as usize) * 2);
         let pointer = offset_base + self.get_pointer(pointer_offset);
 
@@ -91,8 +93,9 @@ impl PkmnapiDB {
             ));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x08;
-        let pointer_offset = (offset_base + 0x7DAE) + ((*type_id as usize) * 2);
+        let (bank_id, offset) = self.offsets.type_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_offset = (offset_base + offset) + ((*type_id as usize) * 2);
         let pointer = offset_base + self.get_pointer(pointer_offset);
 
         let data = [type_name_raw, vec![0x50; old_type_name_len - type_name_len]].concat();
@@ -119,7 +122,8 @@ impl PkmnapiDB {
 ///     }
 /// );
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct TypeName {
     pub name: ROMString,
 }
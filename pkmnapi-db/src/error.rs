@@ -17,46 +17,89 @@ pub enum Error {
     CryCouldNotCreate,
     CryCouldNotFinalize,
     CryCouldNotWriteSample,
+    FreeSpaceNotFound(usize),
+    GameUnsupported(String),
     HeaderParseError(String),
     HeaderTooSmall,
+    HiddenObjectCoordinatesInvalid(u8, u8),
+    HiddenObjectFlagMissing(u8, u8),
+    HiddenObjectsMapInvalid(u8),
     HMIDInvalid(u8, usize, usize),
+    IconFramesMismatch(u8),
+    IconFramesWrongSize(usize, usize),
     IconIDInvalid(u8, usize, usize),
+    IconImgWrongSize(u32, u32),
+    IconNotMirrored(u8),
     ImgCouldNotRead,
     ImgCouldNotWrite,
     InternalIDInvalid(u8),
     ItemIDInvalid(u8, usize, usize),
     ItemNameWrongSize(usize, usize),
+    MapBlockNotFound(usize, usize),
+    MapBlocksWrongSize(usize, usize, usize, usize),
+    MapCouldNotRead,
     MapCouldNotWrite,
     MapIDInvalid(u8, usize, usize),
     MapInvalid(u8),
+    MapObjectCoordinatesInvalid(u8, u8),
     MapPokemonWrongSize(usize, usize),
+    MapWrongSize,
     MartIDInvalid(u8, usize, usize),
-    MartItemsWrongSize(usize, usize),
     MoveIDInvalid(u8, usize, usize),
     MoveNameWrongSize(usize, usize),
+    PaletteNotIndexed,
+    PaletteTooManyColors(usize, Vec<(u32, u32)>),
+    PatchChecksumMismatch,
+    PatchCouldNotRead,
+    PatchOutOfBounds(usize, usize),
     PicCouldNotRead,
     PicCouldNotWrite,
+    PicDimensionsInvalid(u8, u8, u8, u8),
     PicTooLarge,
     PicWrongSize,
     PlayerNamesWrongSize(usize, usize),
     PokedexEntrySpeciesWrongSize(usize, usize),
     PokedexIDInvalid(u8),
-    PokedexTextWrongSize(usize, usize),
-    PokemonEvolutionWrongSize(usize, usize),
-    PokemonLearnsetWrongSize(usize, usize),
     PokemonLogoWrongSize(usize, usize),
     PokemonMovesetWrongSize(usize, usize),
     PokemonTitleWrongSize(usize, usize),
+    ProjectVersionInvalid(u32, u32),
     SavBagItemsWrongSize(usize, usize),
+    SavBoxIDInvalid(u8, usize, usize),
     SavBoxItemsWrongSize(usize, usize),
+    SavBoxPokemonWrongSize(usize, usize),
+    SavPartyPokemonWrongSize(usize, usize),
     SavPlayerNameWrongSize(usize, usize),
+    SavPokemonMovesWrongSize(usize, usize),
+    SavPokemonNameWrongSize(usize, usize),
     SavRivalNameWrongSize(usize, usize),
     SavWrongSize(usize, usize),
+    SGBPaletteIDInvalid(u8, usize, usize),
+    StartersWrongSize(usize, usize),
+    StaticPokemonIDInvalid(u8, usize, usize),
+    StaticPokemonLevelInvalid(u8),
+    StaticPokemonTypeInvalid(u8),
+    StringCharactersInvalid(Vec<(usize, char)>),
+    TextInvalid(usize),
+    TextMarkupInvalid(String),
+    TextPointerInvalid(u8, u16),
+    TextTooLarge(usize, usize),
+    TilesetBlocksShared(u8, u8),
+    TilesetBlocksWrongSize(usize, usize),
+    TilesetCounterTilesWrongSize(usize, usize),
+    TilesetIDInvalid(u8, usize, usize),
+    TilesetImgShared(u8, u8),
+    TilesetImgWrongSize(usize, usize),
     TMIDInvalid(u8, usize, usize),
+    TownMapImgWrongSize(u32, u32),
+    TownMapLocationCoordinatesInvalid(u8, u8),
+    TownMapLocationIDInvalid(u8, usize, usize),
+    TownMapLocationMapIDsInvalid(u8, u8, u8),
+    TownMapTileNotFound(u32, u32),
+    TownMapTooLarge(usize, usize),
     TradeIDInvalid(u8, usize, usize),
     TrainerIDInvalid(u8, usize, usize),
     TrainerNameWrongSize(usize, usize),
-    TrainerPartiesWrongDataSize(usize, usize),
     TrainerPartiesWrongSize(usize, usize),
     TypeEffectIDInvalid(u8, usize, usize),
     TypeIDInvalid(u8, usize, usize),
@@ -87,15 +130,45 @@ impl fmt::Display for Error {
             Error::CryCouldNotCreate => "Could not create cry".to_owned(),
             Error::CryCouldNotFinalize => "Could not finalize cry".to_owned(),
             Error::CryCouldNotWriteSample => "Could not write cry sample".to_owned(),
+            Error::FreeSpaceNotFound(size) => {
+                format!("Could not find {} bytes of free space", size)
+            }
+            Error::GameUnsupported(title) => format!("Unsupported game: {}", title),
             Error::HeaderParseError(string) => string.to_owned(),
             Error::HeaderTooSmall => "Header too small".to_owned(),
+            Error::HiddenObjectCoordinatesInvalid(x, y) => format!(
+                "Invalid hidden object coordinates ({}, {}): Y coordinate 255 ends the list",
+                x, y
+            ),
+            Error::HiddenObjectFlagMissing(x, y) => format!(
+                "Hidden object at ({}, {}) has no pickup flag: hidden items and coins can only be moved, or added where one already exists",
+                x, y
+            ),
+            Error::HiddenObjectsMapInvalid(map_id) => {
+                format!("Map {} has no hidden objects", map_id)
+            }
             Error::HMIDInvalid(hm_id, min, max) => {
                 format!("Invalid HM ID {}: valid range is {}-{}", hm_id, min, max)
             }
+            Error::IconFramesMismatch(icon_id) => {
+                format!("Icon {} frames share tiles but do not match", icon_id)
+            }
+            Error::IconFramesWrongSize(expected, actual) => format!(
+                "Icon frames length mismatch: should be exactly {} frames, found {}",
+                expected, actual
+            ),
             Error::IconIDInvalid(icon_id, min, max) => format!(
                 "Invalid icon ID {}: valid range is {}-{}",
                 icon_id, min, max
             ),
+            Error::IconImgWrongSize(width, height) => format!(
+                "Icon size mismatch: should be 2x2 tiles, found {}x{}",
+                width, height
+            ),
+            Error::IconNotMirrored(icon_id) => format!(
+                "Icon {} must be symmetrical: its right half is a mirror of its left half",
+                icon_id
+            ),
             Error::ImgCouldNotRead => "Could not read image".to_owned(),
             Error::ImgCouldNotWrite => "Could not write image".to_owned(),
             Error::InternalIDInvalid(internal_id) => {
@@ -109,23 +182,33 @@ impl fmt::Display for Error {
                 "Item name length mismatch: should be exactly {} characters, found {}",
                 expected, actual
             ),
+            Error::MapBlockNotFound(x, y) => format!(
+                "Block at {}, {} does not match any block in the map's tileset",
+                x, y
+            ),
+            Error::MapBlocksWrongSize(expected_width, expected_height, width, height) => format!(
+                "Map size mismatch: should be {}x{} blocks, found {}x{}",
+                expected_width, expected_height, width, height
+            ),
+            Error::MapCouldNotRead => "Could not read image".to_owned(),
             Error::MapCouldNotWrite => "Could not write image".to_owned(),
             Error::MapIDInvalid(map_id, min, max) => {
                 format!("Invalid map ID {}: valid range is {}-{}", map_id, min, max)
             }
             Error::MapInvalid(map_id) => format!("Invalid map ID: {}", map_id),
+            Error::MapObjectCoordinatesInvalid(x, y) => format!(
+                "Invalid map object coordinates ({}, {}): valid range is 0-251",
+                x, y
+            ),
             Error::MapPokemonWrongSize(expected, actual) => format!(
                 "Map Pokémon size mismatch: should be exactly {} bytes, found {}",
                 expected, actual
             ),
+            Error::MapWrongSize => "Image dimensions must be multiples of 32".to_owned(),
             Error::MartIDInvalid(mart_id, min, max) => format!(
                 "Invalid mart ID {}: valid range is {}-{}",
                 mart_id, min, max
             ),
-            Error::MartItemsWrongSize(expected, actual) => format!(
-                "Mart items length mismatch: should be exactly {}, found {}",
-                expected, actual
-            ),
             Error::MoveIDInvalid(move_id, min, max) => format!(
                 "Invalid move ID {}: valid range is {}-{}",
                 move_id, min, max
@@ -134,8 +217,37 @@ impl fmt::Display for Error {
                 "Move name length mismatch: should be exactly {} characters, found {}",
                 expected, actual
             ),
+            Error::PaletteNotIndexed => "Image is not an indexed PNG".to_owned(),
+            Error::PaletteTooManyColors(colors, pixels) => {
+                let coordinates: Vec<String> = pixels
+                    .iter()
+                    .take(10)
+                    .map(|(x, y)| format!("({}, {})", x, y))
+                    .collect();
+                let remaining = match pixels.len() {
+                    len if len > 10 => format!(" and {} more", len - 10),
+                    _ => "".to_owned(),
+                };
+
+                format!(
+                    "Image has {} colors, should be at most 4: offending pixels at {}{}",
+                    colors,
+                    coordinates.join(", "),
+                    remaining
+                )
+            }
+            Error::PatchChecksumMismatch => "Patch checksum mismatch".to_owned(),
+            Error::PatchCouldNotRead => "Could not read patch".to_owned(),
+            Error::PatchOutOfBounds(offset, max) => format!(
+                "Patch record out of bounds: offset {} exceeds data size {}",
+                offset, max
+            ),
             Error::PicCouldNotRead => "Could not read image".to_owned(),
             Error::PicCouldNotWrite => "Could not write image".to_owned(),
+            Error::PicDimensionsInvalid(max_width, max_height, width, height) => format!(
+                "Invalid pic dimensions {}x{}: expected {}x{} tiles",
+                width, height, max_width, max_height
+            ),
             Error::PicTooLarge => "Compressed image is too large".to_owned(),
             Error::PicWrongSize => "Image dimensions must be multiples of 8".to_owned(),
             Error::PlayerNamesWrongSize(expected, actual) => format!(
@@ -147,18 +259,6 @@ impl fmt::Display for Error {
                 expected, actual
             ),
             Error::PokedexIDInvalid(pokedex_id) => format!("Invalid Pokédex ID: {}", pokedex_id),
-            Error::PokedexTextWrongSize(expected, actual) => format!(
-                "Pokédex text length mismatch: should be {} characters or fewer, found {}",
-                expected, actual
-            ),
-            Error::PokemonEvolutionWrongSize(expected, actual) => format!(
-                "Pokémon evolutions length mismatch, should be exactly {} bytes, found {}",
-                expected, actual
-            ),
-            Error::PokemonLearnsetWrongSize(expected, actual) => format!(
-                "Pokémon learnset length mismatch, should be exactly {} bytes, found {}",
-                expected, actual
-            ),
             Error::PokemonLogoWrongSize(expected, actual) => format!(
                 "Pokémon logo size mismatch: should be exactly {} bytes, found {}",
                 expected, actual
@@ -171,18 +271,41 @@ impl fmt::Display for Error {
                 "Pokémon title length mismatch: should be {}, found {}",
                 expected, actual
             ),
+            Error::ProjectVersionInvalid(expected, actual) => format!(
+                "Project version mismatch: should be version {}, found {}",
+                expected, actual
+            ),
             Error::SavBagItemsWrongSize(expected, actual) => format!(
                 "Sav bag items length mismatch: should be {} items or fewer, found {}",
                 expected, actual
             ),
+            Error::SavBoxIDInvalid(box_id, min, max) => {
+                format!("Invalid box ID {}: valid range is {}-{}", box_id, min, max)
+            }
             Error::SavBoxItemsWrongSize(expected, actual) => format!(
                 "Sav box items length mismatch: should be {} items or fewer, found {}",
                 expected, actual
             ),
+            Error::SavBoxPokemonWrongSize(expected, actual) => format!(
+                "Sav box Pokémon length mismatch: should be {} Pokémon or fewer, found {}",
+                expected, actual
+            ),
+            Error::SavPartyPokemonWrongSize(expected, actual) => format!(
+                "Sav party Pokémon length mismatch: should be {} Pokémon or fewer, found {}",
+                expected, actual
+            ),
             Error::SavPlayerNameWrongSize(expected, actual) => format!(
                 "Player name length mismatch: should be {} characters or fewer, found {}",
                 expected, actual
             ),
+            Error::SavPokemonMovesWrongSize(expected, actual) => format!(
+                "Sav Pokémon moves length mismatch: should be {} moves or fewer, found {}",
+                expected, actual
+            ),
+            Error::SavPokemonNameWrongSize(expected, actual) => format!(
+                "Sav Pokémon name length mismatch: should be {} characters or fewer, found {}",
+                expected, actual
+            ),
             Error::SavRivalNameWrongSize(expected, actual) => format!(
                 "Sav rival name length mismatch: should be {} characters or fewer, found {}",
                 expected, actual
@@ -191,9 +314,97 @@ impl fmt::Display for Error {
                 "Sav length mismatch: should be {} bytes, found {}",
                 expected, actual
             ),
+            Error::SGBPaletteIDInvalid(sgb_palette_id, min, max) => format!(
+                "Invalid SGB palette ID {}: valid range is {}-{}",
+                sgb_palette_id, min, max
+            ),
+            Error::StartersWrongSize(expected, actual) => format!(
+                "Starters length mismatch: should be exactly {} Pokémon, found {}",
+                expected, actual
+            ),
+            Error::StaticPokemonIDInvalid(static_pokemon_id, min, max) => format!(
+                "Invalid static Pokémon ID {}: valid range is {}-{}",
+                static_pokemon_id, min, max
+            ),
+            Error::StaticPokemonLevelInvalid(level) => format!(
+                "Invalid static Pokémon level {}: valid range is 1-100",
+                level
+            ),
+            Error::StaticPokemonTypeInvalid(static_pokemon_id) => format!(
+                "Invalid static Pokémon type for static Pokémon ID {}: static Pokémon cannot be moved",
+                static_pokemon_id
+            ),
+            Error::StringCharactersInvalid(chars) => {
+                let chars: Vec<String> = chars
+                    .iter()
+                    .map(|(position, c)| format!("{:?} at {}", c, position))
+                    .collect();
+
+                format!("Unsupported characters in string: {}", chars.join(", "))
+            }
+            Error::TextInvalid(offset) => {
+                format!("Invalid text: unknown text command at byte {}", offset)
+            }
+            Error::TextMarkupInvalid(reason) => format!("Invalid text markup: {}", reason),
+            Error::TextPointerInvalid(bank_id, pointer) => format!(
+                "Invalid text pointer {:02X}:{:04X}: pointer is outside of bank",
+                bank_id, pointer
+            ),
+            Error::TextTooLarge(expected, actual) => format!(
+                "Text too large: should be {} bytes or fewer, found {}",
+                expected, actual
+            ),
+            Error::TilesetBlocksShared(tileset_id, shared_tileset_id) => format!(
+                "Tileset {} blocks cannot be changed: they are shared with tileset {}",
+                tileset_id, shared_tileset_id
+            ),
+            Error::TilesetBlocksWrongSize(expected, actual) => format!(
+                "Tileset blocks length mismatch: should be exactly {} blocks of 16 tiles, found {}",
+                expected, actual
+            ),
+            Error::TilesetCounterTilesWrongSize(expected, actual) => format!(
+                "Tileset counter tiles length mismatch: should be at most {} tiles, found {}",
+                expected, actual
+            ),
+            Error::TilesetIDInvalid(tileset_id, min, max) => format!(
+                "Invalid tileset ID {}: valid range is {}-{}",
+                tileset_id, min, max
+            ),
+            Error::TilesetImgShared(tileset_id, shared_tileset_id) => format!(
+                "Tileset {} graphics cannot be changed: they are shared with tileset {}",
+                tileset_id, shared_tileset_id
+            ),
+            Error::TilesetImgWrongSize(expected, actual) => format!(
+                "Tileset image size mismatch: should be exactly {} bytes, found {}",
+                expected, actual
+            ),
             Error::TMIDInvalid(tm_id, min, max) => {
                 format!("Invalid TM ID {}: valid range is {}-{}", tm_id, min, max)
             }
+            Error::TownMapImgWrongSize(width, height) => format!(
+                "Town map size mismatch: should be 20x18 tiles, found {}x{}",
+                width, height
+            ),
+            Error::TownMapLocationCoordinatesInvalid(x, y) => format!(
+                "Invalid town map coordinates ({}, {}): x and y should be at most 15",
+                x, y
+            ),
+            Error::TownMapLocationIDInvalid(town_map_location_id, min, max) => format!(
+                "Invalid town map location ID {}: valid range is {}-{}",
+                town_map_location_id, min, max
+            ),
+            Error::TownMapLocationMapIDsInvalid(town_map_location_id, first, last) => format!(
+                "Invalid map IDs for town map location {}: should be consecutive map IDs from {} up to at most {}",
+                town_map_location_id, first, last
+            ),
+            Error::TownMapTileNotFound(x, y) => format!(
+                "Town map tile at ({}, {}) does not match any town map tile",
+                x, y
+            ),
+            Error::TownMapTooLarge(expected, actual) => format!(
+                "Town map too large: should be {} bytes or fewer when compressed, found {}",
+                expected, actual
+            ),
             Error::TradeIDInvalid(trade_id, min, max) => format!(
                 "Invalid trade ID {}: valid range is {}-{}",
                 trade_id, min, max
@@ -206,10 +417,6 @@ impl fmt::Display for Error {
                 "Trainer name length mismatch: should be exactly {} characters, found {}",
                 expected, actual
             ),
-            Error::TrainerPartiesWrongDataSize(expected, actual) => format!(
-                "Trainer parties data length mismatch: should be exactly {} bytes, found {}",
-                expected, actual
-            ),
             Error::TrainerPartiesWrongSize(expected, actual) => format!(
                 "Trainer parties length mismatch: should be exactly {} {}, found {}",
                 expected,
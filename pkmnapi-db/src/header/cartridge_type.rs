@@ -7,7 +7,7 @@
 ///
 /// let cartridge_type = CartridgeType::ROM_ONLY;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum CartridgeType {
     ROM_ONLY,
@@ -7,7 +7,7 @@
 ///
 /// let flag = CGBFlag::NONE;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CGBFlag {
     NONE,
     SOME,
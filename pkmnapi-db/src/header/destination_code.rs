@@ -7,7 +7,7 @@
 ///
 /// let code = DestinationCode::NON_JAPANESE;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum DestinationCode {
     JAPANESE,
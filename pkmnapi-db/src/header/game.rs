@@ -0,0 +1,71 @@
+use crate::error::{self, Result};
+use crate::header::Header;
+use std::convert::TryFrom;
+
+/// Game version
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::header::*;
+///
+/// let game = Game::YELLOW;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Game {
+    RED,
+    BLUE,
+    YELLOW,
+}
+
+impl Game {
+    const RED_GLOBAL_CHECKSUM: u16 = 0x91E6;
+    const BLUE_GLOBAL_CHECKSUM: u16 = 0x9D0A;
+    const YELLOW_GLOBAL_CHECKSUM: u16 = 0x047C;
+}
+
+impl TryFrom<&Header> for Game {
+    type Error = error::Error;
+
+    /// Detect game version from a ROM header
+    ///
+    /// The title is checked first, falling back to the global checksum for
+    /// ROMs with a modified title. Anything unrecognised is an error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::header::*;
+    /// use std::convert::TryFrom;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let header = Header::from(&rom).unwrap();
+    /// let game = Game::try_from(&header).unwrap();
+    ///
+    /// // RED
+    /// # #[cfg(feature = "PKMN_RED")]
+    /// assert_eq!(game, Game::RED);
+    ///
+    /// // BLUE
+    /// # #[cfg(not(feature = "PKMN_RED"))]
+    /// assert_eq!(game, Game::BLUE);
+    /// ```
+    fn try_from(header: &Header) -> Result<Self> {
+        match header.title.as_str() {
+            "POKEMON RED" => return Ok(Game::RED),
+            "POKEMON BLUE" => return Ok(Game::BLUE),
+            "POKEMON YELLOW" => return Ok(Game::YELLOW),
+            _ => {}
+        };
+
+        match header.global_checksum {
+            Game::BLUE_GLOBAL_CHECKSUM => Ok(Game::BLUE),
+            Game::YELLOW_GLOBAL_CHECKSUM => Ok(Game::YELLOW),
+            Game::RED_GLOBAL_CHECKSUM => Ok(Game::RED),
+            _ => Err(error::Error::GameUnsupported(header.title.to_owned())),
+        }
+    }
+}
@@ -21,6 +21,7 @@
 mod cartridge_type;
 mod cbg_flag;
 mod destination_code;
+mod game;
 mod new_licensee_code;
 mod old_licensee_code;
 mod ram_size;
@@ -30,6 +31,7 @@ mod sgb_flag;
 pub use cartridge_type::*;
 pub use cbg_flag::*;
 pub use destination_code::*;
+pub use game::*;
 pub use new_licensee_code::*;
 pub use old_licensee_code::*;
 pub use ram_size::*;
@@ -43,7 +45,7 @@ use std::num::Wrapping;
 use std::str;
 
 /// Representation of a ROM header
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Header {
     raw: Vec<u8>,
     entry_point: Vec<u8>,
@@ -56,7 +58,7 @@ pub struct Header {
     cartridge_type: CartridgeType,
     rom_size: RomSize,
     ram_size: RamSize,
-    destination_code: DestinationCode,
+    pub destination_code: DestinationCode,
     old_licensee_code: OldLicenseeCode,
     mask_rom_version_number: u8,
     header_checksum: u8,
@@ -93,12 +95,16 @@ impl Header {
         let raw = rom[0x100..=0x14F].to_vec();
         let entry_point = raw[0x000..=0x003].to_vec();
         let logo = raw[0x004..=0x033].to_vec();
-        let title = match str::from_utf8(&raw[0x034..=0x043]) {
+        let cgb_flag = CGBFlag::from(raw[0x043]);
+        let title_end = match cgb_flag {
+            CGBFlag::NONE => 0x043,
+            _ => 0x042,
+        };
+        let title = match str::from_utf8(&raw[0x034..=title_end]) {
             Ok(title) => title.trim_matches(char::from(0)).to_string(),
             Err(e) => return Err(error::Error::HeaderParseError(e.to_string())),
         };
         let manufacturer_code = raw[0x03F..=0x042].to_vec();
-        let cgb_flag = CGBFlag::from(raw[0x043]);
         let new_licensee_code = NewLicenseeCode::from(raw[0x044..=0x045].to_vec());
         let sgb_flag = SGBFlag::from(raw[0x046]);
         let cartridge_type = CartridgeType::from(raw[0x047]);
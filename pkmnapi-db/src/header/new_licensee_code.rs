@@ -7,7 +7,7 @@
 ///
 /// let code = NewLicenseeCode::NINTENDO_RND1;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum NewLicenseeCode {
     NONE,
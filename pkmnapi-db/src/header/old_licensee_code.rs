@@ -7,7 +7,7 @@
 ///
 /// let code = OldLicenseeCode::NINTENDO;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum OldLicenseeCode {
     NONE,
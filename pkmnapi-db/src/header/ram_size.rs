@@ -7,7 +7,7 @@
 ///
 /// let size = RamSize::KBYTES_32;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum RamSize {
     NONE,
@@ -7,7 +7,7 @@
 ///
 /// let size = RomSize::MBYTE_1;
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum RomSize {
     KBYTE_32,
@@ -7,7 +7,7 @@
 ///
 /// let flag = SGBFlag::YES(0x03);
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum SGBFlag {
     NO(u8),
     YES(u8),
@@ -1,12 +1,15 @@
-use super::Img;
+use super::{rgba_to_shades, Img, Palette};
 use crate::error::{self, Result};
 use gif::{Encoder, Frame, Repeat};
+use image::codecs::gif::GifDecoder;
+use image::AnimationDecoder;
+use std::io::Cursor;
 
 #[derive(Debug, PartialEq)]
 pub struct Gif {
-    width: u32,
-    height: u32,
-    frames: Vec<Img>,
+    pub width: u32,
+    pub height: u32,
+    pub frames: Vec<Img>,
 }
 
 impl Gif {
@@ -21,15 +24,63 @@ impl Gif {
         }
     }
 
+    /// Creates a gif from GIF data
+    ///
+    /// Each frame may have at most 4 colors, mapped to shades by luminance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::img::*;
+    ///
+    /// let frame_a = Img::new(&2, &2, &vec![vec![0x00, 0x01, 0x02, 0x03].repeat(0x10); 4]).unwrap();
+    /// let frame_b = Img::new(&2, &2, &vec![vec![0x03; 0x40]; 4]).unwrap();
+    /// let gif = Gif::new(&vec![frame_a, frame_b]);
+    ///
+    /// let data = gif.to_gif(26).unwrap();
+    ///
+    /// assert_eq!(Gif::from_gif(data).unwrap(), gif);
+    /// ```
+    pub fn from_gif(data: Vec<u8>) -> Result<Self> {
+        let decoder = match GifDecoder::new(Cursor::new(data)) {
+            Ok(decoder) => decoder,
+            Err(_) => return Err(error::Error::ImgCouldNotRead),
+        };
+
+        let frames = match decoder.into_frames().collect_frames() {
+            Ok(frames) if !frames.is_empty() => frames,
+            _ => return Err(error::Error::ImgCouldNotRead),
+        };
+
+        let frames = frames
+            .iter()
+            .map(|frame| {
+                let buffer = frame.buffer();
+                let pixels = rgba_to_shades(buffer)?;
+
+                Ok(Img {
+                    width: buffer.width() / 8,
+                    height: buffer.height() / 8,
+                    pixels,
+                })
+            })
+            .collect::<Result<Vec<Img>>>()?;
+
+        Ok(Gif::new(&frames))
+    }
+
     pub fn to_gif(&self, delay: u16) -> Result<Vec<u8>> {
+        self.to_gif_with_palette(delay, &[[0xFF; 3], [0xAA; 3], [0x55; 3], [0x00; 3]])
+    }
+
+    /// Renders the gif with one palette color per shade
+    pub fn to_gif_with_palette(&self, delay: u16, palette: &Palette) -> Result<Vec<u8>> {
         let width = (self.width * 8) as u16;
         let height = (self.height * 8) as u16;
 
         let mut buf = Vec::new();
-        let color_map = &[
-            0xFF, 0xFF, 0xFF, 0xAA, 0xAA, 0xAA, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00,
-        ];
-        let mut encoder = match Encoder::new(&mut buf, width, height, color_map) {
+        let color_map: Vec<u8> = palette.iter().flatten().copied().collect();
+        let mut encoder = match Encoder::new(&mut buf, width, height, &color_map) {
             Ok(encoder) => encoder,
             Err(_) => return Err(error::Error::ImgCouldNotWrite),
         };
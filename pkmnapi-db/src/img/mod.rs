@@ -18,11 +18,15 @@
 //! ```
 
 mod gif;
+mod palette;
 
 pub use crate::img::gif::*;
+pub use crate::img::palette::{Palette, PaletteOrder};
+
+pub(crate) use crate::img::palette::{png_to_shades, rgba_to_shades, shades_to_img};
 
 use crate::error::{self, Result};
-use image::{self, DynamicImage, ImageBuffer, ImageFormat, Luma};
+use image::{self, ImageFormat};
 
 /// Representation of an img
 ///
@@ -122,7 +126,36 @@ impl Img {
     }
 
     pub fn from_png(data: Vec<u8>) -> Result<Self> {
-        Img::from(data, ImageFormat::Png)
+        Img::from_png_with_palette(data, &PaletteOrder::LUMINANCE)
+    }
+
+    /// Creates an img from a PNG with at most 4 colors
+    ///
+    /// Colors are mapped to shades by luminance or, for indexed PNGs, by
+    /// palette index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::img::*;
+    ///
+    /// let img = Img::new(&1, &1, &vec![vec![0x00, 0x01, 0x02, 0x03].repeat(0x10)]).unwrap();
+    /// let png = img.to_png().unwrap();
+    ///
+    /// assert_eq!(
+    ///     Img::from_png_with_palette(png, &PaletteOrder::LUMINANCE).unwrap(),
+    ///     img
+    /// );
+    /// ```
+    pub fn from_png_with_palette(data: Vec<u8>, palette_order: &PaletteOrder) -> Result<Self> {
+        let (width, height, pixels) =
+            png_to_shades(&data, palette_order, error::Error::ImgCouldNotRead)?;
+
+        Ok(Img {
+            width: width / 8,
+            height: height / 8,
+            pixels,
+        })
     }
 
     pub fn from_jpeg(data: Vec<u8>) -> Result<Self> {
@@ -191,18 +224,14 @@ impl Img {
         Ok(bpp2)
     }
 
-    fn to_img(&self, format: ImageFormat) -> Result<Vec<u8>> {
+    fn to_img(&self, format: ImageFormat, palette: Option<&Palette>) -> Result<Vec<u8>> {
         let width = self.width * 8;
         let height = self.height * 8;
 
-        let img = ImageBuffer::from_fn(width, height, |x, y| {
-            let i = x + (y * width);
-            let pixel = (3 - self.pixels[i as usize]) * 0x55;
-
-            Luma([pixel])
+        let img = shades_to_img(width, height, palette, |x, y| {
+            self.pixels[(x + (y * width)) as usize]
         });
 
-        let img = DynamicImage::ImageLuma8(img);
         let mut buf = Vec::new();
 
         match img.write_to(&mut buf, format) {
@@ -214,10 +243,29 @@ impl Img {
     }
 
     pub fn to_png(&self) -> Result<Vec<u8>> {
-        self.to_img(ImageFormat::Png)
+        self.to_img(ImageFormat::Png, None)
+    }
+
+    /// Renders the img as an RGB PNG, one palette color per shade
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::img::*;
+    ///
+    /// let img = Img::new(&1, &1, &vec![vec![0x01; 0x40]]).unwrap();
+    /// let palette = [[0xFF, 0xFF, 0xFF], [0xF8, 0x00, 0x00], [0x80, 0x00, 0x00], [0x00, 0x00, 0x00]];
+    ///
+    /// let png = img.to_png_with_palette(&palette).unwrap();
+    /// let rgb = image::load_from_memory(&png).unwrap().to_rgb8();
+    ///
+    /// assert_eq!(rgb.get_pixel(0, 0).0, [0xF8, 0x00, 0x00]);
+    /// ```
+    pub fn to_png_with_palette(&self, palette: &Palette) -> Result<Vec<u8>> {
+        self.to_img(ImageFormat::Png, Some(palette))
     }
 
     pub fn to_jpeg(&self) -> Result<Vec<u8>> {
-        self.to_img(ImageFormat::Jpeg)
+        self.to_img(ImageFormat::Jpeg, None)
     }
 }
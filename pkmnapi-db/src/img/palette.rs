@@ -0,0 +1,226 @@
+use crate::error::{self, Result};
+use image::{self, DynamicImage, ImageBuffer, ImageFormat, Luma, Rgb, RgbaImage};
+use std::collections::HashMap;
+
+/// Order in which the colors of an image are mapped to the 4 shades
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::img::*;
+///
+/// let palette_order = PaletteOrder::from(Some("index".to_owned()));
+///
+/// assert_eq!(palette_order, PaletteOrder::INDEX);
+///
+/// let palette_order = PaletteOrder::from(None);
+///
+/// assert_eq!(palette_order, PaletteOrder::LUMINANCE);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteOrder {
+    /// Lightest color is shade 0, darkest color is shade 3
+    LUMINANCE,
+    /// Palette index of an indexed PNG is the shade
+    INDEX,
+}
+
+impl From<Option<String>> for PaletteOrder {
+    /// Convert String to PaletteOrder
+    fn from(palette_order: Option<String>) -> Self {
+        match palette_order {
+            Some(palette_order) if palette_order.to_lowercase() == "index" => PaletteOrder::INDEX,
+            _ => PaletteOrder::LUMINANCE,
+        }
+    }
+}
+
+/// RGB color of each of the 4 shades, lightest first
+pub type Palette = [[u8; 3]; 4];
+
+/// Maximum number of distinct colors
+const PALETTE_SIZE: usize = 4;
+
+/// Reads a PNG as shades 0-3
+///
+/// Returns the width and height in pixels along with one shade per pixel.
+/// Images with more than 4 distinct colors are rejected with the coordinates
+/// of the pixels that do not fit in the palette.
+pub(crate) fn png_to_shades(
+    data: &[u8],
+    palette_order: &PaletteOrder,
+    could_not_read: error::Error,
+) -> Result<(u32, u32, Vec<u8>)> {
+    match palette_order {
+        PaletteOrder::LUMINANCE => png_to_shades_luminance(data, could_not_read),
+        PaletteOrder::INDEX => png_to_shades_index(data, could_not_read),
+    }
+}
+
+fn png_to_shades_luminance(
+    data: &[u8],
+    could_not_read: error::Error,
+) -> Result<(u32, u32, Vec<u8>)> {
+    let img = match image::load_from_memory_with_format(data, ImageFormat::Png) {
+        Ok(img) => img.to_rgba8(),
+        Err(_) => return Err(could_not_read),
+    };
+
+    let pixels = rgba_to_shades(&img)?;
+
+    Ok((img.width(), img.height(), pixels))
+}
+
+/// Maps the colors of an image to shades 0-3 by luminance
+///
+/// Alpha is ignored.
+pub(crate) fn rgba_to_shades(img: &RgbaImage) -> Result<Vec<u8>> {
+    let colors: Vec<[u8; 3]> = img
+        .pixels()
+        .map(|pixel| [pixel.0[0], pixel.0[1], pixel.0[2]])
+        .collect();
+
+    let mut color_counts: HashMap<[u8; 3], usize> = HashMap::new();
+
+    for color in &colors {
+        *color_counts.entry(*color).or_insert(0) += 1;
+    }
+
+    if color_counts.len() > PALETTE_SIZE {
+        let mut most_common: Vec<([u8; 3], usize)> = color_counts.into_iter().collect();
+
+        most_common.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+        let palette: Vec<[u8; 3]> = most_common
+            .iter()
+            .take(PALETTE_SIZE)
+            .map(|(color, _)| *color)
+            .collect();
+
+        return Err(error::Error::PaletteTooManyColors(
+            most_common.len(),
+            offending_pixels(img.width(), &colors, |color| !palette.contains(color)),
+        ));
+    }
+
+    let mut palette: Vec<([u8; 3], u32)> = color_counts
+        .keys()
+        .map(|color| (*color, luminance(color)))
+        .collect();
+
+    palette.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let palette_len = palette.len();
+    let mut shades: HashMap<[u8; 3], u8> = HashMap::new();
+    let mut previous_shade: Option<u8> = None;
+
+    for (i, (color, luminance)) in palette.iter().enumerate() {
+        let shade = 3 - ((luminance / 85) as u8);
+        let shade = match previous_shade {
+            Some(previous_shade) => shade.max(previous_shade + 1),
+            None => shade,
+        };
+        let shade = shade.min((PALETTE_SIZE - palette_len + i) as u8);
+
+        shades.insert(*color, shade);
+        previous_shade = Some(shade);
+    }
+
+    let pixels = colors.iter().map(|color| shades[color]).collect();
+
+    Ok(pixels)
+}
+
+fn png_to_shades_index(data: &[u8], could_not_read: error::Error) -> Result<(u32, u32, Vec<u8>)> {
+    let mut decoder = png::Decoder::new(data);
+
+    decoder.set_transformations(png::Transformations::IDENTITY);
+
+    let (info, mut reader) = match decoder.read_info() {
+        Ok(decoded) => decoded,
+        Err(_) => return Err(could_not_read),
+    };
+
+    if info.color_type != png::ColorType::Indexed {
+        return Err(error::Error::PaletteNotIndexed);
+    }
+
+    let mut buf = vec![0x00; info.buffer_size()];
+
+    if reader.next_frame(&mut buf).is_err() {
+        return Err(could_not_read);
+    }
+
+    let palette_len = match &reader.info().palette {
+        Some(palette) => palette.len() / 3,
+        None => return Err(could_not_read),
+    };
+
+    let bit_depth = info.bit_depth as usize;
+    let pixels_per_byte = 8 / bit_depth;
+    let mask = ((1u16 << bit_depth) - 1) as u8;
+
+    let indices: Vec<u8> = buf
+        .chunks(info.line_size)
+        .map(|row| {
+            (0..(info.width as usize)).map(move |x| {
+                let byte = row[x / pixels_per_byte];
+                let shift = 8 - (((x % pixels_per_byte) + 1) * bit_depth);
+
+                (byte >> shift) & mask
+            })
+        })
+        .flatten()
+        .collect();
+
+    if indices.iter().any(|index| *index as usize >= PALETTE_SIZE) {
+        return Err(error::Error::PaletteTooManyColors(
+            palette_len,
+            offending_pixels(info.width, &indices, |index| {
+                *index as usize >= PALETTE_SIZE
+            }),
+        ));
+    }
+
+    Ok((info.width, info.height, indices))
+}
+
+/// Perceived brightness of a color, 0-255
+fn luminance(color: &[u8; 3]) -> u32 {
+    ((color[0] as u32 * 299) + (color[1] as u32 * 587) + (color[2] as u32 * 114)) / 1000
+}
+
+/// Coordinates of every pixel matching the predicate
+fn offending_pixels<T, F>(width: u32, pixels: &Vec<T>, is_offending: F) -> Vec<(u32, u32)>
+where
+    F: Fn(&T) -> bool,
+{
+    pixels
+        .iter()
+        .enumerate()
+        .filter(|(_, pixel)| is_offending(pixel))
+        .map(|(i, _)| ((i as u32) % width, (i as u32) / width))
+        .collect()
+}
+
+/// Builds an image from shades 0-3
+///
+/// Without a palette the image is 8-bit grayscale, otherwise it is RGB.
+pub(crate) fn shades_to_img<F>(
+    width: u32,
+    height: u32,
+    palette: Option<&Palette>,
+    shade: F,
+) -> DynamicImage
+where
+    F: Fn(u32, u32) -> u8,
+{
+    match palette {
+        Some(palette) => DynamicImage::ImageRgb8(ImageBuffer::from_fn(width, height, |x, y| {
+            Rgb(palette[shade(x, y) as usize])
+        })),
+        None => DynamicImage::ImageLuma8(ImageBuffer::from_fn(width, height, |x, y| {
+            Luma([(3 - shade(x, y)) * 0x55])
+        })),
+    }
+}
@@ -12,6 +12,7 @@
 //! let db = PkmnapiDB::new(&rom).build().unwrap();
 //! ```
 
+pub mod battle;
 pub mod cry;
 pub mod error;
 pub mod header;
@@ -21,9 +22,11 @@ pub mod patch;
 pub mod pic;
 pub mod sav;
 pub mod string;
+pub mod text;
 
 mod builder;
 mod db;
+mod offsets;
 
 pub use builder::*;
 pub use db::*;
@@ -31,12 +34,14 @@ pub use db::*;
 use byteorder::{LittleEndian, ReadBytesExt};
 use error::Result;
 use header::*;
+use offsets::Offsets;
 use patch::*;
 use sav::*;
 use std::cmp;
 use std::collections::HashMap;
 use std::io::Cursor;
 use std::num::Wrapping;
+use string::Charset;
 
 /// Pkmnapi database
 ///
@@ -51,12 +56,15 @@ use std::num::Wrapping;
 /// let rom = fs::read(rom_path).unwrap();
 /// let db = PkmnapiDB::new(&rom).build().unwrap();
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PkmnapiDB {
     pub rom: Vec<u8>,
     pub sav: Option<Sav>,
     pub hash: String,
     pub header: Header,
+    pub game: Game,
+    offsets: &'static Offsets,
+    allocations: Vec<(usize, usize)>,
 }
 
 impl PkmnapiDB {
@@ -180,8 +188,32 @@ impl PkmnapiDB {
         self.hash == hash.into()
     }
 
+    /// Character set of the ROM's text
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// assert_eq!(db.charset(), Charset::ENGLISH);
+    /// ```
+    pub fn charset(&self) -> Charset {
+        Charset::from(&self.header.destination_code)
+    }
+
     /// Apply ROM patch
     ///
+    /// Accepts a single `Patch` or a `PatchSet`. A patch set is applied as a
+    /// whole, or not at all and an error is returned if any of its patches
+    /// falls outside the ROM.
+    ///
     /// # Example
     ///
     /// ```
@@ -198,19 +230,132 @@ impl PkmnapiDB {
     ///
     /// let patch = Patch::new(&0x00, &vec![0x13, 0x37]);
     ///
-    /// db.apply_patch(patch);
+    /// db.apply_patch(patch).unwrap();
     ///
     /// assert_eq!(db.rom[..4], [0x13, 0x37, 0x00, 0x00]);
     /// ```
-    pub fn apply_patch<S: Into<Patch>>(&mut self, patch: S) {
-        let patch = patch.into();
+    pub fn apply_patch<S: Into<PatchSet>>(&mut self, patch: S) -> Result<()> {
+        let patch_set = patch.into();
+
+        for patch in patch_set.patches.iter() {
+            if patch.offset + patch.length > self.rom.len() {
+                return Err(error::Error::PatchOutOfBounds(
+                    patch.offset + patch.length,
+                    self.rom.len(),
+                ));
+            }
+        }
+
+        for patch in patch_set.patches {
+            self.rom = [
+                &self.rom[..patch.offset],
+                &patch.data[..],
+                &self.rom[(patch.offset + patch.length)..],
+            ]
+            .concat();
+            self.allocations
+                .push((patch.offset, patch.offset + patch.length));
+        }
+
+        Ok(())
+    }
+
+    /// Import an IPS or BPS patch file
+    ///
+    /// BPS patches are decoded against the ROM and diffed back into patches.
+    /// Every record must lie within the ROM.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let patch_set = db
+    ///     .import_patch(&vec![
+    ///         0x50, 0x41, 0x54, 0x43, 0x48, 0x00, 0x00, 0x00, 0x00, 0x02, 0x13, 0x37, 0x45, 0x4F,
+    ///         0x46,
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     patch_set.patches,
+    ///     vec![Patch::new(&0x00, &vec![0x13, 0x37])]
+    /// );
+    /// ```
+    pub fn import_patch(&self, raw: &Vec<u8>) -> Result<PatchSet> {
+        let patch_set = match raw.get(..4) {
+            Some(b"BPS1") => BPSPatch::from_raw(&self.rom, raw)?.to_patch_set(),
+            _ => PatchSet::from_ips(raw)?,
+        };
+
+        for patch in patch_set.patches.iter() {
+            if patch.offset + patch.length > self.rom.len() {
+                return Err(error::Error::PatchOutOfBounds(
+                    patch.offset + patch.length,
+                    self.rom.len(),
+                ));
+            }
+        }
+
+        Ok(patch_set)
+    }
+
+    /// Get offset of free space within a ROM bank
+    ///
+    /// Banks are padded with 0x00 up to their end. Everything after the last
+    /// used byte of the bank, minus one byte of padding kept as a terminator,
+    /// is treated as free space. Applied patches are tracked, so data ending
+    /// in 0x00 written by an earlier patch is never handed out again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let offset = db.get_free_space(&0x0E, &0x10).unwrap();
+    ///
+    /// assert_eq!(offset / PkmnapiDB::ROM_PAGE, 0x0E);
+    /// assert_eq!(db.rom[(offset - 1)..(offset + 0x10)], [0x00; 0x11]);
+    /// ```
+    pub fn get_free_space(&self, bank_id: &usize, size: &usize) -> Result<usize> {
+        let offset_min = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_max = offset_min + PkmnapiDB::ROM_PAGE;
+
+        if offset_max > self.rom.len() {
+            return Err(error::Error::FreeSpaceNotFound(*size));
+        }
+
+        let offset = match self.rom[offset_min..offset_max]
+            .iter()
+            .rposition(|&x| x != 0x00)
+        {
+            Some(position) => offset_min + position + 2,
+            None => offset_min,
+        };
+        let offset = self
+            .allocations
+            .iter()
+            .filter(|(start, _)| (offset_min..offset_max).contains(start))
+            .fold(offset, |offset, (_, end)| offset.max(*end));
 
-        self.rom = [
-            &self.rom[..patch.offset],
-            &patch.data[..],
-            &self.rom[(patch.offset + patch.length)..],
-        ]
-        .concat();
+        if offset + size > offset_max {
+            return Err(error::Error::FreeSpaceNotFound(*size));
+        }
+
+        Ok(offset)
     }
 
     fn get_all<T>(&self, ids: &Vec<u8>, func: impl Fn(&u8) -> Result<T>) -> Result<HashMap<u8, T>> {
@@ -232,6 +377,16 @@ impl PkmnapiDB {
         cursor.read_u16::<LittleEndian>().unwrap_or(0) as usize
     }
 
+    fn offset_to_pointer(offset: usize) -> Vec<u8> {
+        let pointer = if offset < PkmnapiDB::ROM_PAGE {
+            offset
+        } else {
+            (offset % PkmnapiDB::ROM_PAGE) + PkmnapiDB::ROM_PAGE
+        };
+
+        (pointer as u16).to_le_bytes().to_vec()
+    }
+
     fn get_tiles(&self, offset: usize, tile_count: usize, hi_bit: bool) -> Vec<Vec<u8>> {
         let hi_bit = if hi_bit { 0x01 } else { 0x00 };
         let lo_bit = hi_bit ^ 0x01;
@@ -260,6 +415,44 @@ impl PkmnapiDB {
             .collect()
     }
 
+    /// Encodes a pic and writes it over the one at `offset`
+    ///
+    /// Pics may be at most 7x7 tiles. Without an encoding method, the
+    /// smallest encoding is used. Pics that no longer fit are moved to free
+    /// space in the same bank and the pointer at `pointer_offset` is updated.
+    fn set_pic(
+        &self,
+        bank_id: &usize,
+        offset: &usize,
+        pointer_offset: &usize,
+        pic: &pic::Pic,
+        encoding_method: Option<pic::PicEncodingMethod>,
+    ) -> Result<PatchSet> {
+        if pic.width > 7 || pic.height > 7 {
+            return Err(error::Error::PicDimensionsInvalid(
+                7, 7, pic.width, pic.height,
+            ));
+        }
+
+        let old_pic = pic::Pic::new(&self.rom[*offset..])?;
+
+        let pic_data = match encoding_method {
+            Some(encoding_method) => pic.encode(encoding_method),
+            None => pic.encode_smallest(),
+        };
+
+        if pic_data.len() <= old_pic.bytes + 1 {
+            return Ok(PatchSet::from(Patch::new(offset, &pic_data)));
+        }
+
+        let new_offset = self.get_free_space(bank_id, &pic_data.len())?;
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&new_offset, &pic_data),
+            Patch::new(pointer_offset, &PkmnapiDB::offset_to_pointer(new_offset)),
+        ]))
+    }
+
     /// Pokémon internal max
     ///
     /// # Example
@@ -278,8 +471,9 @@ impl PkmnapiDB {
     /// assert_eq!(pokemon_internal_max, 190);
     /// ```
     pub fn pokemon_internal_max(&self) -> usize {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x1C;
-        let offset = offset_base + 0x1E5F;
+        let (bank_id, offset) = self.offsets.pokemon_internal_max;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         (self.rom[offset] as usize) - 1
     }
@@ -307,8 +501,9 @@ impl PkmnapiDB {
     /// assert_eq!(pokedex_id, 1);
     /// ```
     pub fn pokemon_name_to_pokedex_id(&self, pokemon_name: &PokemonName) -> Option<u8> {
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x07;
-        let offset = offset_base + 0x021E;
+        let (bank_id, offset) = self.offsets.pokemon_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
         let pokemon_internal_max = self.pokemon_internal_max();
 
         return (0..pokemon_internal_max)
@@ -353,8 +548,9 @@ impl PkmnapiDB {
             return Err(error::Error::PokedexIDInvalid(*pokedex_id));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x10;
-        let offset = offset_base + 0x1024;
+        let (bank_id, offset) = self.offsets.pokedex_order;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
         let pokemon_internal_max = self.pokemon_internal_max();
 
         let internal_id = match (&self.rom[offset..(offset + pokemon_internal_max)])
@@ -393,8 +589,9 @@ impl PkmnapiDB {
             return Err(error::Error::InternalIDInvalid(*internal_id));
         }
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x10;
-        let offset = (offset_base + 0x1024) + (*internal_id as usize);
+        let (bank_id, offset) = self.offsets.pokedex_order;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = (offset_base + offset) + (*internal_id as usize);
 
         Ok(self.rom[offset])
     }
@@ -489,8 +686,9 @@ impl PkmnapiDB {
     pub fn type_id_bounds(&self) -> (usize, usize) {
         let min_id = 0usize;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x08;
-        let pointer_base = offset_base + 0x7DAE;
+        let (bank_id, offset) = self.offsets.type_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer_base = offset_base + offset;
 
         let max_index = self.rom[pointer_base..]
             .iter()
@@ -557,8 +755,9 @@ impl PkmnapiDB {
     pub fn type_effect_id_bounds(&self) -> (usize, usize) {
         let min_id = 0usize;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0F;
-        let pointer = offset_base + 0x2474;
+        let (bank_id, offset) = self.offsets.type_effects;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let pointer = offset_base + offset;
 
         let max_index = self.rom[pointer..].iter().position(|&r| r == 0xFF).unwrap();
         let max_id = (((max_index as f32) / 3.0) as usize) - 1;
@@ -626,7 +825,8 @@ impl PkmnapiDB {
     pub fn trainer_id_bounds(&self) -> (usize, usize) {
         let min_id = 1usize;
 
-        let offset_base = (PkmnapiDB::ROM_PAGE * 0x0E) + 0x19FF;
+        let (bank_id, offset) = self.offsets.trainer_names;
+        let offset_base = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
 
         let max_offset = self.rom[offset_base..]
             .iter()
@@ -696,7 +896,7 @@ impl PkmnapiDB {
     pub fn hm_id_bounds(&self) -> (usize, usize) {
         let min_id = 1usize;
 
-        let offset_base = 0x3052;
+        let offset_base = self.offsets.hm_moves;
 
         let max_id = self.rom[offset_base..]
             .iter()
@@ -822,8 +1022,9 @@ impl PkmnapiDB {
     pub fn item_id_bounds(&self) -> (usize, usize) {
         let min_id = 1usize;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x01;
-        let offset_base = offset_base + 0x072B;
+        let (bank_id, offset) = self.offsets.item_names;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset_base = offset_base + offset;
 
         let max_offset = self.rom[offset_base..]
             .iter()
@@ -893,7 +1094,8 @@ impl PkmnapiDB {
     pub fn move_id_bounds(&self) -> (usize, usize) {
         let min_id = 1usize;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x0E;
+        let (bank_id, offset) = self.offsets.move_stats;
+        let offset_base = (PkmnapiDB::ROM_PAGE * bank_id) + offset;
 
         let max_index = self.rom[offset_base..]
             .chunks(2)
@@ -960,8 +1162,9 @@ impl PkmnapiDB {
     pub fn map_id_bounds(&self) -> (usize, usize) {
         let min_id = 0usize;
 
-        let offset_base = PkmnapiDB::ROM_PAGE * 0x03;
-        let offset = offset_base + 0x0EEB;
+        let (bank_id, offset) = self.offsets.wild_pokemon;
+        let offset_base = PkmnapiDB::ROM_PAGE * bank_id;
+        let offset = offset_base + offset;
 
         let max_id = self.rom[offset..]
             .chunks(2)
@@ -1086,7 +1289,7 @@ impl PkmnapiDB {
     /// assert_eq!((min_mart_id, max_mart_id), (0, 15));
     /// ```
     pub fn mart_id_bounds(&self) -> (usize, usize) {
-        let offset_base = 0x2442;
+        let offset_base = self.offsets.marts;
         let min_id = 0usize;
         let mut max_id = 0usize;
         let mut max_index = 0;
@@ -1202,4 +1405,274 @@ impl PkmnapiDB {
 
         Ok((min_id, max_id))
     }
+
+    /// Tileset ID bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let (min_tileset_id, max_tileset_id) = db.tileset_id_bounds();
+    ///
+    /// assert_eq!((min_tileset_id, max_tileset_id), (0, 23));
+    /// ```
+    pub fn tileset_id_bounds(&self) -> (usize, usize) {
+        let min_id = 0usize;
+        let max_id = 23usize;
+
+        (min_id, max_id)
+    }
+
+    /// Validate tileset ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let tileset_id = 0;
+    ///
+    /// db.tileset_id_validate(&tileset_id).unwrap();
+    ///
+    /// let tileset_id = 100;
+    ///
+    /// match db.tileset_id_validate(&tileset_id) {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(e) => assert_eq!(e, error::Error::TilesetIDInvalid(tileset_id, 0, 23))
+    /// };
+    /// ```
+    pub fn tileset_id_validate(&self, tileset_id: &u8) -> Result<(usize, usize)> {
+        let (min_id, max_id) = self.tileset_id_bounds();
+
+        if tileset_id > &(max_id as u8) {
+            return Err(error::Error::TilesetIDInvalid(*tileset_id, min_id, max_id));
+        }
+
+        Ok((min_id, max_id))
+    }
+
+    /// SGB palette ID bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let (min_sgb_palette_id, max_sgb_palette_id) = db.sgb_palette_id_bounds();
+    ///
+    /// assert_eq!((min_sgb_palette_id, max_sgb_palette_id), (0, 36));
+    /// ```
+    pub fn sgb_palette_id_bounds(&self) -> (usize, usize) {
+        let min_id = 0usize;
+        let max_id = 36usize;
+
+        (min_id, max_id)
+    }
+
+    /// Validate SGB palette ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let sgb_palette_id = 0;
+    ///
+    /// db.sgb_palette_id_validate(&sgb_palette_id).unwrap();
+    ///
+    /// let sgb_palette_id = 100;
+    ///
+    /// match db.sgb_palette_id_validate(&sgb_palette_id) {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(e) => assert_eq!(e, error::Error::SGBPaletteIDInvalid(sgb_palette_id, 0, 36))
+    /// };
+    /// ```
+    pub fn sgb_palette_id_validate(&self, sgb_palette_id: &u8) -> Result<(usize, usize)> {
+        let (min_id, max_id) = self.sgb_palette_id_bounds();
+
+        if sgb_palette_id > &(max_id as u8) {
+            return Err(error::Error::SGBPaletteIDInvalid(
+                *sgb_palette_id,
+                min_id,
+                max_id,
+            ));
+        }
+
+        Ok((min_id, max_id))
+    }
+
+    /// Town map location ID bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let (min_town_map_location_id, max_town_map_location_id) =
+    ///     db.town_map_location_id_bounds();
+    ///
+    /// assert_eq!(min_town_map_location_id, 0);
+    /// assert!(max_town_map_location_id > 0x25);
+    /// ```
+    pub fn town_map_location_id_bounds(&self) -> (usize, usize) {
+        let offset = self.get_town_map_internal_offset();
+        let internal_count = self.rom[offset..]
+            .iter()
+            .step_by(0x04)
+            .take_while(|&x| *x != 0xFF)
+            .count();
+        let min_id = 0usize;
+        let max_id = TOWN_MAP_EXTERNAL_COUNT + internal_count - 1;
+
+        (min_id, max_id)
+    }
+
+    /// Validate town map location ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let town_map_location_id = 0;
+    ///
+    /// db.town_map_location_id_validate(&town_map_location_id).unwrap();
+    ///
+    /// let town_map_location_id = 200;
+    ///
+    /// match db.town_map_location_id_validate(&town_map_location_id) {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(e) => match e {
+    ///         error::Error::TownMapLocationIDInvalid(id, 0, _) => assert_eq!(id, 200),
+    ///         _ => unreachable!(),
+    ///     },
+    /// };
+    /// ```
+    pub fn town_map_location_id_validate(
+        &self,
+        town_map_location_id: &u8,
+    ) -> Result<(usize, usize)> {
+        let (min_id, max_id) = self.town_map_location_id_bounds();
+
+        if town_map_location_id > &(max_id as u8) {
+            return Err(error::Error::TownMapLocationIDInvalid(
+                *town_map_location_id,
+                min_id,
+                max_id,
+            ));
+        }
+
+        Ok((min_id, max_id))
+    }
+
+    /// Static Pokémon ID bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let (min_static_pokemon_id, max_static_pokemon_id) = db.static_pokemon_id_bounds();
+    ///
+    /// assert_eq!(min_static_pokemon_id, 0);
+    /// assert!(max_static_pokemon_id > 0);
+    /// ```
+    pub fn static_pokemon_id_bounds(&self) -> (usize, usize) {
+        let min_id = 0usize;
+        let max_id = self.get_static_pokemon_locations().len() - 1;
+
+        (min_id, max_id)
+    }
+
+    /// Validate static Pokémon ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fs;
+    /// use pkmnapi_db::*;
+    /// use pkmnapi_db::error;
+    /// # use std::env;
+    /// # let rom_path = env::var("PKMN_ROM").expect("Set the PKMN_ROM environment variable to point to the ROM location");
+    ///
+    /// let rom = fs::read(rom_path).unwrap();
+    /// let db = PkmnapiDB::new(&rom).build().unwrap();
+    ///
+    /// let static_pokemon_id = 0;
+    ///
+    /// db.static_pokemon_id_validate(&static_pokemon_id).unwrap();
+    ///
+    /// let static_pokemon_id = 200;
+    ///
+    /// match db.static_pokemon_id_validate(&static_pokemon_id) {
+    ///     Ok(_) => unreachable!(),
+    ///     Err(e) => match e {
+    ///         error::Error::StaticPokemonIDInvalid(id, 0, _) => assert_eq!(id, 200),
+    ///         _ => unreachable!(),
+    ///     },
+    /// };
+    /// ```
+    pub fn static_pokemon_id_validate(&self, static_pokemon_id: &u8) -> Result<(usize, usize)> {
+        let (min_id, max_id) = self.static_pokemon_id_bounds();
+
+        if static_pokemon_id > &(max_id as u8) {
+            return Err(error::Error::StaticPokemonIDInvalid(
+                *static_pokemon_id,
+                min_id,
+                max_id,
+            ));
+        }
+
+        Ok((min_id, max_id))
+    }
 }
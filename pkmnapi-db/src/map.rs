@@ -18,7 +18,8 @@
 //! ```
 
 use crate::error::{self, Result};
-use image::{self, DynamicImage, ImageBuffer, ImageFormat, Luma};
+use crate::img::{png_to_shades, shades_to_img, Palette, PaletteOrder};
+use image::{self, ImageFormat};
 
 /// Representation of a map
 ///
@@ -88,18 +89,98 @@ impl Map {
         })
     }
 
-    fn to_img(&self, format: ImageFormat) -> Result<Vec<u8>> {
+    fn from(data: Vec<u8>, format: ImageFormat) -> Result<Self> {
+        let raw = match image::load_from_memory_with_format(&data, format) {
+            Ok(img) => img,
+            Err(_) => return Err(error::Error::MapCouldNotRead),
+        };
+
+        let img = match raw.as_luma8() {
+            Some(img) => img,
+            None => return Err(error::Error::MapCouldNotRead),
+        };
+
+        if img.width() % 32 != 0 || img.height() % 32 != 0 {
+            return Err(error::Error::MapWrongSize);
+        }
+
+        let width = img.width() / 8;
+        let height = img.height() / 8;
+        let pixels: Vec<u8> = img
+            .enumerate_pixels()
+            .map(|(_, _, pixel)| 3 - (((pixel.0[0] as f32) / 85.0).round() as u8))
+            .collect();
+
+        Ok(Map {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn from_png(data: Vec<u8>) -> Result<Self> {
+        Map::from_png_with_palette(data, &PaletteOrder::LUMINANCE)
+    }
+
+    /// Creates a map from a PNG with at most 4 colors
+    ///
+    /// Colors are mapped to shades by luminance or, for indexed PNGs, by
+    /// palette index.
+    pub fn from_png_with_palette(data: Vec<u8>, palette_order: &PaletteOrder) -> Result<Self> {
+        let (width, height, pixels) =
+            png_to_shades(&data, palette_order, error::Error::MapCouldNotRead)?;
+
+        if width % 32 != 0 || height % 32 != 0 {
+            return Err(error::Error::MapWrongSize);
+        }
+
+        Ok(Map {
+            width: width / 8,
+            height: height / 8,
+            pixels,
+        })
+    }
+
+    /// Creates a map from an 8-bit grayscale JPEG
+    ///
+    /// Each pixel is rounded to the nearest of the 4 shades, so compression
+    /// noise does not stop blocks from matching their tileset's blocks.
+    pub fn from_jpeg(data: Vec<u8>) -> Result<Self> {
+        Map::from(data, ImageFormat::Jpeg)
+    }
+
+    /// Pixels of the 32x32 block at block coordinates x, y
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::map::*;
+    ///
+    /// let map = Map::new(&0x02, &0x01, &vec![vec![0x01; 0x40]; 0x20]).unwrap();
+    ///
+    /// assert_eq!(map.block_pixels(1, 0), vec![0x01; 0x400]);
+    /// ```
+    pub fn block_pixels(&self, x: u32, y: u32) -> Vec<u8> {
+        let stride = self.width * 8;
+
+        (0..32)
+            .map(|pixel_y| {
+                let offset = (((y * 32) + pixel_y) * stride) + (x * 32);
+
+                self.pixels[(offset as usize)..((offset + 32) as usize)].to_vec()
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn to_img(&self, format: ImageFormat, palette: Option<&Palette>) -> Result<Vec<u8>> {
         let width = self.width * 8;
         let height = self.height * 8;
 
-        let img = ImageBuffer::from_fn(width, height, |x, y| {
-            let i = x + (y * width);
-            let pixel = (3 - self.pixels[i as usize]) * 0x55;
-
-            Luma([pixel])
+        let img = shades_to_img(width, height, palette, |x, y| {
+            self.pixels[(x + (y * width)) as usize]
         });
 
-        let img = DynamicImage::ImageLuma8(img);
         let mut buf = Vec::new();
 
         match img.write_to(&mut buf, format) {
@@ -111,10 +192,15 @@ impl Map {
     }
 
     pub fn to_png(&self) -> Result<Vec<u8>> {
-        self.to_img(ImageFormat::Png)
+        self.to_img(ImageFormat::Png, None)
+    }
+
+    /// Renders the map as an RGB PNG, one palette color per shade
+    pub fn to_png_with_palette(&self, palette: &Palette) -> Result<Vec<u8>> {
+        self.to_img(ImageFormat::Png, Some(palette))
     }
 
     pub fn to_jpeg(&self) -> Result<Vec<u8>> {
-        self.to_img(ImageFormat::Jpeg)
+        self.to_img(ImageFormat::Jpeg, None)
     }
 }
@@ -0,0 +1,163 @@
+use crate::header::Game;
+
+/// ROM locations of one game version
+///
+/// Banked locations are `(bank, offset)` pairs, read as
+/// `PkmnapiDB::ROM_PAGE * bank + offset` like the rest of the crate.
+/// Supporting a new version only needs a new table. Saves are not covered,
+/// as Red, Blue and Yellow share one save layout.
+#[derive(Debug)]
+pub(crate) struct Offsets {
+    /// Pointer to `wcf91`, which holds the species shown by `DisplayPokedex`
+    pub cf91_pointer: u16,
+    /// Pointer to `wCurEnemyLVL`
+    pub cur_enemy_level_pointer: u16,
+    /// Pointer to `wCurOpponent`
+    pub cur_opponent_pointer: u16,
+    pub game_boy_img: (usize, usize),
+    pub game_boy_tiles: (usize, usize),
+    /// Pointer to `GivePokemon` in bank 0
+    pub give_pokemon_pointer: u16,
+    /// Map IDs with hidden objects, followed by their table pointers
+    pub hidden_objects: (usize, usize),
+    pub hm_moves: usize,
+    pub hm_name: usize,
+    pub icon: (usize, usize),
+    /// Poké Ball icon tiles, which are not part of the icon table
+    pub icon_ball: (usize, usize),
+    pub item_names: (usize, usize),
+    pub map_header_banks: (usize, usize),
+    pub map_header_pointers: usize,
+    pub marts: usize,
+    /// Mew's base stats, which are kept apart from the others
+    pub mew_stats: usize,
+    pub monster_palettes: (usize, usize),
+    pub move_names: (usize, usize),
+    pub move_stats: (usize, usize),
+    pub player_names: (usize, usize),
+    /// Second copy of the player names, as shown in the naming menu
+    pub player_names_menu: (usize, usize),
+    pub pokedex_entries: (usize, usize),
+    pub pokedex_order: (usize, usize),
+    /// Pointer to `wPokedexOwned`, followed by 19 bytes of flags
+    pub pokedex_owned_pointer: u16,
+    pub pokemon_cries: (usize, usize),
+    /// Evolutions and learnsets share one pointer table
+    pub pokemon_evolutions: (usize, usize),
+    pub pokemon_icons: (usize, usize),
+    pub pokemon_internal_max: (usize, usize),
+    pub pokemon_logo_img: (usize, usize),
+    pub pokemon_names: (usize, usize),
+    /// Code in bank 0 choosing the bank of each Pokémon pic
+    pub pokemon_pic_banks: usize,
+    pub pokemon_stats: (usize, usize),
+    pub pokemon_title: (usize, usize),
+    /// Trainer IDs of the rival's three trainer classes
+    pub rival_trainer_ids: [u8; 3],
+    pub sfx_headers: (usize, usize),
+    pub sgb_palettes: (usize, usize),
+    /// Offsets of every reference to each starter
+    ///
+    /// The first offset of each starter is the Pokémon given to the player by
+    /// its Poké Ball in Oak's lab. The others are the Pokémon given to the
+    /// rival and the rival's checks of its starter, e.g. to choose its party.
+    pub starters: [&'static [usize]; 3],
+    pub tileset_headers: usize,
+    pub tm_moves: (usize, usize),
+    pub tm_name: usize,
+    pub tm_prices: (usize, usize),
+    /// Town map tiles, used when the pointer to them can't be found
+    pub town_map: (usize, usize),
+    pub town_map_external_locations: (usize, usize),
+    pub town_map_internal_locations: (usize, usize),
+    pub town_map_tiles: (usize, usize),
+    pub trades: (usize, usize),
+    pub trainer_names: (usize, usize),
+    pub trainer_parties: (usize, usize),
+    /// Bank of the trainer pics
+    pub trainer_pic_bank: usize,
+    /// Trainer pic pointers and rewards share one table
+    pub trainer_pics: (usize, usize),
+    pub type_effects: (usize, usize),
+    pub type_names: (usize, usize),
+    pub wild_pokemon: (usize, usize),
+}
+
+impl Offsets {
+    /// Offset table of a game version
+    ///
+    /// Returns `None` for versions whose ROM locations are not known yet.
+    pub fn from_game(game: &Game) -> Option<&'static Offsets> {
+        match game {
+            Game::RED | Game::BLUE => Some(&RED_BLUE),
+            Game::YELLOW => None,
+        }
+    }
+}
+
+/// Red and Blue share every ROM location
+const RED_BLUE: Offsets = Offsets {
+    cf91_pointer: 0xCF91,
+    cur_enemy_level_pointer: 0xD127,
+    cur_opponent_pointer: 0xD059,
+    game_boy_img: (0x1E, 0x1C20),
+    game_boy_tiles: (0x0E, 0x29BE),
+    give_pokemon_pointer: 0x3E48,
+    hidden_objects: (0x11, 0x2A40),
+    hm_moves: 0x3052,
+    hm_name: 0x303E,
+    icon: (0x1C, 0x17C0),
+    icon_ball: (0x04, 0x1180),
+    item_names: (0x01, 0x072B),
+    map_header_banks: (0x03, 0x023D),
+    map_header_pointers: 0x01AE,
+    marts: 0x2442,
+    mew_stats: 0x425B,
+    monster_palettes: (0x1C, 0x25C8),
+    move_names: (0x2C, 0x0000),
+    move_stats: (0x0E, 0x0000),
+    player_names: (0x01, 0x2AA8),
+    player_names_menu: (0x01, 0x2AF2),
+    pokedex_entries: (0x0F, 0x447E),
+    pokedex_order: (0x10, 0x1024),
+    pokedex_owned_pointer: 0xD2F7,
+    pokemon_cries: (0x0E, 0x1446),
+    pokemon_evolutions: (0x0E, 0x305C),
+    pokemon_icons: (0x1C, 0x190D),
+    pokemon_internal_max: (0x1C, 0x1E5F),
+    pokemon_logo_img: (0x04, 0x1380),
+    pokemon_names: (0x07, 0x021E),
+    pokemon_pic_banks: 0x163A,
+    pokemon_stats: (0x0E, 0x03DE),
+    pokemon_title: (0x01, 0x0588),
+    rival_trainer_ids: [0x19, 0x2A, 0x2B],
+    sfx_headers: (0x02, 0x003C),
+    sgb_palettes: (0x1C, 0x2660),
+    starters: [
+        &[0x1D10E, 0x1D126, 0x1CC84, 0x39CF8, 0x50FB3, 0x510DD],
+        &[
+            0x1D11F, 0x1D104, 0x19591, 0x1CC88, 0x1CDC8, 0x50FAF, 0x510D9, 0x51CAF, 0x6060E,
+            0x61450, 0x75F9E,
+        ],
+        &[
+            0x1D130, 0x1D115, 0x19599, 0x1CDD0, 0x39CF2, 0x50FB1, 0x510DB, 0x51CB7, 0x60616,
+            0x61458, 0x75FA6,
+        ],
+    ],
+    tileset_headers: 0xC7BE,
+    tm_moves: (0x04, 0x3773),
+    tm_name: 0x303C,
+    tm_prices: (0x1E, 0x3FA7),
+    town_map: (0x1C, 0x1100),
+    town_map_external_locations: (0x1C, 0x1382),
+    town_map_internal_locations: (0x1C, 0x13F1),
+    town_map_tiles: (0x04, 0x25A8),
+    trades: (0x1C, 0x1B7B),
+    trainer_names: (0x0E, 0x19FF),
+    trainer_parties: (0x0E, 0x1D3B),
+    trainer_pic_bank: 0x13,
+    trainer_pics: (0x0E, 0x1914),
+    type_effects: (0x0F, 0x2474),
+    type_names: (0x08, 0x7DAE),
+    wild_pokemon: (0x03, 0x0EEB),
+};
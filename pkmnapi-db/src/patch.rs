@@ -12,7 +12,9 @@
 //! assert_eq!(patch.data, vec![0x13, 0x37]);
 //! ```
 
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use crate::error::{self, Result};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::cmp;
 use std::io::Cursor;
 
 /// Representation of a ROM patch (IPS)
@@ -112,3 +114,618 @@ impl From<Vec<u8>> for Patch {
         }
     }
 }
+
+/// Ordered set of non-contiguous ROM patches applied as a single change
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::patch::*;
+///
+/// let patch_set = PatchSet::new(&vec![
+///     Patch::new(&0x00, &vec![0x13, 0x37]),
+///     Patch::new(&0x10, &vec![0x42]),
+/// ])
+/// .with_description("Example");
+///
+/// assert_eq!(patch_set.patches.len(), 2);
+/// assert_eq!(patch_set.description, Some("Example".to_owned()));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct PatchSet {
+    pub description: Option<String>,
+    pub patches: Vec<Patch>,
+}
+
+impl PatchSet {
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::new(&vec![Patch::new(&0x00, &vec![0x13, 0x37])]);
+    ///
+    /// assert_eq!(patch_set.description, None);
+    /// assert_eq!(patch_set.patches, vec![Patch::new(&0x00, &vec![0x13, 0x37])]);
+    /// ```
+    pub fn new(patches: &Vec<Patch>) -> PatchSet {
+        let patches = patches
+            .iter()
+            .map(|patch| Patch::new(&patch.offset, &patch.data))
+            .collect();
+
+        PatchSet {
+            description: None,
+            patches,
+        }
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::new(&vec![]).with_description("Example");
+    ///
+    /// assert_eq!(patch_set.description, Some("Example".to_owned()));
+    /// ```
+    pub fn with_description<S: Into<String>>(self, description: S) -> PatchSet {
+        PatchSet {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let mut patch_set = PatchSet::new(&vec![]);
+    ///
+    /// patch_set.push(Patch::new(&0x00, &vec![0x13, 0x37]));
+    ///
+    /// assert_eq!(patch_set.patches, vec![Patch::new(&0x00, &vec![0x13, 0x37])]);
+    /// ```
+    pub fn push(&mut self, patch: Patch) {
+        self.patches.push(patch);
+    }
+
+    /// Parse an IPS file, including RLE records
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::from_ips(&vec![
+    ///     0x50, 0x41, 0x54, 0x43, 0x48, // PATCH
+    ///     0x00, 0x00, 0x10, 0x00, 0x02, 0x13, 0x37, // DATA
+    ///     0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x03, 0xFF, // RLE
+    ///     0x45, 0x4F, 0x46, // EOF
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     patch_set.patches,
+    ///     vec![
+    ///         Patch::new(&0x10, &vec![0x13, 0x37]),
+    ///         Patch::new(&0x20, &vec![0xFF, 0xFF, 0xFF]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_ips(raw: &Vec<u8>) -> Result<PatchSet> {
+        if raw.get(..5) != Some(&b"PATCH"[..]) {
+            return Err(error::Error::PatchCouldNotRead);
+        }
+
+        let mut patches = vec![];
+        let mut i = 5;
+
+        loop {
+            let record = raw.get(i..(i + 3)).ok_or(error::Error::PatchCouldNotRead)?;
+
+            if record == &b"EOF"[..] {
+                break;
+            }
+
+            let mut cursor = Cursor::new(&raw[i..]);
+
+            let offset = cursor
+                .read_u24::<BigEndian>()
+                .or(Err(error::Error::PatchCouldNotRead))? as usize;
+            let length = cursor
+                .read_u16::<BigEndian>()
+                .or(Err(error::Error::PatchCouldNotRead))? as usize;
+
+            let (data, record_length) = if length == 0 {
+                let rle_length = cursor
+                    .read_u16::<BigEndian>()
+                    .or(Err(error::Error::PatchCouldNotRead))?
+                    as usize;
+                let value = cursor.read_u8().or(Err(error::Error::PatchCouldNotRead))?;
+
+                (vec![value; rle_length], 8)
+            } else {
+                let data = raw
+                    .get((i + 5)..(i + 5 + length))
+                    .ok_or(error::Error::PatchCouldNotRead)?
+                    .to_vec();
+
+                (data, 5 + length)
+            };
+
+            patches.push(Patch::new(&offset, &data));
+
+            i += record_length;
+        }
+
+        Ok(PatchSet::from(patches))
+    }
+
+    /// Build the patches that turn `source` into `target`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::from_diff(
+    ///     &vec![0x00, 0x01, 0x02, 0x03, 0x04],
+    ///     &vec![0x00, 0xFF, 0xFF, 0x03, 0xFF],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     patch_set.patches,
+    ///     vec![
+    ///         Patch::new(&0x01, &vec![0xFF, 0xFF]),
+    ///         Patch::new(&0x04, &vec![0xFF]),
+    ///     ]
+    /// );
+    /// ```
+    pub fn from_diff(source: &Vec<u8>, target: &Vec<u8>) -> PatchSet {
+        let mut patches = vec![];
+        let mut i = 0;
+
+        while i < target.len() {
+            if source.get(i) == Some(&target[i]) {
+                i += 1;
+
+                continue;
+            }
+
+            let length = (i..target.len())
+                .take_while(|&j| source.get(j) != Some(&target[j]))
+                .take(0xFFFF)
+                .count();
+
+            patches.push(Patch::new(&i, &target[i..(i + length)].to_vec()));
+
+            i += length;
+        }
+
+        PatchSet::from(patches)
+    }
+
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::new(&vec![
+    ///     Patch::new(&0x123456, &vec![0x13, 0x37]),
+    ///     Patch::new(&0x00, &vec![0x42]),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     patch_set.to_raw(),
+    ///     vec![0x12, 0x34, 0x56, 0x00, 0x02, 0x13, 0x37, 0x00, 0x00, 0x00, 0x00, 0x01, 0x42]
+    /// );
+    /// ```
+    pub fn to_raw(&self) -> Vec<u8> {
+        self.patches
+            .iter()
+            .map(|patch| patch.to_raw())
+            .flatten()
+            .collect()
+    }
+}
+
+impl From<Patch> for PatchSet {
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::from(Patch::new(&0x00, &vec![0x13, 0x37]));
+    ///
+    /// assert_eq!(patch_set.patches, vec![Patch::new(&0x00, &vec![0x13, 0x37])]);
+    /// ```
+    fn from(patch: Patch) -> Self {
+        PatchSet {
+            description: None,
+            patches: vec![patch],
+        }
+    }
+}
+
+impl From<Vec<Patch>> for PatchSet {
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::from(vec![Patch::new(&0x00, &vec![0x13, 0x37])]);
+    ///
+    /// assert_eq!(patch_set.patches, vec![Patch::new(&0x00, &vec![0x13, 0x37])]);
+    /// ```
+    fn from(patches: Vec<Patch>) -> Self {
+        PatchSet {
+            description: None,
+            patches,
+        }
+    }
+}
+
+impl From<Vec<u8>> for PatchSet {
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let patch_set = PatchSet::from(vec![
+    ///     0x12, 0x34, 0x56, 0x00, 0x02, 0x13, 0x37, 0x00, 0x00, 0x00, 0x00, 0x01, 0x42,
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     patch_set.patches,
+    ///     vec![
+    ///         Patch::new(&0x123456, &vec![0x13, 0x37]),
+    ///         Patch::new(&0x00, &vec![0x42]),
+    ///     ]
+    /// );
+    /// ```
+    fn from(patch_set: Vec<u8>) -> Self {
+        let mut patches = vec![];
+        let mut i = 0;
+
+        while i + 5 <= patch_set.len() {
+            let mut cursor = Cursor::new(&patch_set[i..]);
+
+            let offset = cursor.read_u24::<BigEndian>().unwrap_or(0) as usize;
+            let length = cursor.read_u16::<BigEndian>().unwrap_or(0) as usize;
+            let data_end = (i + 5 + length).min(patch_set.len());
+
+            patches.push(Patch::new(&offset, &patch_set[(i + 5)..data_end].to_vec()));
+
+            i = data_end;
+        }
+
+        PatchSet {
+            description: None,
+            patches,
+        }
+    }
+}
+
+/// BPS patch from a source ROM to a target ROM
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::patch::*;
+///
+/// let bps_patch = BPSPatch::new(&vec![0x00, 0x01, 0x02, 0x03], &vec![0x00, 0xFF, 0x02, 0x03]);
+///
+/// assert_eq!(
+///     bps_patch.to_raw(),
+///     vec![
+///         0x42, 0x50, 0x53, 0x31, 0x84, 0x84, 0x80, 0x80, 0x81, 0xFF, 0x84, 0x13, 0x86, 0xB9,
+///         0x8B, 0xC9, 0xD8, 0xE3, 0x34, 0xCF, 0x14, 0x46, 0xFD
+///     ]
+/// );
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct BPSPatch {
+    pub source: Vec<u8>,
+    pub target: Vec<u8>,
+}
+
+impl BPSPatch {
+    const SOURCE_READ: usize = 0x00;
+    const TARGET_READ: usize = 0x01;
+    const SOURCE_COPY: usize = 0x02;
+
+    pub fn new(source: &Vec<u8>, target: &Vec<u8>) -> BPSPatch {
+        BPSPatch {
+            source: source.to_vec(),
+            target: target.to_vec(),
+        }
+    }
+
+    /// Decode a `BPS1` patch against its source
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    ///
+    /// let source = vec![0x00, 0x01, 0x02, 0x03];
+    /// let target = vec![0x00, 0xFF, 0x02, 0x03];
+    /// let raw = BPSPatch::new(&source, &target).to_raw();
+    ///
+    /// let bps_patch = BPSPatch::from_raw(&source, &raw).unwrap();
+    ///
+    /// assert_eq!(bps_patch.target, target);
+    /// ```
+    pub fn from_raw(source: &Vec<u8>, raw: &Vec<u8>) -> Result<BPSPatch> {
+        if raw.len() < 16 || raw[..4] != b"BPS1"[..] {
+            return Err(error::Error::PatchCouldNotRead);
+        }
+
+        let footer = raw.len() - 12;
+        let mut cursor = Cursor::new(&raw[footer..]);
+
+        let source_checksum = cursor.read_u32::<LittleEndian>().unwrap_or(0);
+        let target_checksum = cursor.read_u32::<LittleEndian>().unwrap_or(0);
+        let patch_checksum = cursor.read_u32::<LittleEndian>().unwrap_or(0);
+
+        if crc32fast::hash(&raw[..(footer + 8)]) != patch_checksum
+            || crc32fast::hash(source) != source_checksum
+        {
+            return Err(error::Error::PatchChecksumMismatch);
+        }
+
+        let mut i = 4;
+        let source_size = decode_number(raw, &mut i)?;
+        let target_size = decode_number(raw, &mut i)?;
+        let metadata_size = decode_number(raw, &mut i)?;
+
+        if source_size != source.len() {
+            return Err(error::Error::PatchChecksumMismatch);
+        }
+
+        i += metadata_size;
+
+        let mut target: Vec<u8> = vec![];
+        let mut source_relative = 0;
+        let mut target_relative = 0;
+
+        while i < footer {
+            let data = decode_number(raw, &mut i)?;
+            let length = (data >> 2) + 1;
+
+            let bytes = match data & 0x03 {
+                BPSPatch::SOURCE_READ => source.get(target.len()..(target.len() + length)),
+                BPSPatch::TARGET_READ => {
+                    i += length;
+
+                    raw.get((i - length)..i)
+                }
+                BPSPatch::SOURCE_COPY => {
+                    source_relative = decode_relative(raw, &mut i, source_relative)?;
+                    source_relative += length;
+
+                    source.get((source_relative - length)..source_relative)
+                }
+                _ => {
+                    target_relative = decode_relative(raw, &mut i, target_relative)?;
+
+                    for _ in 0..length {
+                        let byte = *target
+                            .get(target_relative)
+                            .ok_or(error::Error::PatchCouldNotRead)?;
+
+                        target.push(byte);
+                        target_relative += 1;
+                    }
+
+                    continue;
+                }
+            };
+
+            target.extend(bytes.ok_or(error::Error::PatchCouldNotRead)?);
+        }
+
+        if target.len() != target_size || crc32fast::hash(&target) != target_checksum {
+            return Err(error::Error::PatchChecksumMismatch);
+        }
+
+        Ok(BPSPatch::new(source, &target))
+    }
+
+    /// Build the patches that turn the source into the target
+    pub fn to_patch_set(&self) -> PatchSet {
+        PatchSet::from_diff(&self.source, &self.target)
+    }
+
+    /// Encode the patch as `BPS1`
+    ///
+    /// Runs of bytes equal to the source are encoded as source reads and
+    /// everything else as target reads.
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut raw = "BPS1".chars().map(|c| c as u8).collect::<Vec<u8>>();
+
+        raw.extend(encode_number(self.source.len()));
+        raw.extend(encode_number(self.target.len()));
+        raw.extend(encode_number(0));
+
+        let unchanged = |i: usize| i < self.source.len() && self.source[i] == self.target[i];
+        let mut i = 0;
+
+        while i < self.target.len() {
+            let is_unchanged = unchanged(i);
+            let length = (i..self.target.len())
+                .take_while(|&j| unchanged(j) == is_unchanged)
+                .count();
+
+            if is_unchanged {
+                raw.extend(encode_number(((length - 1) << 2) | BPSPatch::SOURCE_READ));
+            } else {
+                raw.extend(encode_number(((length - 1) << 2) | BPSPatch::TARGET_READ));
+                raw.extend(&self.target[i..(i + length)]);
+            }
+
+            i += length;
+        }
+
+        append_checksums(raw, &self.source, &self.target)
+    }
+}
+
+/// UPS patch from a source ROM to a target ROM
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::patch::*;
+///
+/// let ups_patch = UPSPatch::new(&vec![0x00, 0x01, 0x02, 0x03], &vec![0x00, 0xFF, 0x02, 0x03]);
+///
+/// assert_eq!(
+///     ups_patch.to_raw(),
+///     vec![
+///         0x55, 0x50, 0x53, 0x31, 0x84, 0x84, 0x81, 0xFE, 0x00, 0x13, 0x86, 0xB9, 0x8B, 0xC9,
+///         0xD8, 0xE3, 0x34, 0x9E, 0xF4, 0xF3, 0x93
+///     ]
+/// );
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct UPSPatch {
+    pub source: Vec<u8>,
+    pub target: Vec<u8>,
+}
+
+impl UPSPatch {
+    pub fn new(source: &Vec<u8>, target: &Vec<u8>) -> UPSPatch {
+        UPSPatch {
+            source: source.to_vec(),
+            target: target.to_vec(),
+        }
+    }
+
+    /// Encode the patch as `UPS1`
+    ///
+    /// Each hunk is a relative offset followed by the XOR of the source and
+    /// target bytes, terminated by 0x00.
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut raw = "UPS1".chars().map(|c| c as u8).collect::<Vec<u8>>();
+
+        raw.extend(encode_number(self.source.len()));
+        raw.extend(encode_number(self.target.len()));
+
+        let length = cmp::max(self.source.len(), self.target.len());
+        let xor =
+            |i: usize| self.source.get(i).unwrap_or(&0x00) ^ self.target.get(i).unwrap_or(&0x00);
+        let mut relative = 0;
+        let mut i = 0;
+
+        while i < length {
+            if xor(i) == 0x00 {
+                relative += 1;
+                i += 1;
+
+                continue;
+            }
+
+            raw.extend(encode_number(relative));
+
+            loop {
+                let x = xor(i);
+
+                raw.push(x);
+                i += 1;
+
+                if x == 0x00 {
+                    break;
+                }
+            }
+
+            relative = 0;
+        }
+
+        append_checksums(raw, &self.source, &self.target)
+    }
+}
+
+/// Variable-length number encoding shared by BPS and UPS
+fn encode_number(number: usize) -> Vec<u8> {
+    let mut number = number;
+    let mut raw = vec![];
+
+    loop {
+        let x = (number & 0x7F) as u8;
+
+        number >>= 7;
+
+        if number == 0 {
+            raw.push(0x80 | x);
+
+            break;
+        }
+
+        raw.push(x);
+        number -= 1;
+    }
+
+    raw
+}
+
+/// Variable-length number decoding shared by BPS and UPS
+fn decode_number(raw: &Vec<u8>, i: &mut usize) -> Result<usize> {
+    let mut number: usize = 0;
+    let mut shift: usize = 1;
+
+    loop {
+        let x = *raw.get(*i).ok_or(error::Error::PatchCouldNotRead)? as usize;
+
+        *i += 1;
+
+        number = (x & 0x7F)
+            .checked_mul(shift)
+            .and_then(|x| number.checked_add(x))
+            .ok_or(error::Error::PatchCouldNotRead)?;
+
+        if x & 0x80 != 0x00 {
+            break;
+        }
+
+        shift = shift
+            .checked_mul(0x80)
+            .ok_or(error::Error::PatchCouldNotRead)?;
+        number = number
+            .checked_add(shift)
+            .ok_or(error::Error::PatchCouldNotRead)?;
+    }
+
+    Ok(number)
+}
+
+/// Decode a signed BPS copy offset relative to `offset`
+fn decode_relative(raw: &Vec<u8>, i: &mut usize, offset: usize) -> Result<usize> {
+    let data = decode_number(raw, i)?;
+    let relative = data >> 1;
+
+    let offset = if data & 0x01 == 0x01 {
+        offset.checked_sub(relative)
+    } else {
+        offset.checked_add(relative)
+    };
+
+    offset.ok_or(error::Error::PatchCouldNotRead)
+}
+
+fn append_checksums(raw: Vec<u8>, source: &Vec<u8>, target: &Vec<u8>) -> Vec<u8> {
+    let mut raw = raw;
+
+    raw.write_u32::<LittleEndian>(crc32fast::hash(source))
+        .unwrap();
+    raw.write_u32::<LittleEndian>(crc32fast::hash(target))
+        .unwrap();
+
+    let patch_checksum = crc32fast::hash(&raw);
+
+    raw.write_u32::<LittleEndian>(patch_checksum).unwrap();
+
+    raw
+}
@@ -26,9 +26,10 @@ mod encoding_method;
 pub use encoding_method::EncodingMethod as PicEncodingMethod;
 
 use crate::error::{self, Result};
+use crate::img::{png_to_shades, shades_to_img, Palette, PaletteOrder};
 use bitplane::*;
 use bitstream::*;
-use image::{self, DynamicImage, ImageBuffer, ImageFormat, Luma};
+use image::{self, ImageFormat};
 
 /// Representation of an image
 ///
@@ -217,6 +218,34 @@ impl Pic {
         data
     }
 
+    /// Encodes the pic with every encoding method and primary buffer,
+    /// keeping the smallest result
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::pic::*;
+    ///
+    /// let pic = Pic::new(&[0x55]).unwrap();
+    ///
+    /// let data = pic.encode_smallest();
+    ///
+    /// assert!(data.len() <= pic.encode(PicEncodingMethod::ONE(0x00)).len());
+    /// assert_eq!(Pic::new(&data).unwrap().pixels, pic.pixels);
+    /// ```
+    pub fn encode_smallest(&self) -> Vec<u8> {
+        (0x01..=0x03)
+            .map(|encoding_method| {
+                (0x00..=0x01).map(move |primary_buffer| {
+                    PicEncodingMethod::from(encoding_method, primary_buffer)
+                })
+            })
+            .flatten()
+            .map(|encoding_method| self.encode(encoding_method))
+            .min_by_key(|data| data.len())
+            .unwrap()
+    }
+
     fn from(data: Vec<u8>, format: ImageFormat) -> Result<Self> {
         let raw = match image::load_from_memory_with_format(&data, format) {
             Ok(img) => img,
@@ -255,7 +284,64 @@ impl Pic {
     }
 
     pub fn from_png(data: Vec<u8>) -> Result<Self> {
-        Pic::from(data, ImageFormat::Png)
+        Pic::from_png_with_palette(data, &PaletteOrder::LUMINANCE)
+    }
+
+    /// Creates a pic from a PNG with at most 4 colors
+    ///
+    /// Colors are mapped to shades by luminance or, for indexed PNGs, by
+    /// palette index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::error::*;
+    /// use pkmnapi_db::img::*;
+    /// use pkmnapi_db::pic::*;
+    /// use image::{ImageBuffer, ImageFormat, Rgb};
+    ///
+    /// let colors = [[0xFF, 0xFF, 0xFF], [0xF8, 0xB8, 0x88], [0x80, 0x40, 0x20], [0x00, 0x00, 0x00]];
+    /// let img = ImageBuffer::from_fn(8, 8, |x, _| Rgb(colors[(x as usize) % 4]));
+    ///
+    /// let mut png = vec![];
+    /// image::DynamicImage::ImageRgb8(img.clone())
+    ///     .write_to(&mut png, ImageFormat::Png)
+    ///     .unwrap();
+    ///
+    /// let pic = Pic::from_png_with_palette(png, &PaletteOrder::LUMINANCE).unwrap();
+    ///
+    /// assert_eq!(pic.pixels, vec![0x00, 0x01, 0x02, 0x03].repeat(0x10));
+    ///
+    /// let mut img = img;
+    /// img.put_pixel(3, 5, Rgb([0x00, 0x00, 0xFF]));
+    ///
+    /// let mut png = vec![];
+    /// image::DynamicImage::ImageRgb8(img)
+    ///     .write_to(&mut png, ImageFormat::Png)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     Pic::from_png_with_palette(png, &PaletteOrder::LUMINANCE),
+    ///     Err(Error::PaletteTooManyColors(5, vec![(3, 5)]))
+    /// );
+    /// ```
+    pub fn from_png_with_palette(data: Vec<u8>, palette_order: &PaletteOrder) -> Result<Self> {
+        let (width, height, pixels) =
+            png_to_shades(&data, palette_order, error::Error::PicCouldNotRead)?;
+
+        if width % 8 != 0 || height % 8 != 0 {
+            return Err(error::Error::PicWrongSize);
+        }
+
+        let encoding_method = PicEncodingMethod::from(0x01, 0x00);
+
+        Ok(Pic {
+            width: (width / 8) as u8,
+            height: (height / 8) as u8,
+            pixels,
+            bytes: 0,
+            encoding_method,
+        })
     }
 
     pub fn from_jpeg(data: Vec<u8>) -> Result<Self> {
@@ -408,11 +494,16 @@ impl Pic {
         output
     }
 
-    fn to_img(&self, format: ImageFormat, mirror: bool) -> Result<Vec<u8>> {
+    fn to_img(
+        &self,
+        format: ImageFormat,
+        mirror: bool,
+        palette: Option<&Palette>,
+    ) -> Result<Vec<u8>> {
         let width = self.width as u32 * 8;
         let height = self.height as u32 * 8;
 
-        let img = ImageBuffer::from_fn(width, height, |x, y| {
+        let img = shades_to_img(width, height, palette, |x, y| {
             let i = (y * width) + {
                 if mirror {
                     width - x - 1
@@ -420,12 +511,10 @@ impl Pic {
                     x
                 }
             };
-            let pixel = (3 - self.pixels[i as usize]) * 0x55;
 
-            Luma([pixel])
+            self.pixels[i as usize]
         });
 
-        let img = DynamicImage::ImageLuma8(img);
         let mut buf = Vec::new();
 
         match img.write_to(&mut buf, format) {
@@ -437,10 +526,15 @@ impl Pic {
     }
 
     pub fn to_png(&self, mirror: bool) -> Result<Vec<u8>> {
-        self.to_img(ImageFormat::Png, mirror)
+        self.to_img(ImageFormat::Png, mirror, None)
+    }
+
+    /// Renders the pic as an RGB PNG, one palette color per shade
+    pub fn to_png_with_palette(&self, mirror: bool, palette: &Palette) -> Result<Vec<u8>> {
+        self.to_img(ImageFormat::Png, mirror, Some(palette))
     }
 
     pub fn to_jpeg(&self, mirror: bool) -> Result<Vec<u8>> {
-        self.to_img(ImageFormat::Jpeg, mirror)
+        self.to_img(ImageFormat::Jpeg, mirror, None)
     }
 }
@@ -0,0 +1,674 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::sav::{Sav, SavePartyPokemon};
+use crate::string::*;
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use std::io::Cursor;
+
+impl Sav {
+    /// Get save box Pokémon by box ID
+    ///
+    /// The current box is read from its working copy in bank 1. All other
+    /// boxes are read from SRAM banks 2 (boxes 0-5) and 3 (boxes 6-11).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    /// use pkmnapi_db::string::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let mut sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// let box_pokemon = vec![SaveBoxPokemon {
+    ///     internal_id: 0x98,
+    ///     current_hp: 20,
+    ///     level: 5,
+    ///     status: 0x00,
+    ///     type_ids: vec![0x16, 0x03],
+    ///     catch_rate: 45,
+    ///     moves: vec![SavePokemonMove {
+    ///         move_id: 0x21,
+    ///         pp: 35,
+    ///         pp_up: 0,
+    ///     }],
+    ///     ot_id: 666,
+    ///     exp: 135,
+    ///     stat_exp: SavePokemonStats::default(),
+    ///     dvs: SavePokemonDVs::default(),
+    ///     nickname: ROMString::from("BULBASAUR"),
+    ///     ot_name: ROMString::from("RED"),
+    /// }];
+    ///
+    /// let patch_set = sav.set_box_pokemon(&7, &box_pokemon).unwrap();
+    ///
+    /// sav.apply_patch(patch_set).unwrap();
+    ///
+    /// assert_eq!(sav.get_box_pokemon(&7).unwrap(), box_pokemon);
+    /// ```
+    pub fn get_box_pokemon(&self, box_id: &u8) -> Result<Vec<SaveBoxPokemon>> {
+        let offset = self.get_box_offset(box_id)?;
+        let max_len = 20;
+
+        let box_pokemon = self
+            .get_pokemon_list(offset, max_len, 0x21)
+            .into_iter()
+            .map(|(data, ot_name, nickname)| {
+                let mut box_pokemon = SaveBoxPokemon::from(data);
+
+                box_pokemon.ot_name = ot_name;
+                box_pokemon.nickname = nickname;
+
+                box_pokemon
+            })
+            .collect();
+
+        Ok(box_pokemon)
+    }
+
+    /// Set save box Pokémon by box ID
+    ///
+    /// Boxes stored in banks 2 and 3 are protected by checksums at the end of
+    /// each bank, so the patch for those boxes extends to and includes the
+    /// recalculated checksums.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::sav::*;
+    /// use pkmnapi_db::string::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// let box_pokemon = vec![SaveBoxPokemon {
+    ///     internal_id: 0x98,
+    ///     current_hp: 20,
+    ///     level: 5,
+    ///     status: 0x00,
+    ///     type_ids: vec![0x16, 0x03],
+    ///     catch_rate: 45,
+    ///     moves: vec![SavePokemonMove {
+    ///         move_id: 0x21,
+    ///         pp: 35,
+    ///         pp_up: 0,
+    ///     }],
+    ///     ot_id: 666,
+    ///     exp: 135,
+    ///     stat_exp: SavePokemonStats::default(),
+    ///     dvs: SavePokemonDVs::default(),
+    ///     nickname: ROMString::from("BULBASAUR"),
+    ///     ot_name: ROMString::from("RED"),
+    /// }];
+    ///
+    /// let patch_set = sav.set_box_pokemon(&1, &box_pokemon).unwrap();
+    /// let patch = &patch_set.patches[0];
+    ///
+    /// assert_eq!(patch_set.patches.len(), 2);
+    /// assert_eq!(patch.offset, 0x4462);
+    /// assert_eq!(patch.length, 0x462);
+    /// assert_eq!(patch.data[..0x03], [0x01, 0x99, 0xFF]);
+    /// assert_eq!(
+    ///     patch.data[0x16..0x37],
+    ///     [
+    ///         0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A,
+    ///         0x00, 0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///         0x00, 0x23, 0x00, 0x00, 0x00
+    ///     ]
+    /// );
+    /// ```
+    pub fn set_box_pokemon(
+        &self,
+        box_id: &u8,
+        save_box_pokemon: &Vec<SaveBoxPokemon>,
+    ) -> Result<PatchSet> {
+        let offset = self.get_box_offset(box_id)?;
+        let max_len = 20;
+
+        let pokemon_count = save_box_pokemon.len();
+
+        if pokemon_count > max_len {
+            return Err(error::Error::SavBoxPokemonWrongSize(max_len, pokemon_count));
+        }
+
+        let pokemon = save_box_pokemon
+            .iter()
+            .map(|box_pokemon| {
+                Ok((
+                    box_pokemon.internal_id,
+                    box_pokemon.to_raw()?,
+                    &box_pokemon.ot_name,
+                    &box_pokemon.nickname,
+                ))
+            })
+            .collect::<Result<Vec<(u8, Vec<u8>, &ROMString, &ROMString)>>>()?;
+
+        let data = self.set_pokemon_list(offset, max_len, 0x21, pokemon)?;
+
+        if offset == Sav::CURRENT_BOX_OFFSET {
+            return Ok(PatchSet::from(Patch::new(&offset, &data)));
+        }
+
+        let offset_bank = Sav::BOX_BANK_OFFSETS[*box_id as usize / Sav::BOX_BANK_COUNT];
+
+        let offset_checksums = offset_bank + (Sav::BOX_BANK_COUNT * Sav::BOX_SIZE);
+        let bank = [
+            &self.sav[offset_bank..offset],
+            &data[..],
+            &self.sav[(offset + Sav::BOX_SIZE)..offset_checksums],
+        ]
+        .concat();
+        let checksums = Sav::box_bank_checksums(&bank);
+
+        Ok(PatchSet::from(vec![
+            Patch::new(&offset, &data),
+            Patch::new(&offset_checksums, &checksums),
+        ]))
+    }
+
+    /// Get save box offset by box ID
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// let current_box = sav.get_current_box().unwrap();
+    ///
+    /// assert_eq!(sav.get_box_offset(&current_box).unwrap(), 0x30C0);
+    /// assert_eq!(sav.get_box_offset(&11).unwrap(), 0x75EA);
+    /// ```
+    pub fn get_box_offset(&self, box_id: &u8) -> Result<usize> {
+        let (min_id, max_id) = (0, (Sav::BOX_BANK_COUNT * Sav::BOX_BANK_OFFSETS.len()) - 1);
+
+        if *box_id as usize > max_id {
+            return Err(error::Error::SavBoxIDInvalid(*box_id, min_id, max_id));
+        }
+
+        if *box_id == self.get_current_box()? {
+            return Ok(Sav::CURRENT_BOX_OFFSET);
+        }
+
+        let box_id = *box_id as usize;
+        let offset_bank = Sav::BOX_BANK_OFFSETS[box_id / Sav::BOX_BANK_COUNT];
+
+        Ok(offset_bank + ((box_id % Sav::BOX_BANK_COUNT) * Sav::BOX_SIZE))
+    }
+}
+
+/// Save box Pokémon
+///
+/// `internal_id` is the zero-based internal ID of the species, as returned by
+/// `PkmnapiDB::pokedex_id_to_internal_id`.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::sav::*;
+///
+/// let sav = vec![
+///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x2D, 0x00, 0x00, 0x02, 0x9A, 0x00,
+///     0x00, 0x87, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x05, 0xAB, 0xCD, 0x23,
+///     0x68, 0x00, 0x00,
+/// ];
+/// let box_pokemon = SaveBoxPokemon::from(&sav[..]);
+///
+/// assert_eq!(box_pokemon.internal_id, 0x98);
+/// assert_eq!(box_pokemon.current_hp, 20);
+/// assert_eq!(box_pokemon.level, 5);
+/// assert_eq!(box_pokemon.moves.len(), 2);
+/// assert_eq!(box_pokemon.stat_exp.special, 5);
+/// assert_eq!(box_pokemon.dvs.hp(), 0x05);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveBoxPokemon {
+    pub internal_id: u8,
+    pub current_hp: u16,
+    pub level: u8,
+    pub status: u8,
+    pub type_ids: Vec<u8>,
+    pub catch_rate: u8,
+    pub moves: Vec<SavePokemonMove>,
+    pub ot_id: u16,
+    pub exp: u32,
+    pub stat_exp: SavePokemonStats,
+    pub dvs: SavePokemonDVs,
+    pub nickname: ROMString,
+    pub ot_name: ROMString,
+}
+
+impl From<&[u8]> for SaveBoxPokemon {
+    /// Convert &[u8] to SaveBoxPokemon
+    ///
+    /// The nickname and OT name are stored outside of the 33-byte structure
+    /// and are left empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let sav = vec![
+    ///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A, 0x00,
+    ///     0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23,
+    ///     0x00, 0x00, 0x00,
+    /// ];
+    /// let box_pokemon = SaveBoxPokemon::from(&sav[..]);
+    ///
+    /// assert_eq!(box_pokemon.internal_id, 0x98);
+    /// assert_eq!(box_pokemon.exp, 135);
+    /// ```
+    fn from(sav: &[u8]) -> Self {
+        let mut cursor = Cursor::new(sav);
+
+        let internal_id = cursor.read_u8().unwrap_or(0).wrapping_sub(1);
+        let current_hp = cursor.read_u16::<BigEndian>().unwrap_or(0);
+        let level = cursor.read_u8().unwrap_or(0);
+        let status = cursor.read_u8().unwrap_or(0);
+        let type_ids = vec![cursor.read_u8().unwrap_or(0), cursor.read_u8().unwrap_or(0)];
+        let catch_rate = cursor.read_u8().unwrap_or(0);
+        let move_ids: Vec<u8> = (0..4).map(|_| cursor.read_u8().unwrap_or(0)).collect();
+        let ot_id = cursor.read_u16::<BigEndian>().unwrap_or(0);
+        let exp = cursor.read_u24::<BigEndian>().unwrap_or(0);
+        let stat_exp = SavePokemonStats::from(&mut cursor);
+        let dvs = SavePokemonDVs::from(&mut cursor);
+        let move_pps: Vec<u8> = (0..4).map(|_| cursor.read_u8().unwrap_or(0)).collect();
+
+        let moves = move_ids
+            .iter()
+            .zip(move_pps.iter())
+            .filter(|(move_id, _)| **move_id != 0x00)
+            .map(|(move_id, move_pp)| SavePokemonMove::new(*move_id, *move_pp))
+            .collect();
+
+        SaveBoxPokemon {
+            internal_id,
+            current_hp,
+            level,
+            status,
+            type_ids,
+            catch_rate,
+            moves,
+            ot_id,
+            exp,
+            stat_exp,
+            dvs,
+            nickname: ROMString::new(&[]),
+            ot_name: ROMString::new(&[]),
+        }
+    }
+}
+
+impl From<&SavePartyPokemon> for SaveBoxPokemon {
+    /// Convert SavePartyPokemon to SaveBoxPokemon
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let sav = vec![
+    ///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A, 0x00,
+    ///     0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23,
+    ///     0x00, 0x00, 0x00, 0x05, 0x00, 0x14, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0B,
+    /// ];
+    /// let party_pokemon = SavePartyPokemon::from(&sav[..]);
+    /// let box_pokemon = SaveBoxPokemon::from(&party_pokemon);
+    ///
+    /// assert_eq!(box_pokemon.to_raw().unwrap(), sav[..0x21].to_vec());
+    /// ```
+    fn from(party_pokemon: &SavePartyPokemon) -> Self {
+        SaveBoxPokemon {
+            internal_id: party_pokemon.internal_id,
+            current_hp: party_pokemon.current_hp,
+            level: party_pokemon.level,
+            status: party_pokemon.status,
+            type_ids: party_pokemon.type_ids.to_vec(),
+            catch_rate: party_pokemon.catch_rate,
+            moves: party_pokemon.moves.to_vec(),
+            ot_id: party_pokemon.ot_id,
+            exp: party_pokemon.exp,
+            stat_exp: party_pokemon.stat_exp.clone(),
+            dvs: party_pokemon.dvs.clone(),
+            nickname: party_pokemon.nickname.clone(),
+            ot_name: party_pokemon.ot_name.clone(),
+        }
+    }
+}
+
+impl SaveBoxPokemon {
+    /// Save box Pokémon to raw bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let sav = vec![
+    ///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A, 0x00,
+    ///     0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23,
+    ///     0x00, 0x00, 0x00,
+    /// ];
+    /// let box_pokemon = SaveBoxPokemon::from(&sav[..]);
+    ///
+    /// let raw = box_pokemon.to_raw().unwrap();
+    ///
+    /// assert_eq!(raw, sav);
+    /// ```
+    pub fn to_raw(&self) -> Result<Vec<u8>> {
+        let moves_len = self.moves.len();
+        let max_moves_len = 4;
+
+        if moves_len > max_moves_len {
+            return Err(error::Error::SavPokemonMovesWrongSize(
+                max_moves_len,
+                moves_len,
+            ));
+        }
+
+        let moves: Vec<SavePokemonMove> = (0..max_moves_len)
+            .map(|i| match self.moves.get(i) {
+                Some(pokemon_move) => pokemon_move.clone(),
+                None => SavePokemonMove::new(0x00, 0x00),
+            })
+            .collect();
+
+        let type_ids: Vec<u8> = (0..2)
+            .map(|i| {
+                *self
+                    .type_ids
+                    .get(i)
+                    .or(self.type_ids.first())
+                    .unwrap_or(&0x00)
+            })
+            .collect();
+
+        let mut raw = vec![];
+
+        raw.write_u8(self.internal_id.wrapping_add(1)).unwrap();
+        raw.write_u16::<BigEndian>(self.current_hp).unwrap();
+        raw.write_u8(self.level).unwrap();
+        raw.write_u8(self.status).unwrap();
+        raw.extend(&type_ids);
+        raw.write_u8(self.catch_rate).unwrap();
+        raw.extend(moves.iter().map(|pokemon_move| pokemon_move.move_id));
+        raw.write_u16::<BigEndian>(self.ot_id).unwrap();
+        raw.write_u24::<BigEndian>(self.exp).unwrap();
+        raw.extend(self.stat_exp.to_raw());
+        raw.extend(self.dvs.to_raw());
+        raw.extend(moves.iter().map(|pokemon_move| pokemon_move.to_raw()));
+
+        Ok(raw)
+    }
+}
+
+/// Save Pokémon move
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::sav::*;
+///
+/// let pokemon_move = SavePokemonMove::new(0x21, 0x63);
+///
+/// assert_eq!(
+///     pokemon_move,
+///     SavePokemonMove {
+///         move_id: 0x21,
+///         pp: 35,
+///         pp_up: 1
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavePokemonMove {
+    pub move_id: u8,
+    pub pp: u8,
+    pub pp_up: u8,
+}
+
+impl SavePokemonMove {
+    /// Create a move from its ID and raw PP byte
+    ///
+    /// The top two bits of the PP byte hold the number of PP Ups applied.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let pokemon_move = SavePokemonMove::new(0x21, 0xE3);
+    ///
+    /// assert_eq!(pokemon_move.pp, 35);
+    /// assert_eq!(pokemon_move.pp_up, 3);
+    /// ```
+    pub fn new(move_id: u8, move_pp: u8) -> Self {
+        SavePokemonMove {
+            move_id,
+            pp: move_pp & 0x3F,
+            pp_up: (move_pp & 0xC0) >> 6,
+        }
+    }
+
+    /// Save Pokémon move PP to raw byte
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let pokemon_move = SavePokemonMove {
+    ///     move_id: 0x21,
+    ///     pp: 35,
+    ///     pp_up: 3,
+    /// };
+    ///
+    /// assert_eq!(pokemon_move.to_raw(), 0xE3);
+    /// ```
+    pub fn to_raw(&self) -> u8 {
+        ((self.pp_up & 0x03) << 6) | (self.pp & 0x3F)
+    }
+}
+
+/// Save Pokémon stats
+///
+/// Used for both the calculated stats of party Pokémon and stat experience.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::sav::*;
+///
+/// let stats = SavePokemonStats {
+///     hp: 0x0102,
+///     attack: 0x0304,
+///     defence: 0x0506,
+///     speed: 0x0708,
+///     special: 0x090A,
+/// };
+///
+/// assert_eq!(
+///     stats.to_raw(),
+///     vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A]
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SavePokemonStats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defence: u16,
+    pub speed: u16,
+    pub special: u16,
+}
+
+impl From<&mut Cursor<&[u8]>> for SavePokemonStats {
+    fn from(cursor: &mut Cursor<&[u8]>) -> Self {
+        let hp = cursor.read_u16::<BigEndian>().unwrap_or(0);
+        let attack = cursor.read_u16::<BigEndian>().unwrap_or(0);
+        let defence = cursor.read_u16::<BigEndian>().unwrap_or(0);
+        let speed = cursor.read_u16::<BigEndian>().unwrap_or(0);
+        let special = cursor.read_u16::<BigEndian>().unwrap_or(0);
+
+        SavePokemonStats {
+            hp,
+            attack,
+            defence,
+            speed,
+            special,
+        }
+    }
+}
+
+impl SavePokemonStats {
+    pub fn to_raw(&self) -> Vec<u8> {
+        let mut raw = vec![];
+
+        raw.write_u16::<BigEndian>(self.hp).unwrap();
+        raw.write_u16::<BigEndian>(self.attack).unwrap();
+        raw.write_u16::<BigEndian>(self.defence).unwrap();
+        raw.write_u16::<BigEndian>(self.speed).unwrap();
+        raw.write_u16::<BigEndian>(self.special).unwrap();
+
+        raw
+    }
+}
+
+/// Save Pokémon DVs
+///
+/// The HP DV is not stored; it is derived from the lowest bit of the other
+/// four DVs.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::sav::*;
+///
+/// let dvs = SavePokemonDVs {
+///     attack: 0x0A,
+///     defence: 0x0B,
+///     speed: 0x0C,
+///     special: 0x0D,
+/// };
+///
+/// assert_eq!(dvs.hp(), 0x05);
+/// assert_eq!(dvs.to_raw(), vec![0xAB, 0xCD]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SavePokemonDVs {
+    pub attack: u8,
+    pub defence: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+impl From<&mut Cursor<&[u8]>> for SavePokemonDVs {
+    fn from(cursor: &mut Cursor<&[u8]>) -> Self {
+        let attack_defence = cursor.read_u8().unwrap_or(0);
+        let speed_special = cursor.read_u8().unwrap_or(0);
+
+        SavePokemonDVs {
+            attack: (attack_defence & 0xF0) >> 4,
+            defence: attack_defence & 0x0F,
+            speed: (speed_special & 0xF0) >> 4,
+            special: speed_special & 0x0F,
+        }
+    }
+}
+
+impl SavePokemonDVs {
+    pub fn hp(&self) -> u8 {
+        ((self.attack & 0x01) << 3)
+            | ((self.defence & 0x01) << 2)
+            | ((self.speed & 0x01) << 1)
+            | (self.special & 0x01)
+    }
+
+    pub fn to_raw(&self) -> Vec<u8> {
+        vec![
+            ((self.attack & 0x0F) << 4) | (self.defence & 0x0F),
+            ((self.speed & 0x0F) << 4) | (self.special & 0x0F),
+        ]
+    }
+}
+
+/// Save Pokémon nickname or OT name
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::sav::*;
+/// use pkmnapi_db::string::*;
+///
+/// let sav = vec![0x80, 0x81, 0x82, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+/// let pokemon_name = SavePokemonName::from(&sav[..]);
+///
+/// assert_eq!(
+///     pokemon_name,
+///     SavePokemonName {
+///         name: ROMString::from("ABC")
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavePokemonName {
+    pub name: ROMString,
+}
+
+impl From<&[u8]> for SavePokemonName {
+    fn from(sav: &[u8]) -> Self {
+        let name_end_index = sav.iter().position(|&r| r == 0x50).unwrap_or(sav.len());
+
+        let name = ROMString::new(&sav[..name_end_index]);
+
+        SavePokemonName { name }
+    }
+}
+
+impl SavePokemonName {
+    /// Save Pokémon name to raw bytes
+    ///
+    /// Names are padded with terminators to the full 11 bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    /// use pkmnapi_db::string::*;
+    ///
+    /// let pokemon_name = SavePokemonName {
+    ///     name: ROMString::from("ABC"),
+    /// };
+    ///
+    /// let raw = pokemon_name.to_raw();
+    ///
+    /// assert_eq!(
+    ///     raw,
+    ///     vec![0x80, 0x81, 0x82, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50]
+    /// );
+    /// ```
+    pub fn to_raw(&self) -> Vec<u8> {
+        let name_raw: Vec<u8> = self
+            .name
+            .value
+            .iter()
+            .take_while(|&x| *x != 0x50)
+            .cloned()
+            .collect();
+        let padding = vec![0x50; 0x0B - name_raw.len().min(0x0A)];
+
+        [name_raw, padding].concat()
+    }
+}
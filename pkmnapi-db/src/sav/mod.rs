@@ -19,10 +19,12 @@
 mod badges;
 mod bag_items;
 mod box_items;
+mod box_pokemon;
 mod coins;
 mod current_box;
 mod money;
 mod options;
+mod party_pokemon;
 mod player_id;
 mod player_name;
 mod pokemon_owned;
@@ -32,10 +34,12 @@ mod rival_name;
 pub use badges::*;
 pub use bag_items::*;
 pub use box_items::*;
+pub use box_pokemon::*;
 pub use coins::*;
 pub use current_box::*;
 pub use money::*;
 pub use options::*;
+pub use party_pokemon::*;
 pub use player_id::*;
 pub use player_name::*;
 pub use pokemon_owned::*;
@@ -44,15 +48,21 @@ pub use rival_name::*;
 
 use crate::error::{self, Result};
 use crate::patch::*;
+use crate::string::*;
 use crate::*;
 use std::num::Wrapping;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Sav {
     pub sav: Vec<u8>,
 }
 
 impl Sav {
+    pub const BOX_BANK_COUNT: usize = 6;
+    pub const BOX_BANK_OFFSETS: [usize; 2] = [0x4000, 0x6000];
+    pub const BOX_SIZE: usize = 0x462;
+    pub const CURRENT_BOX_OFFSET: usize = 0x30C0;
+
     /// Create sav from an array of bytes
     ///
     /// # Example
@@ -146,6 +156,10 @@ impl Sav {
 
     /// Apply save patch
     ///
+    /// Accepts a single `Patch` or a `PatchSet`. A patch set is applied as a
+    /// whole, or not at all and an error is returned if any of its patches
+    /// falls outside the save.
+    ///
     /// # Example
     ///
     /// ```
@@ -162,18 +176,239 @@ impl Sav {
     ///
     /// let patch = Patch::new(&0x00, &vec![0x13, 0x37]);
     ///
-    /// sav.apply_patch(patch);
+    /// sav.apply_patch(patch).unwrap();
     ///
     /// assert_eq!(sav.sav[..4], [0x13, 0x37, 0x00, 0x00]);
     /// ```
-    pub fn apply_patch<S: Into<Patch>>(&mut self, patch: S) {
-        let patch = patch.into();
+    pub fn apply_patch<S: Into<PatchSet>>(&mut self, patch: S) -> Result<()> {
+        let patch_set = patch.into();
+
+        for patch in patch_set.patches.iter() {
+            if patch.offset + patch.length > self.sav.len() {
+                return Err(error::Error::PatchOutOfBounds(
+                    patch.offset + patch.length,
+                    self.sav.len(),
+                ));
+            }
+        }
+
+        for patch in patch_set.patches {
+            self.sav = [
+                &self.sav[..patch.offset],
+                &patch.data[..],
+                &self.sav[(patch.offset + patch.length)..],
+            ]
+            .concat();
+        }
+
+        Ok(())
+    }
+
+    /// Verify box bank checksums
+    ///
+    /// Boxes 0-5 and 6-11 are stored in SRAM banks 2 and 3, each followed by
+    /// a checksum of the whole bank and one checksum per box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let mut sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// sav.apply_patch(sav.generate_box_checksums().unwrap()).unwrap();
+    ///
+    /// assert_eq!(sav.verify_box_checksums(), true);
+    /// ```
+    pub fn verify_box_checksums(&self) -> bool {
+        Sav::BOX_BANK_OFFSETS.iter().all(|offset_bank| {
+            let offset_checksums = offset_bank + (Sav::BOX_BANK_COUNT * Sav::BOX_SIZE);
+            let checksums = Sav::box_bank_checksums(&self.sav[*offset_bank..offset_checksums]);
+
+            checksums[..] == self.sav[offset_checksums..(offset_checksums + checksums.len())]
+        })
+    }
+
+    /// Generate box bank checksums
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::sav::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// let patch_set = sav.generate_box_checksums().unwrap();
+    ///
+    /// assert_eq!(patch_set.patches.len(), 2);
+    /// assert_eq!(patch_set.patches[0].offset, 0x5A4C);
+    /// assert_eq!(patch_set.patches[0].length, 0x07);
+    /// assert_eq!(patch_set.patches[1].offset, 0x7A4C);
+    /// assert_eq!(patch_set.patches[1].length, 0x07);
+    /// ```
+    pub fn generate_box_checksums(&self) -> Result<PatchSet> {
+        let patches = Sav::BOX_BANK_OFFSETS
+            .iter()
+            .map(|offset_bank| {
+                let offset_checksums = offset_bank + (Sav::BOX_BANK_COUNT * Sav::BOX_SIZE);
+                let checksums = Sav::box_bank_checksums(&self.sav[*offset_bank..offset_checksums]);
+
+                Patch::new(&offset_checksums, &checksums)
+            })
+            .collect::<Vec<Patch>>();
+
+        Ok(PatchSet::from(patches))
+    }
+
+    fn box_bank_checksums(bank: &[u8]) -> Vec<u8> {
+        let checksum = |data: &[u8]| {
+            data.iter()
+                .fold(Wrapping(0u8), |acc, x| acc + Wrapping(*x))
+                .0
+                ^ 0xFF
+        };
+
+        [
+            vec![checksum(bank)],
+            bank.chunks(Sav::BOX_SIZE)
+                .map(|box_data| checksum(box_data))
+                .collect(),
+        ]
+        .concat()
+    }
+
+    /// Read a stored Pokémon list (party or box)
+    ///
+    /// Lists are laid out as a count, a terminated species list, the Pokémon
+    /// data, the OT names and the nicknames.
+    fn get_pokemon_list(
+        &self,
+        offset: usize,
+        max_len: usize,
+        data_size: usize,
+    ) -> Vec<(&[u8], ROMString, ROMString)> {
+        let pokemon_count = (self.sav[offset] as usize).min(max_len);
+
+        let offset_data = offset + max_len + 0x02;
+        let offset_ot_names = offset_data + (max_len * data_size);
+        let offset_nicknames = offset_ot_names + (max_len * 0x0B);
+
+        (0..pokemon_count)
+            .map(|i| {
+                let offset_data = offset_data + (i * data_size);
+                let offset_ot_name = offset_ot_names + (i * 0x0B);
+                let offset_nickname = offset_nicknames + (i * 0x0B);
 
-        self.sav = [
-            &self.sav[..patch.offset],
-            &patch.data[..],
-            &self.sav[(patch.offset + patch.length)..],
+                let data = &self.sav[offset_data..(offset_data + data_size)];
+                let ot_name =
+                    SavePokemonName::from(&self.sav[offset_ot_name..(offset_ot_name + 0x0B)]).name;
+                let nickname =
+                    SavePokemonName::from(&self.sav[offset_nickname..(offset_nickname + 0x0B)])
+                        .name;
+
+                (data, ot_name, nickname)
+            })
+            .collect()
+    }
+
+    /// Write a stored Pokémon list (party or box)
+    ///
+    /// The count and species list are rebuilt from the given Pokémon so that
+    /// they always agree. Unused slots keep their current bytes.
+    fn set_pokemon_list(
+        &self,
+        offset: usize,
+        max_len: usize,
+        data_size: usize,
+        pokemon: Vec<(u8, Vec<u8>, &ROMString, &ROMString)>,
+    ) -> Result<Vec<u8>> {
+        let pokemon_count = pokemon.len();
+
+        let offset_species = offset + 0x01;
+        let offset_data = offset_species + max_len + 0x01;
+        let offset_ot_names = offset_data + (max_len * data_size);
+        let offset_nicknames = offset_ot_names + (max_len * 0x0B);
+
+        let pokemon_name_raw = |name: &ROMString| {
+            let pokemon_name_raw = SavePokemonName { name: name.clone() }.to_raw();
+            let pokemon_name_raw_len = pokemon_name_raw.len();
+            let max_len = 0x0B;
+
+            if pokemon_name_raw_len > max_len {
+                return Err(error::Error::SavPokemonNameWrongSize(
+                    max_len - 1,
+                    pokemon_name_raw_len - 1,
+                ));
+            }
+
+            Ok(pokemon_name_raw)
+        };
+
+        let species: Vec<u8> = pokemon
+            .iter()
+            .map(|(internal_id, _, _, _)| internal_id.wrapping_add(1))
+            .chain(vec![0xFF])
+            .chain(
+                self.sav[(offset_species + pokemon_count + 1)..offset_data]
+                    .iter()
+                    .cloned(),
+            )
+            .collect();
+
+        let pokemon_data: Vec<u8> = (0..max_len)
+            .map(|i| match pokemon.get(i) {
+                Some((_, data, _, _)) => data.to_vec(),
+                None => {
+                    let offset_data = offset_data + (i * data_size);
+
+                    self.sav[offset_data..(offset_data + data_size)].to_vec()
+                }
+            })
+            .flatten()
+            .collect();
+
+        let ot_names = (0..max_len)
+            .map(|i| match pokemon.get(i) {
+                Some((_, _, ot_name, _)) => pokemon_name_raw(ot_name),
+                None => {
+                    let offset_ot_name = offset_ot_names + (i * 0x0B);
+
+                    Ok(self.sav[offset_ot_name..(offset_ot_name + 0x0B)].to_vec())
+                }
+            })
+            .collect::<Result<Vec<Vec<u8>>>>()?
+            .concat();
+
+        let nicknames = (0..max_len)
+            .map(|i| match pokemon.get(i) {
+                Some((_, _, _, nickname)) => pokemon_name_raw(nickname),
+                None => {
+                    let offset_nickname = offset_nicknames + (i * 0x0B);
+
+                    Ok(self.sav[offset_nickname..(offset_nickname + 0x0B)].to_vec())
+                }
+            })
+            .collect::<Result<Vec<Vec<u8>>>>()?
+            .concat();
+
+        let data = [
+            vec![pokemon_count as u8],
+            species,
+            pokemon_data,
+            ot_names,
+            nicknames,
         ]
         .concat();
+
+        Ok(data)
     }
 }
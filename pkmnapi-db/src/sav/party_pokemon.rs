@@ -0,0 +1,320 @@
+use crate::error::{self, Result};
+use crate::patch::*;
+use crate::sav::{Sav, SaveBoxPokemon, SavePokemonDVs, SavePokemonMove, SavePokemonStats};
+use crate::string::*;
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use std::io::Cursor;
+
+impl Sav {
+    /// Get save party Pokémon
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    /// use pkmnapi_db::string::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let mut sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// let party_pokemon = vec![SavePartyPokemon {
+    ///     internal_id: 0x98,
+    ///     current_hp: 20,
+    ///     level: 5,
+    ///     status: 0x00,
+    ///     type_ids: vec![0x16, 0x03],
+    ///     catch_rate: 45,
+    ///     moves: vec![
+    ///         SavePokemonMove {
+    ///             move_id: 0x21,
+    ///             pp: 35,
+    ///             pp_up: 0,
+    ///         },
+    ///         SavePokemonMove {
+    ///             move_id: 0x2D,
+    ///             pp: 40,
+    ///             pp_up: 0,
+    ///         },
+    ///     ],
+    ///     ot_id: 666,
+    ///     exp: 135,
+    ///     stat_exp: SavePokemonStats::default(),
+    ///     dvs: SavePokemonDVs {
+    ///         attack: 0x0A,
+    ///         defence: 0x0B,
+    ///         speed: 0x0C,
+    ///         special: 0x0D,
+    ///     },
+    ///     stats: SavePokemonStats {
+    ///         hp: 20,
+    ///         attack: 10,
+    ///         defence: 10,
+    ///         speed: 10,
+    ///         special: 11,
+    ///     },
+    ///     nickname: ROMString::from("BULBASAUR"),
+    ///     ot_name: ROMString::from("RED"),
+    /// }];
+    ///
+    /// let patch = sav.set_party_pokemon(&party_pokemon).unwrap();
+    ///
+    /// sav.apply_patch(patch).unwrap();
+    ///
+    /// assert_eq!(sav.get_party_pokemon().unwrap(), party_pokemon);
+    /// ```
+    pub fn get_party_pokemon(&self) -> Result<Vec<SavePartyPokemon>> {
+        let offset = 0x2F2C;
+        let max_len = 6;
+
+        let party_pokemon = self
+            .get_pokemon_list(offset, max_len, 0x2C)
+            .into_iter()
+            .map(|(data, ot_name, nickname)| {
+                let mut party_pokemon = SavePartyPokemon::from(data);
+
+                party_pokemon.ot_name = ot_name;
+                party_pokemon.nickname = nickname;
+
+                party_pokemon
+            })
+            .collect();
+
+        Ok(party_pokemon)
+    }
+
+    /// Set save party Pokémon
+    ///
+    /// The party count and species list are rewritten alongside the Pokémon
+    /// data so that they always agree. Unused slots keep their current bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::patch::*;
+    /// use pkmnapi_db::sav::*;
+    /// use pkmnapi_db::string::*;
+    /// use std::fs;
+    /// # use std::env;
+    /// # let sav_path = env::var("PKMN_SAV").expect("Set the PKMN_SAV environment variable to point to the SAV location");
+    ///
+    /// let sav_data = fs::read(sav_path).unwrap();
+    /// let sav = Sav::new(&sav_data).unwrap();
+    ///
+    /// let patch = sav
+    ///     .set_party_pokemon(&vec![SavePartyPokemon {
+    ///         internal_id: 0x98,
+    ///         current_hp: 20,
+    ///         level: 5,
+    ///         status: 0x00,
+    ///         type_ids: vec![0x16, 0x03],
+    ///         catch_rate: 45,
+    ///         moves: vec![SavePokemonMove {
+    ///             move_id: 0x21,
+    ///             pp: 35,
+    ///             pp_up: 0,
+    ///         }],
+    ///         ot_id: 666,
+    ///         exp: 135,
+    ///         stat_exp: SavePokemonStats::default(),
+    ///         dvs: SavePokemonDVs::default(),
+    ///         stats: SavePokemonStats {
+    ///             hp: 20,
+    ///             attack: 10,
+    ///             defence: 10,
+    ///             speed: 10,
+    ///             special: 11,
+    ///         },
+    ///         nickname: ROMString::from("BULBASAUR"),
+    ///         ot_name: ROMString::from("RED"),
+    ///     }])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(patch.offset, 0x2F2C);
+    /// assert_eq!(patch.length, 0x194);
+    /// assert_eq!(patch.data[..0x03], [0x01, 0x99, 0xFF]);
+    /// assert_eq!(
+    ///     patch.data[0x08..0x34],
+    ///     [
+    ///         0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A,
+    ///         0x00, 0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///         0x00, 0x23, 0x00, 0x00, 0x00, 0x05, 0x00, 0x14, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0A,
+    ///         0x00, 0x0B
+    ///     ]
+    /// );
+    /// assert_eq!(patch.data[0x110..0x114], [0x91, 0x84, 0x83, 0x50]);
+    /// assert_eq!(
+    ///     patch.data[0x152..0x15C],
+    ///     [0x81, 0x94, 0x8B, 0x81, 0x80, 0x92, 0x80, 0x94, 0x91, 0x50]
+    /// );
+    /// ```
+    pub fn set_party_pokemon(&self, save_party_pokemon: &Vec<SavePartyPokemon>) -> Result<Patch> {
+        let offset = 0x2F2C;
+        let max_len = 6;
+
+        let pokemon_count = save_party_pokemon.len();
+
+        if pokemon_count > max_len {
+            return Err(error::Error::SavPartyPokemonWrongSize(
+                max_len,
+                pokemon_count,
+            ));
+        }
+
+        let pokemon = save_party_pokemon
+            .iter()
+            .map(|party_pokemon| {
+                Ok((
+                    party_pokemon.internal_id,
+                    party_pokemon.to_raw()?,
+                    &party_pokemon.ot_name,
+                    &party_pokemon.nickname,
+                ))
+            })
+            .collect::<Result<Vec<(u8, Vec<u8>, &ROMString, &ROMString)>>>()?;
+
+        let data = self.set_pokemon_list(offset, max_len, 0x2C, pokemon)?;
+
+        Ok(Patch::new(&offset, &data))
+    }
+}
+
+/// Save party Pokémon
+///
+/// `internal_id` is the zero-based internal ID of the species, as returned by
+/// `PkmnapiDB::pokedex_id_to_internal_id`.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::sav::*;
+///
+/// let sav = vec![
+///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x2D, 0x00, 0x00, 0x02, 0x9A, 0x00,
+///     0x00, 0x87, 0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00, 0x05, 0xAB, 0xCD, 0x23,
+///     0x68, 0x00, 0x00, 0x05, 0x00, 0x14, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0B,
+/// ];
+/// let party_pokemon = SavePartyPokemon::from(&sav[..]);
+///
+/// assert_eq!(party_pokemon.internal_id, 0x98);
+/// assert_eq!(party_pokemon.current_hp, 20);
+/// assert_eq!(party_pokemon.level, 5);
+/// assert_eq!(party_pokemon.type_ids, vec![0x16, 0x03]);
+/// assert_eq!(
+///     party_pokemon.moves,
+///     vec![
+///         SavePokemonMove {
+///             move_id: 0x21,
+///             pp: 35,
+///             pp_up: 0
+///         },
+///         SavePokemonMove {
+///             move_id: 0x2D,
+///             pp: 40,
+///             pp_up: 1
+///         }
+///     ]
+/// );
+/// assert_eq!(party_pokemon.ot_id, 666);
+/// assert_eq!(party_pokemon.exp, 135);
+/// assert_eq!(party_pokemon.dvs.hp(), 0x05);
+/// assert_eq!(party_pokemon.stats.hp, 20);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavePartyPokemon {
+    pub internal_id: u8,
+    pub current_hp: u16,
+    pub level: u8,
+    pub status: u8,
+    pub type_ids: Vec<u8>,
+    pub catch_rate: u8,
+    pub moves: Vec<SavePokemonMove>,
+    pub ot_id: u16,
+    pub exp: u32,
+    pub stat_exp: SavePokemonStats,
+    pub dvs: SavePokemonDVs,
+    pub stats: SavePokemonStats,
+    pub nickname: ROMString,
+    pub ot_name: ROMString,
+}
+
+impl From<&[u8]> for SavePartyPokemon {
+    /// Convert &[u8] to SavePartyPokemon
+    ///
+    /// The nickname and OT name are stored outside of the 44-byte structure
+    /// and are left empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let sav = vec![
+    ///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A, 0x00,
+    ///     0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23,
+    ///     0x00, 0x00, 0x00, 0x05, 0x00, 0x14, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0B,
+    /// ];
+    /// let party_pokemon = SavePartyPokemon::from(&sav[..]);
+    ///
+    /// assert_eq!(party_pokemon.internal_id, 0x98);
+    /// assert_eq!(party_pokemon.stats.special, 11);
+    /// ```
+    fn from(sav: &[u8]) -> Self {
+        let box_pokemon = SaveBoxPokemon::from(&sav[..0x21]);
+
+        let mut cursor = Cursor::new(&sav[0x21..]);
+
+        let level = cursor.read_u8().unwrap_or(0);
+        let stats = SavePokemonStats::from(&mut cursor);
+
+        SavePartyPokemon {
+            internal_id: box_pokemon.internal_id,
+            current_hp: box_pokemon.current_hp,
+            level,
+            status: box_pokemon.status,
+            type_ids: box_pokemon.type_ids,
+            catch_rate: box_pokemon.catch_rate,
+            moves: box_pokemon.moves,
+            ot_id: box_pokemon.ot_id,
+            exp: box_pokemon.exp,
+            stat_exp: box_pokemon.stat_exp,
+            dvs: box_pokemon.dvs,
+            stats,
+            nickname: box_pokemon.nickname,
+            ot_name: box_pokemon.ot_name,
+        }
+    }
+}
+
+impl SavePartyPokemon {
+    /// Save party Pokémon to raw bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::sav::*;
+    ///
+    /// let sav = vec![
+    ///     0x99, 0x00, 0x14, 0x05, 0x00, 0x16, 0x03, 0x2D, 0x21, 0x00, 0x00, 0x00, 0x02, 0x9A, 0x00,
+    ///     0x00, 0x87, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x23,
+    ///     0x00, 0x00, 0x00, 0x05, 0x00, 0x14, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0A, 0x00, 0x0B,
+    /// ];
+    /// let party_pokemon = SavePartyPokemon::from(&sav[..]);
+    ///
+    /// let raw = party_pokemon.to_raw().unwrap();
+    ///
+    /// assert_eq!(raw, sav);
+    /// ```
+    pub fn to_raw(&self) -> Result<Vec<u8>> {
+        let box_pokemon = SaveBoxPokemon::from(self);
+
+        let mut raw = box_pokemon.to_raw()?;
+
+        raw.write_u8(self.level).unwrap();
+        raw.extend(self.stats.to_raw());
+
+        Ok(raw)
+    }
+}
@@ -10,6 +10,10 @@
 //! assert_eq!(string.value, vec![0x80, 0x81, 0x82, 0x50]);
 //! ```
 
+use crate::error;
+use crate::header::DestinationCode;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use std::fmt;
 
 /// Representation of a ROM string
@@ -48,6 +52,9 @@ impl ROMString {
 
     /// Creates a ROM string from a &str or String
     ///
+    /// Characters that cannot be encoded are replaced with `?`. Use
+    /// `ROMString::try_from` to reject them instead.
+    ///
     /// # Example
     ///
     /// ```
@@ -56,125 +63,615 @@ impl ROMString {
     /// let string = ROMString::from("ABC@");
     ///
     /// assert_eq!(string.value, vec![0x80, 0x81, 0x82, 0x50]);
+    ///
+    /// let string = ROMString::from("A~");
+    ///
+    /// assert_eq!(string.value, vec![0x80, 0xE6]);
     /// ```
     pub fn from<S: Into<String>>(dbstr: S) -> Self {
-        let dbstr = dbstr.into();
-        let mut chars = dbstr.chars().peekable();
-        let mut value: Vec<u8> = vec![];
+        let (value, _) = ROMString::encode(&dbstr.into(), &Charset::ENGLISH);
+
+        ROMString { value }
+    }
+
+    /// Creates a ROM string from a &str or String
+    ///
+    /// Fails with the position of every character that cannot be encoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::error;
+    /// use pkmnapi_db::string::*;
+    ///
+    /// let string = ROMString::try_from("<PLAYER>'s PC!").unwrap();
+    ///
+    /// assert_eq!(string.value, vec![0x52, 0xBD, 0x7F, 0x8F, 0x82, 0xE7]);
+    ///
+    /// let string = ROMString::try_from("A~B{");
+    ///
+    /// assert_eq!(
+    ///     string,
+    ///     Err(error::Error::StringCharactersInvalid(vec![(1, '~'), (3, '{')]))
+    /// );
+    /// ```
+    pub fn try_from<S: Into<String>>(dbstr: S) -> error::Result<Self> {
+        ROMString::try_from_charset(dbstr, &Charset::ENGLISH)
+    }
+
+    /// Creates a ROM string from a &str or String using a game's character
+    /// set
+    ///
+    /// Fails with the position of every character that cannot be encoded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    ///
+    /// let string = ROMString::try_from_charset("ピカチュウ", &Charset::JAPANESE).unwrap();
+    ///
+    /// assert_eq!(string.value, vec![0x41, 0x85, 0x90, 0xAE, 0x82]);
+    /// ```
+    pub fn try_from_charset<S: Into<String>>(dbstr: S, charset: &Charset) -> error::Result<Self> {
+        let (value, invalid_chars) = ROMString::encode(&dbstr.into(), charset);
 
-        while let Some(c) = chars.next() {
-            match c {
-                '\'' => {
-                    let next_c = chars.peek().unwrap();
+        if !invalid_chars.is_empty() {
+            return Err(error::Error::StringCharactersInvalid(invalid_chars));
+        }
 
-                    match next_c {
-                        's' => {
-                            value.push(0xBD);
-                            chars.next();
+        Ok(ROMString { value })
+    }
 
-                            continue;
-                        }
-                        't' => {
-                            value.push(0xBE);
-                            chars.next();
+    /// Encodes a string, returning the encoded bytes along with the position
+    /// of every character that could not be encoded
+    ///
+    /// At every position the longest matching entry of the character table
+    /// wins, so `'s` is a single character and `<PK>` is not read as `<`.
+    /// Bytes without a character can be written as `<0xNN>`.
+    fn encode(dbstr: &str, charset: &Charset) -> (Vec<u8>, Vec<(usize, char)>) {
+        let mut value = vec![];
+        let mut invalid_chars = vec![];
+        let mut rest = dbstr;
+        let mut position = 0;
 
-                            continue;
-                        }
-                        _ => {}
-                    };
-                }
-                _ => {}
-            };
+        while let Some(c) = rest.chars().next() {
+            let (chr, len) = match charset.encode_char(rest) {
+                Some((chr, len)) => (chr, len),
+                None => {
+                    invalid_chars.push((position, c));
 
-            let chr = match c {
-                '¶' => 0x49,
-                '\n' => 0x4E,
-                '@' => 0x50,
-                '#' => 0x54,
-                ' ' => 0x7F,
-                'A' => 0x80,
-                'B' => 0x81,
-                'C' => 0x82,
-                'D' => 0x83,
-                'E' => 0x84,
-                'F' => 0x85,
-                'G' => 0x86,
-                'H' => 0x87,
-                'I' => 0x88,
-                'J' => 0x89,
-                'K' => 0x8a,
-                'L' => 0x8b,
-                'M' => 0x8c,
-                'N' => 0x8d,
-                'O' => 0x8e,
-                'P' => 0x8f,
-                'Q' => 0x90,
-                'R' => 0x91,
-                'S' => 0x92,
-                'T' => 0x93,
-                'U' => 0x94,
-                'V' => 0x95,
-                'W' => 0x96,
-                'X' => 0x97,
-                'Y' => 0x98,
-                'Z' => 0x99,
-                'a' => 0xA0,
-                'b' => 0xA1,
-                'c' => 0xA2,
-                'd' => 0xA3,
-                'e' => 0xA4,
-                'f' => 0xA5,
-                'g' => 0xA6,
-                'h' => 0xA7,
-                'i' => 0xA8,
-                'j' => 0xA9,
-                'k' => 0xAA,
-                'l' => 0xAB,
-                'm' => 0xAC,
-                'n' => 0xAD,
-                'o' => 0xAE,
-                'p' => 0xAF,
-                'q' => 0xB0,
-                'r' => 0xB1,
-                's' => 0xB2,
-                't' => 0xB3,
-                'u' => 0xB4,
-                'v' => 0xB5,
-                'w' => 0xB6,
-                'x' => 0xB7,
-                'y' => 0xB8,
-                'z' => 0xB9,
-                'é' => 0xBA,
-                '\'' => 0xE0,
-                '-' => 0xE3,
-                '?' => 0xE6,
-                '.' => 0xE8,
-                '♂' => 0xEF,
-                ',' => 0xF4,
-                '♀' => 0xF5,
-                '0' => 0xF6,
-                '1' => 0xF7,
-                '2' => 0xF8,
-                '3' => 0xF9,
-                '4' => 0xFA,
-                '5' => 0xFB,
-                '6' => 0xFC,
-                '7' => 0xFD,
-                '8' => 0xFE,
-                '9' => 0xFF,
-                _ => c as u8,
+                    (0xE6, c.len_utf8())
+                }
             };
 
             value.push(chr);
+            position += rest[..len].chars().count();
+            rest = &rest[len..];
         }
 
-        ROMString { value }
+        (value, invalid_chars)
+    }
+
+    /// Decodes a single byte
+    ///
+    /// Bytes without a character are decoded as `<0xNN>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    ///
+    /// assert_eq!(ROMString::decode_char(&0x52), "<PLAYER>");
+    /// assert_eq!(ROMString::decode_char(&0x00), "<0x00>");
+    /// ```
+    pub fn decode_char(chr: &u8) -> String {
+        Charset::ENGLISH.decode_char(chr)
+    }
+
+    /// Decodes a ROM string using a game's character set
+    ///
+    /// Decoding stops at the first `@`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    ///
+    /// let string = ROMString::new(&[0x41, 0x85, 0x90, 0xAE, 0x82, 0x50]);
+    ///
+    /// assert_eq!(string.decode(&Charset::JAPANESE), "ピカチュウ");
+    /// assert_eq!(string.decode(&Charset::ENGLISH), "<0x41>FQoC");
+    /// ```
+    pub fn decode(&self, charset: &Charset) -> String {
+        self.value
+            .iter()
+            .take_while(|chr| **chr != 0x50)
+            .map(|chr| charset.decode_char(chr))
+            .collect()
+    }
+}
+
+/// Character set
+///
+/// English releases and Japanese releases use different fonts, so the same
+/// byte may stand for a different character.
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::header::*;
+/// use pkmnapi_db::string::*;
+///
+/// let charset = Charset::from(&DestinationCode::JAPANESE);
+///
+/// assert_eq!(charset, Charset::JAPANESE);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Charset {
+    ENGLISH,
+    JAPANESE,
+}
+
+impl Charset {
+    /// Encodes the character at the start of a string, returning the byte
+    /// and the length of the matched string
+    fn encode_char(&self, dbstr: &str) -> Option<(u8, usize)> {
+        let table_match = self
+            .chars()
+            .iter()
+            .filter(|(_, markup)| dbstr.starts_with(markup))
+            .max_by_key(|(_, markup)| markup.len())
+            .map(|(chr, markup)| (*chr, markup.len()));
+
+        if table_match.is_some() {
+            return table_match;
+        }
+
+        match dbstr.get(..6) {
+            Some(markup) if markup.starts_with("<0x") && markup.ends_with('>') => {
+                u8::from_str_radix(&markup[3..5], 16)
+                    .ok()
+                    .map(|chr| (chr, 6))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes a single byte
+    ///
+    /// Bytes without a character are decoded as `<0xNN>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::string::*;
+    ///
+    /// assert_eq!(Charset::JAPANESE.decode_char(&0x80), "ア");
+    /// assert_eq!(Charset::ENGLISH.decode_char(&0x80), "A");
+    /// ```
+    pub fn decode_char(&self, chr: &u8) -> String {
+        match self.chars().iter().find(|(table_chr, _)| table_chr == chr) {
+            Some((_, markup)) => markup.to_string(),
+            None => format!("<0x{:02X}>", chr),
+        }
+    }
+
+    fn chars(&self) -> &'static [(u8, &'static str)] {
+        match self {
+            Charset::ENGLISH => &ROM_CHARS,
+            Charset::JAPANESE => &ROM_CHARS_JAPANESE,
+        }
     }
 }
 
+impl From<&DestinationCode> for Charset {
+    /// Convert &DestinationCode to Charset
+    fn from(destination_code: &DestinationCode) -> Self {
+        match destination_code {
+            DestinationCode::JAPANESE => Charset::JAPANESE,
+            DestinationCode::NON_JAPANESE => Charset::ENGLISH,
+        }
+    }
+}
+
+/// Character table of the English font and placeholder tokens
+///
+/// When several strings share a byte, the first one is used for decoding.
+const ROM_CHARS: [(u8, &str); 152] = [
+    (0x49, "¶"),
+    (0x49, "<PAGE>"),
+    (0x4A, "<PKMN>"),
+    (0x4B, "<_CONT>"),
+    (0x4C, "<SCROLL>"),
+    (0x4E, "\n"),
+    (0x4E, "<NEXT>"),
+    (0x4F, "<LINE>"),
+    (0x50, "@"),
+    (0x51, "<PARA>"),
+    (0x52, "<PLAYER>"),
+    (0x53, "<RIVAL>"),
+    (0x54, "#"),
+    (0x55, "<CONT>"),
+    (0x56, "<……>"),
+    (0x57, "<DONE>"),
+    (0x58, "<PROMPT>"),
+    (0x59, "<TARGET>"),
+    (0x5A, "<USER>"),
+    (0x5B, "<PC>"),
+    (0x5C, "<TM>"),
+    (0x5D, "<TRAINER>"),
+    (0x5E, "<ROCKET>"),
+    (0x5F, "<DEXEND>"),
+    (0x60, "<BOLD_A>"),
+    (0x61, "<BOLD_B>"),
+    (0x62, "<BOLD_C>"),
+    (0x63, "<BOLD_D>"),
+    (0x64, "<BOLD_E>"),
+    (0x65, "<BOLD_F>"),
+    (0x66, "<BOLD_G>"),
+    (0x67, "<BOLD_H>"),
+    (0x68, "<BOLD_I>"),
+    (0x69, "<BOLD_V>"),
+    (0x6A, "<BOLD_S>"),
+    (0x6B, "<BOLD_L>"),
+    (0x6C, "<BOLD_M>"),
+    (0x6D, "<COLON>"),
+    (0x6E, "ぃ"),
+    (0x6F, "ぅ"),
+    (0x70, "‘"),
+    (0x71, "’"),
+    (0x72, "“"),
+    (0x73, "”"),
+    (0x74, "·"),
+    (0x75, "…"),
+    (0x76, "ぁ"),
+    (0x77, "ぇ"),
+    (0x78, "ぉ"),
+    (0x79, "┌"),
+    (0x7A, "─"),
+    (0x7B, "┐"),
+    (0x7C, "│"),
+    (0x7D, "└"),
+    (0x7E, "┘"),
+    (0x7F, " "),
+    (0x80, "A"),
+    (0x81, "B"),
+    (0x82, "C"),
+    (0x83, "D"),
+    (0x84, "E"),
+    (0x85, "F"),
+    (0x86, "G"),
+    (0x87, "H"),
+    (0x88, "I"),
+    (0x89, "J"),
+    (0x8A, "K"),
+    (0x8B, "L"),
+    (0x8C, "M"),
+    (0x8D, "N"),
+    (0x8E, "O"),
+    (0x8F, "P"),
+    (0x90, "Q"),
+    (0x91, "R"),
+    (0x92, "S"),
+    (0x93, "T"),
+    (0x94, "U"),
+    (0x95, "V"),
+    (0x96, "W"),
+    (0x97, "X"),
+    (0x98, "Y"),
+    (0x99, "Z"),
+    (0x9A, "("),
+    (0x9B, ")"),
+    (0x9C, ":"),
+    (0x9D, ";"),
+    (0x9E, "["),
+    (0x9F, "]"),
+    (0xA0, "a"),
+    (0xA1, "b"),
+    (0xA2, "c"),
+    (0xA3, "d"),
+    (0xA4, "e"),
+    (0xA5, "f"),
+    (0xA6, "g"),
+    (0xA7, "h"),
+    (0xA8, "i"),
+    (0xA9, "j"),
+    (0xAA, "k"),
+    (0xAB, "l"),
+    (0xAC, "m"),
+    (0xAD, "n"),
+    (0xAE, "o"),
+    (0xAF, "p"),
+    (0xB0, "q"),
+    (0xB1, "r"),
+    (0xB2, "s"),
+    (0xB3, "t"),
+    (0xB4, "u"),
+    (0xB5, "v"),
+    (0xB6, "w"),
+    (0xB7, "x"),
+    (0xB8, "y"),
+    (0xB9, "z"),
+    (0xBA, "é"),
+    (0xBB, "'d"),
+    (0xBC, "'l"),
+    (0xBD, "'s"),
+    (0xBE, "'t"),
+    (0xBF, "'v"),
+    (0xE0, "'"),
+    (0xE1, "<PK>"),
+    (0xE2, "<MN>"),
+    (0xE3, "-"),
+    (0xE4, "'r"),
+    (0xE5, "'m"),
+    (0xE6, "?"),
+    (0xE7, "!"),
+    (0xE8, "."),
+    (0xE9, "ァ"),
+    (0xEA, "ゥ"),
+    (0xEB, "ェ"),
+    (0xEC, "▷"),
+    (0xED, "▶"),
+    (0xEE, "▼"),
+    (0xEF, "♂"),
+    (0xF0, "¥"),
+    (0xF1, "×"),
+    (0xF2, "<DOT>"),
+    (0xF3, "/"),
+    (0xF4, ","),
+    (0xF5, "♀"),
+    (0xF6, "0"),
+    (0xF7, "1"),
+    (0xF8, "2"),
+    (0xF9, "3"),
+    (0xFA, "4"),
+    (0xFB, "5"),
+    (0xFC, "6"),
+    (0xFD, "7"),
+    (0xFE, "8"),
+    (0xFF, "9"),
+];
+
+/// Character table of the Japanese font and placeholder tokens
+///
+/// The katakana ヘ, ベ, ペ and リ share the bytes of their hiragana
+/// counterparts.
+///
+/// When several strings share a byte, the first one is used for decoding.
+const ROM_CHARS_JAPANESE: [(u8, &str); 225] = [
+    (0x05, "ガ"),
+    (0x06, "ギ"),
+    (0x07, "グ"),
+    (0x08, "ゲ"),
+    (0x09, "ゴ"),
+    (0x0A, "ザ"),
+    (0x0B, "ジ"),
+    (0x0C, "ズ"),
+    (0x0D, "ゼ"),
+    (0x0E, "ゾ"),
+    (0x0F, "ダ"),
+    (0x10, "ヂ"),
+    (0x11, "ヅ"),
+    (0x12, "デ"),
+    (0x13, "ド"),
+    (0x19, "バ"),
+    (0x1A, "ビ"),
+    (0x1B, "ブ"),
+    (0x1C, "ボ"),
+    (0x26, "が"),
+    (0x27, "ぎ"),
+    (0x28, "ぐ"),
+    (0x29, "げ"),
+    (0x2A, "ご"),
+    (0x2B, "ざ"),
+    (0x2C, "じ"),
+    (0x2D, "ず"),
+    (0x2E, "ぜ"),
+    (0x2F, "ぞ"),
+    (0x30, "だ"),
+    (0x31, "ぢ"),
+    (0x32, "づ"),
+    (0x33, "で"),
+    (0x34, "ど"),
+    (0x3A, "ば"),
+    (0x3B, "び"),
+    (0x3C, "ぶ"),
+    (0x3D, "べ"),
+    (0x3D, "ベ"),
+    (0x3E, "ぼ"),
+    (0x40, "パ"),
+    (0x41, "ピ"),
+    (0x42, "プ"),
+    (0x43, "ポ"),
+    (0x44, "ぱ"),
+    (0x45, "ぴ"),
+    (0x46, "ぷ"),
+    (0x47, "ぺ"),
+    (0x47, "ペ"),
+    (0x48, "ぽ"),
+    (0x49, "¶"),
+    (0x49, "<PAGE>"),
+    (0x4A, "<PKMN>"),
+    (0x4B, "<_CONT>"),
+    (0x4C, "<SCROLL>"),
+    (0x4E, "\n"),
+    (0x4E, "<NEXT>"),
+    (0x4F, "<LINE>"),
+    (0x50, "@"),
+    (0x51, "<PARA>"),
+    (0x52, "<PLAYER>"),
+    (0x53, "<RIVAL>"),
+    (0x54, "#"),
+    (0x55, "<CONT>"),
+    (0x56, "<……>"),
+    (0x57, "<DONE>"),
+    (0x58, "<PROMPT>"),
+    (0x59, "<TARGET>"),
+    (0x5A, "<USER>"),
+    (0x5B, "<PC>"),
+    (0x5C, "<TM>"),
+    (0x5D, "<TRAINER>"),
+    (0x5E, "<ROCKET>"),
+    (0x5F, "<DEXEND>"),
+    (0x6E, "ぃ"),
+    (0x6F, "ぅ"),
+    (0x70, "「"),
+    (0x71, "」"),
+    (0x72, "『"),
+    (0x73, "』"),
+    (0x74, "・"),
+    (0x75, "⋯"),
+    (0x76, "ぁ"),
+    (0x77, "ぇ"),
+    (0x78, "ぉ"),
+    (0x79, "┌"),
+    (0x7A, "─"),
+    (0x7B, "┐"),
+    (0x7C, "│"),
+    (0x7D, "└"),
+    (0x7E, "┘"),
+    (0x7F, " "),
+    (0x7F, "　"),
+    (0x80, "ア"),
+    (0x81, "イ"),
+    (0x82, "ウ"),
+    (0x83, "エ"),
+    (0x84, "オ"),
+    (0x85, "カ"),
+    (0x86, "キ"),
+    (0x87, "ク"),
+    (0x88, "ケ"),
+    (0x89, "コ"),
+    (0x8A, "サ"),
+    (0x8B, "シ"),
+    (0x8C, "ス"),
+    (0x8D, "セ"),
+    (0x8E, "ソ"),
+    (0x8F, "タ"),
+    (0x90, "チ"),
+    (0x91, "ツ"),
+    (0x92, "テ"),
+    (0x93, "ト"),
+    (0x94, "ナ"),
+    (0x95, "ニ"),
+    (0x96, "ヌ"),
+    (0x97, "ネ"),
+    (0x98, "ノ"),
+    (0x99, "ハ"),
+    (0x9A, "ヒ"),
+    (0x9B, "フ"),
+    (0x9C, "ホ"),
+    (0x9D, "マ"),
+    (0x9E, "ミ"),
+    (0x9F, "ム"),
+    (0xA0, "メ"),
+    (0xA1, "モ"),
+    (0xA2, "ヤ"),
+    (0xA3, "ユ"),
+    (0xA4, "ヨ"),
+    (0xA5, "ラ"),
+    (0xA6, "ル"),
+    (0xA7, "レ"),
+    (0xA8, "ロ"),
+    (0xA9, "ワ"),
+    (0xAA, "ヲ"),
+    (0xAB, "ン"),
+    (0xAC, "ッ"),
+    (0xAD, "ャ"),
+    (0xAE, "ュ"),
+    (0xAF, "ョ"),
+    (0xB0, "ィ"),
+    (0xB1, "あ"),
+    (0xB2, "い"),
+    (0xB3, "う"),
+    (0xB4, "え"),
+    (0xB5, "お"),
+    (0xB6, "か"),
+    (0xB7, "き"),
+    (0xB8, "く"),
+    (0xB9, "け"),
+    (0xBA, "こ"),
+    (0xBB, "さ"),
+    (0xBC, "し"),
+    (0xBD, "す"),
+    (0xBE, "せ"),
+    (0xBF, "そ"),
+    (0xC0, "た"),
+    (0xC1, "ち"),
+    (0xC2, "つ"),
+    (0xC3, "て"),
+    (0xC4, "と"),
+    (0xC5, "な"),
+    (0xC6, "に"),
+    (0xC7, "ぬ"),
+    (0xC8, "ね"),
+    (0xC9, "の"),
+    (0xCA, "は"),
+    (0xCB, "ひ"),
+    (0xCC, "ふ"),
+    (0xCD, "へ"),
+    (0xCD, "ヘ"),
+    (0xCE, "ほ"),
+    (0xCF, "ま"),
+    (0xD0, "み"),
+    (0xD1, "む"),
+    (0xD2, "め"),
+    (0xD3, "も"),
+    (0xD4, "や"),
+    (0xD5, "ゆ"),
+    (0xD6, "よ"),
+    (0xD7, "ら"),
+    (0xD8, "り"),
+    (0xD8, "リ"),
+    (0xD9, "る"),
+    (0xDA, "れ"),
+    (0xDB, "ろ"),
+    (0xDC, "わ"),
+    (0xDD, "を"),
+    (0xDE, "ん"),
+    (0xDF, "っ"),
+    (0xE0, "ゃ"),
+    (0xE1, "ゅ"),
+    (0xE2, "ょ"),
+    (0xE3, "ー"),
+    (0xE4, "゜"),
+    (0xE5, "゛"),
+    (0xE6, "？"),
+    (0xE6, "?"),
+    (0xE7, "！"),
+    (0xE7, "!"),
+    (0xE8, "。"),
+    (0xE9, "ァ"),
+    (0xEA, "ゥ"),
+    (0xEB, "ェ"),
+    (0xEC, "▷"),
+    (0xED, "▶"),
+    (0xEE, "▼"),
+    (0xEF, "♂"),
+    (0xF0, "円"),
+    (0xF1, "×"),
+    (0xF2, "．"),
+    (0xF3, "／"),
+    (0xF4, "ォ"),
+    (0xF5, "♀"),
+    (0xF6, "0"),
+    (0xF7, "1"),
+    (0xF8, "2"),
+    (0xF9, "3"),
+    (0xFA, "4"),
+    (0xFB, "5"),
+    (0xFC, "6"),
+    (0xFD, "7"),
+    (0xFE, "8"),
+    (0xFF, "9"),
+];
+
 impl fmt::Display for ROMString {
     /// Converts the internal string represnetation to a String
     ///
+    /// Decoding stops at the first `@`.
+    ///
     /// # Example
     ///
     /// ```
@@ -186,95 +683,30 @@ impl fmt::Display for ROMString {
     /// assert_eq!(decoded, "ABC");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let value: String = self
-            .value
-            .iter()
-            .map(|c| match c {
-                0x49 => "¶",
-                0x4E => "\n",
-                0x50 => "@",
-                0x54 => "#",
-                0x7F => " ",
-                0x80 => "A",
-                0x81 => "B",
-                0x82 => "C",
-                0x83 => "D",
-                0x84 => "E",
-                0x85 => "F",
-                0x86 => "G",
-                0x87 => "H",
-                0x88 => "I",
-                0x89 => "J",
-                0x8a => "K",
-                0x8b => "L",
-                0x8c => "M",
-                0x8d => "N",
-                0x8e => "O",
-                0x8f => "P",
-                0x90 => "Q",
-                0x91 => "R",
-                0x92 => "S",
-                0x93 => "T",
-                0x94 => "U",
-                0x95 => "V",
-                0x96 => "W",
-                0x97 => "X",
-                0x98 => "Y",
-                0x99 => "Z",
-                0xA0 => "a",
-                0xA1 => "b",
-                0xA2 => "c",
-                0xA3 => "d",
-                0xA4 => "e",
-                0xA5 => "f",
-                0xA6 => "g",
-                0xA7 => "h",
-                0xA8 => "i",
-                0xA9 => "j",
-                0xAA => "k",
-                0xAB => "l",
-                0xAC => "m",
-                0xAD => "n",
-                0xAE => "o",
-                0xAF => "p",
-                0xB0 => "q",
-                0xB1 => "r",
-                0xB2 => "s",
-                0xB3 => "t",
-                0xB4 => "u",
-                0xB5 => "v",
-                0xB6 => "w",
-                0xB7 => "x",
-                0xB8 => "y",
-                0xB9 => "z",
-                0xBA => "é",
-                0xBD => "'s",
-                0xBE => "'t",
-                0xE0 => "'",
-                0xE3 => "-",
-                0xE8 => ".",
-                0xEF => "♂",
-                0xF4 => ",",
-                0xF5 => "♀",
-                0xF6 => "0",
-                0xF7 => "1",
-                0xF8 => "2",
-                0xF9 => "3",
-                0xFA => "4",
-                0xFB => "5",
-                0xFC => "6",
-                0xFD => "7",
-                0xFE => "8",
-                0xFF => "9",
-                _ => "?",
-            })
-            .collect();
+        write!(f, "{}", self.decode(&Charset::ENGLISH))
+    }
+}
 
-        let at_offset = value.find('@').unwrap_or(value.len());
-        let mut value = String::from(&value);
+impl Serialize for ROMString {
+    /// Serializes a ROM string as its decoded String
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
 
-        value.truncate(at_offset);
+impl<'de> Deserialize<'de> for ROMString {
+    /// Deserializes a ROM string from a String
+    ///
+    /// Fails on characters that have no ROM equivalent.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
 
-        write!(f, "{}", value)
+        ROMString::try_from(string).map_err(de::Error::custom)
     }
 }
@@ -0,0 +1,598 @@
+//! Pkmnapi text module
+//!
+//! Game text is a stream of text commands. Printable characters live inside
+//! `{text}` commands, which end with `@` or with one of the characters that
+//! end the whole text (`<DONE>`, `<PROMPT>` and `<DEXEND>`).
+//!
+//! In markup, characters are written as they are printed, control characters
+//! as `<TAG>` and every other command as `{command arguments}`. Characters at
+//! the start of the text or after a command open a new `{text}` command.
+//!
+//! # Example
+//!
+//! ```
+//! use pkmnapi_db::text::*;
+//!
+//! let text = Text::from_markup("Hello <PLAYER>.{far 0x22:0x4000}{end}").unwrap();
+//!
+//! assert_eq!(
+//!     text.commands,
+//!     vec![
+//!         TextCommand::Start(vec![0x87, 0xA4, 0xAB, 0xAB, 0xAE, 0x7F, 0x52, 0xE8]),
+//!         TextCommand::Far(0x22, 0x4000),
+//!         TextCommand::End,
+//!     ]
+//! );
+//! assert_eq!(
+//!     text.to_raw(),
+//!     vec![
+//!         0x00, 0x87, 0xA4, 0xAB, 0xAB, 0xAE, 0x7F, 0x52, 0xE8, 0x50, 0x17, 0x00, 0x40, 0x22,
+//!         0x50
+//!     ]
+//! );
+//! ```
+
+use crate::error::{self, Result};
+use crate::string::*;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// Representation of game text
+///
+/// # Example
+///
+/// ```
+/// use pkmnapi_db::text::*;
+///
+/// let text = Text::new(&[0x00, 0x80, 0x81, 0x82, 0x57]).unwrap();
+///
+/// assert_eq!(text.to_string(), "ABC<DONE>");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Text {
+    pub commands: Vec<TextCommand>,
+}
+
+/// Text command
+///
+/// Addresses are RAM or VRAM addresses, far pointers are a bank and a pointer
+/// into that bank.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextCommand {
+    /// Characters to print, without the `@` that ends them
+    Start(Vec<u8>),
+    /// Print the string at an address
+    Ram(u16),
+    /// Print the BCD number at an address, with its length and flags
+    Bcd(u16, u8),
+    /// Move the cursor to an address
+    Move(u16),
+    /// Draw a box at an address, with its height and width
+    Box(u16, u8, u8),
+    /// Move the cursor to the bottom line
+    Low,
+    /// Wait for a button press, showing the prompt arrow
+    PromptButton,
+    /// Scroll the text box up one line
+    Scroll,
+    /// Run the code that follows, ending the text
+    Asm,
+    /// Print the number at an address, with its size in bytes and digits
+    Num(u16, u8, u8),
+    /// Wait for a short time
+    Pause,
+    /// Play a sound effect or cry
+    Sound(u8),
+    /// Print a number of dots, waiting after each one
+    Dots(u8),
+    /// Wait for a button press
+    WaitButton,
+    /// Continue with the text at a far pointer
+    Far(u8, u16),
+    /// End the text
+    End,
+}
+
+/// Characters that end the whole text
+const TEXT_END_CHARS: [u8; 3] = [0x57, 0x58, 0x5F];
+
+impl Text {
+    /// Decodes text from an array of bytes
+    ///
+    /// Decoding stops after the command that ends the text, so any bytes
+    /// after it are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let text = Text::new(&[0x17, 0x00, 0x40, 0x22, 0x50, 0xFF]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     text.commands,
+    ///     vec![TextCommand::Far(0x22, 0x4000), TextCommand::End]
+    /// );
+    /// ```
+    pub fn new(data: &[u8]) -> Result<Text> {
+        let mut commands = vec![];
+        let mut i = 0;
+
+        loop {
+            let command_offset = i;
+            let command_id = *data
+                .get(i)
+                .ok_or(error::Error::TextInvalid(command_offset))?;
+            let arg_len = match command_id {
+                0x01 | 0x03 => 2,
+                0x02 | 0x09 | 0x17 => 3,
+                0x04 => 4,
+                0x0C => 1,
+                _ => 0,
+            };
+            let args = data
+                .get((i + 1)..(i + 1 + arg_len))
+                .ok_or(error::Error::TextInvalid(command_offset))?;
+            let address = match args {
+                [lo, hi, ..] => u16::from_le_bytes([*lo, *hi]),
+                _ => 0,
+            };
+
+            i += 1 + arg_len;
+
+            let command = match command_id {
+                0x00 => {
+                    let string_len = data[i..]
+                        .iter()
+                        .position(|chr| *chr == 0x50 || TEXT_END_CHARS.contains(chr))
+                        .ok_or(error::Error::TextInvalid(command_offset))?;
+                    let string_end = i + string_len;
+
+                    if data[string_end] != 0x50 {
+                        commands.push(TextCommand::Start(data[i..=string_end].to_vec()));
+
+                        break;
+                    }
+
+                    let command = TextCommand::Start(data[i..string_end].to_vec());
+
+                    i = string_end + 1;
+
+                    command
+                }
+                0x01 => TextCommand::Ram(address),
+                0x02 => TextCommand::Bcd(address, args[2]),
+                0x03 => TextCommand::Move(address),
+                0x04 => TextCommand::Box(address, args[2], args[3]),
+                0x05 => TextCommand::Low,
+                0x06 => TextCommand::PromptButton,
+                0x07 => TextCommand::Scroll,
+                0x08 => TextCommand::Asm,
+                0x09 => TextCommand::Num(address, args[2] >> 0x04, args[2] & 0x0F),
+                0x0A => TextCommand::Pause,
+                0x0B | 0x0E..=0x16 => TextCommand::Sound(command_id),
+                0x0C => TextCommand::Dots(args[0]),
+                0x0D => TextCommand::WaitButton,
+                0x17 => TextCommand::Far(args[2], address),
+                0x50 => TextCommand::End,
+                _ => return Err(error::Error::TextInvalid(command_offset)),
+            };
+            let is_end = command.is_end();
+
+            commands.push(command);
+
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(Text { commands })
+    }
+
+    /// Parses text from markup
+    ///
+    /// The last command must end the text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let text = Text::from_markup("{text}{bcd 0xD347 0xC3}<0xF0>{end}").unwrap();
+    ///
+    /// assert_eq!(
+    ///     text.commands,
+    ///     vec![
+    ///         TextCommand::Start(vec![]),
+    ///         TextCommand::Bcd(0xD347, 0xC3),
+    ///         TextCommand::Start(vec![0xF0]),
+    ///         TextCommand::End,
+    ///     ]
+    /// );
+    ///
+    /// assert!(Text::from_markup("ABC").is_err());
+    /// ```
+    pub fn from_markup<S: Into<String>>(markup: S) -> Result<Text> {
+        let markup = markup.into();
+        let mut commands = vec![];
+        let mut string: Option<Vec<u8>> = None;
+        let mut run = String::new();
+        let mut run_position = 0;
+        let mut chars = markup.chars().enumerate();
+
+        while let Some((position, c)) = chars.next() {
+            if c != '{' {
+                if run.is_empty() {
+                    run_position = position;
+                }
+
+                run.push(c);
+
+                continue;
+            }
+
+            let mut tag = String::new();
+
+            loop {
+                match chars.next() {
+                    Some((_, '}')) => break,
+                    Some((_, tag_c)) => tag.push(tag_c),
+                    None => {
+                        return Err(error::Error::TextMarkupInvalid(format!(
+                            "missing }} after {{{}",
+                            tag
+                        )))
+                    }
+                }
+            }
+
+            if !run.is_empty() {
+                string
+                    .get_or_insert(vec![])
+                    .extend(Text::encode_chars(&run, run_position)?);
+                run.clear();
+            }
+
+            if let Some(string) = string.take() {
+                commands.push(TextCommand::Start(string));
+            }
+
+            if tag == "text" {
+                string = Some(vec![]);
+            } else {
+                commands.push(TextCommand::from_markup(&tag)?);
+            }
+        }
+
+        if !run.is_empty() {
+            string
+                .get_or_insert(vec![])
+                .extend(Text::encode_chars(&run, run_position)?);
+        }
+
+        if let Some(string) = string {
+            commands.push(TextCommand::Start(string));
+        }
+
+        let text = Text { commands };
+
+        text.validate()?;
+
+        Ok(text)
+    }
+
+    /// Text to raw bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let text = Text {
+    ///     commands: vec![
+    ///         TextCommand::Start(vec![0x80]),
+    ///         TextCommand::Num(0xD11E, 1, 3),
+    ///         TextCommand::Start(vec![0x81, 0x58]),
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     text.to_raw(),
+    ///     vec![0x00, 0x80, 0x50, 0x09, 0x1E, 0xD1, 0x13, 0x00, 0x81, 0x58]
+    /// );
+    /// ```
+    pub fn to_raw(&self) -> Vec<u8> {
+        self.commands
+            .iter()
+            .map(|command| command.to_raw())
+            .flatten()
+            .collect()
+    }
+
+    /// Checks that only the last command ends the text and that strings are
+    /// only ended by the command they belong to
+    fn validate(&self) -> Result<()> {
+        let last_index = self.commands.len().max(1) - 1;
+
+        for (i, command) in self.commands.iter().enumerate() {
+            if let TextCommand::Start(string) = command {
+                let end_chars = if i == last_index {
+                    &string[..string.len().max(1) - 1]
+                } else {
+                    &string[..]
+                };
+
+                if end_chars
+                    .iter()
+                    .any(|chr| *chr == 0x50 || TEXT_END_CHARS.contains(chr))
+                {
+                    return Err(error::Error::TextMarkupInvalid(
+                        "only the last character of the text may end it".to_owned(),
+                    ));
+                }
+            }
+
+            if command.is_end() != (i == last_index) {
+                return Err(error::Error::TextMarkupInvalid(
+                    "text must end with {end}, {asm}, <DONE>, <PROMPT> or <DEXEND>".to_owned(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the characters of a `{text}` command, reporting invalid
+    /// characters by their position in the whole markup
+    fn encode_chars(chars: &str, position: usize) -> Result<Vec<u8>> {
+        match ROMString::try_from(chars) {
+            Ok(string) => Ok(string.value),
+            Err(error::Error::StringCharactersInvalid(invalid_chars)) => {
+                Err(error::Error::StringCharactersInvalid(
+                    invalid_chars
+                        .iter()
+                        .map(|(char_position, c)| (position + char_position, *c))
+                        .collect(),
+                ))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl TextCommand {
+    /// Whether the command ends the text
+    pub fn is_end(&self) -> bool {
+        match self {
+            TextCommand::Start(string) => match string.last() {
+                Some(chr) => TEXT_END_CHARS.contains(chr),
+                None => false,
+            },
+            TextCommand::Asm | TextCommand::End => true,
+            _ => false,
+        }
+    }
+
+    /// Text command to raw bytes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let command = TextCommand::Far(0x22, 0x4000);
+    ///
+    /// assert_eq!(command.to_raw(), vec![0x17, 0x00, 0x40, 0x22]);
+    /// ```
+    pub fn to_raw(&self) -> Vec<u8> {
+        match self {
+            TextCommand::Start(string) => {
+                let terminator = if self.is_end() { vec![] } else { vec![0x50] };
+
+                [vec![0x00], string.to_vec(), terminator].concat()
+            }
+            TextCommand::Ram(address) => [vec![0x01], address.to_le_bytes().to_vec()].concat(),
+            TextCommand::Bcd(address, flags) => {
+                [vec![0x02], address.to_le_bytes().to_vec(), vec![*flags]].concat()
+            }
+            TextCommand::Move(address) => [vec![0x03], address.to_le_bytes().to_vec()].concat(),
+            TextCommand::Box(address, height, width) => [
+                vec![0x04],
+                address.to_le_bytes().to_vec(),
+                vec![*height, *width],
+            ]
+            .concat(),
+            TextCommand::Low => vec![0x05],
+            TextCommand::PromptButton => vec![0x06],
+            TextCommand::Scroll => vec![0x07],
+            TextCommand::Asm => vec![0x08],
+            TextCommand::Num(address, bytes, digits) => [
+                vec![0x09],
+                address.to_le_bytes().to_vec(),
+                vec![(bytes << 0x04) | (digits & 0x0F)],
+            ]
+            .concat(),
+            TextCommand::Pause => vec![0x0A],
+            TextCommand::Sound(sound_id) => vec![*sound_id],
+            TextCommand::Dots(count) => vec![0x0C, *count],
+            TextCommand::WaitButton => vec![0x0D],
+            TextCommand::Far(bank_id, pointer) => {
+                [vec![0x17], pointer.to_le_bytes().to_vec(), vec![*bank_id]].concat()
+            }
+            TextCommand::End => vec![0x50],
+        }
+    }
+
+    /// Parses a command from the markup between `{` and `}`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let command = TextCommand::from_markup("box 0xC4E1 2 4").unwrap();
+    ///
+    /// assert_eq!(command, TextCommand::Box(0xC4E1, 2, 4));
+    /// ```
+    pub fn from_markup(markup: &str) -> Result<TextCommand> {
+        let invalid = || error::Error::TextMarkupInvalid(format!("invalid command {{{}}}", markup));
+        let mut parts = markup.split_whitespace();
+        let name = parts.next().ok_or_else(invalid)?;
+        let args = parts
+            .map(|arg| arg.split(':'))
+            .flatten()
+            .map(|arg| match arg.strip_prefix("0x") {
+                Some(arg) => u32::from_str_radix(arg, 16),
+                None => arg.parse::<u32>(),
+            })
+            .collect::<std::result::Result<Vec<u32>, _>>()
+            .map_err(|_| invalid())?;
+        let arg_u8 = |i: usize| -> Result<u8> {
+            u8::try_from(*args.get(i).ok_or_else(invalid)?).map_err(|_| invalid())
+        };
+        let arg_u16 = |i: usize| -> Result<u16> {
+            u16::try_from(*args.get(i).ok_or_else(invalid)?).map_err(|_| invalid())
+        };
+        let (command, arg_count) = match name {
+            "ram" => (TextCommand::Ram(arg_u16(0)?), 1),
+            "bcd" => (TextCommand::Bcd(arg_u16(0)?, arg_u8(1)?), 2),
+            "move" => (TextCommand::Move(arg_u16(0)?), 1),
+            "box" => (TextCommand::Box(arg_u16(0)?, arg_u8(1)?, arg_u8(2)?), 3),
+            "low" => (TextCommand::Low, 0),
+            "prompt_button" => (TextCommand::PromptButton, 0),
+            "scroll" => (TextCommand::Scroll, 0),
+            "asm" => (TextCommand::Asm, 0),
+            "num" if arg_u8(1)? <= 0x0F && arg_u8(2)? <= 0x0F => {
+                (TextCommand::Num(arg_u16(0)?, arg_u8(1)?, arg_u8(2)?), 3)
+            }
+            "pause" => (TextCommand::Pause, 0),
+            "sound" => match arg_u8(0)? {
+                sound_id @ 0x0B | sound_id @ 0x0E..=0x16 => (TextCommand::Sound(sound_id), 1),
+                _ => return Err(invalid()),
+            },
+            "dots" => (TextCommand::Dots(arg_u8(0)?), 1),
+            "wait_button" => (TextCommand::WaitButton, 0),
+            "far" => (TextCommand::Far(arg_u8(0)?, arg_u16(1)?), 2),
+            "end" => (TextCommand::End, 0),
+            _ => return Err(invalid()),
+        };
+
+        if args.len() != arg_count {
+            return Err(invalid());
+        }
+
+        Ok(command)
+    }
+}
+
+impl fmt::Display for Text {
+    /// Converts text to markup
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let text = Text::new(&[
+    ///     0x00, 0x80, 0x4F, 0x81, 0x50, 0x00, 0x82, 0x50, 0x0C, 0x05, 0x50,
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(text.to_string(), "A<LINE>B{text}C{dots 5}{end}");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut previous_is_start = false;
+
+        for command in &self.commands {
+            let is_start = match command {
+                TextCommand::Start(string) => {
+                    if previous_is_start || string.is_empty() {
+                        write!(f, "{{text}}")?;
+                    }
+
+                    true
+                }
+                _ => false,
+            };
+
+            write!(f, "{}", command)?;
+
+            previous_is_start = is_start;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TextCommand {
+    /// Converts a text command to markup
+    ///
+    /// Characters of a `{text}` command are written without the command
+    /// itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pkmnapi_db::text::*;
+    ///
+    /// let command = TextCommand::Far(0x22, 0x4000);
+    ///
+    /// assert_eq!(command.to_string(), "{far 0x22:0x4000}");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextCommand::Start(string) => {
+                let chars: String = string.iter().map(ROMString::decode_char).collect();
+
+                write!(f, "{}", chars)
+            }
+            TextCommand::Ram(address) => write!(f, "{{ram 0x{:04X}}}", address),
+            TextCommand::Bcd(address, flags) => {
+                write!(f, "{{bcd 0x{:04X} 0x{:02X}}}", address, flags)
+            }
+            TextCommand::Move(address) => write!(f, "{{move 0x{:04X}}}", address),
+            TextCommand::Box(address, height, width) => {
+                write!(f, "{{box 0x{:04X} {} {}}}", address, height, width)
+            }
+            TextCommand::Low => write!(f, "{{low}}"),
+            TextCommand::PromptButton => write!(f, "{{prompt_button}}"),
+            TextCommand::Scroll => write!(f, "{{scroll}}"),
+            TextCommand::Asm => write!(f, "{{asm}}"),
+            TextCommand::Num(address, bytes, digits) => {
+                write!(f, "{{num 0x{:04X} {} {}}}", address, bytes, digits)
+            }
+            TextCommand::Pause => write!(f, "{{pause}}"),
+            TextCommand::Sound(sound_id) => write!(f, "{{sound 0x{:02X}}}", sound_id),
+            TextCommand::Dots(count) => write!(f, "{{dots {}}}", count),
+            TextCommand::WaitButton => write!(f, "{{wait_button}}"),
+            TextCommand::Far(bank_id, pointer) => {
+                write!(f, "{{far 0x{:02X}:0x{:04X}}}", bank_id, pointer)
+            }
+            TextCommand::End => write!(f, "{{end}}"),
+        }
+    }
+}
+
+impl Serialize for Text {
+    /// Serializes text as its markup
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Text {
+    /// Deserializes text from markup
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let markup = String::deserialize(deserializer)?;
+
+        Text::from_markup(markup).map_err(|e| de::Error::custom(e.to_string()))
+    }
+}
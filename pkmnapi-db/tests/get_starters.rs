@@ -0,0 +1,12 @@
+mod common;
+
+#[test]
+#[ignore]
+#[allow(non_snake_case)]
+fn get_starters() {
+    let db = common::load_rom();
+
+    let starters = db.get_starters().unwrap();
+
+    assert_eq!(starters, vec![4, 7, 1]);
+}
@@ -0,0 +1,46 @@
+use pkmnapi_db::*;
+
+mod common;
+
+macro_rules! get_static_pokemon_test {
+    ($test_name:ident, $static_pokemon_id:expr, $pokedex_id:expr, $level:expr, $static_pokemon_type:expr) => {
+        #[test]
+        #[ignore]
+        #[allow(non_snake_case)]
+        fn $test_name() {
+            let db = common::load_rom();
+
+            match db.get_static_pokemon(&$static_pokemon_id) {
+                Ok(static_pokemon) => assert_eq!(
+                    static_pokemon,
+                    StaticPokemon {
+                        pokedex_id: $pokedex_id,
+                        level: $level,
+                        static_pokemon_type: $static_pokemon_type,
+                    },
+                    "Searched for static Pokémon ID: {}",
+                    $static_pokemon_id
+                ),
+                Err(_) => panic!(format!(
+                    "Could not find static Pokémon ID: {}",
+                    $static_pokemon_id
+                )),
+            };
+        }
+    };
+}
+
+get_static_pokemon_test!(
+    get_static_pokemon_0,
+    0,
+    143,
+    30,
+    StaticPokemonType::ENCOUNTER(0x17)
+);
+get_static_pokemon_test!(
+    get_static_pokemon_1,
+    1,
+    143,
+    30,
+    StaticPokemonType::ENCOUNTER(0x1B)
+);
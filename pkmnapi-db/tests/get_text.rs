@@ -0,0 +1,28 @@
+mod common;
+
+macro_rules! get_text_test {
+    ($test_name:ident, $bank_id:expr, $pointer:expr, $text:expr) => {
+        #[test]
+        #[ignore]
+        #[allow(non_snake_case)]
+        fn $test_name() {
+            let db = common::load_rom();
+
+            match db.get_text(&$bank_id, &$pointer) {
+                Ok(text) => assert_eq!(
+                    text.to_string(),
+                    $text,
+                    "Searched for text: {:02X}:{:04X}",
+                    $bank_id,
+                    $pointer
+                ),
+                Err(_) => panic!(format!(
+                    "Could not find text: {:02X}:{:04X}",
+                    $bank_id, $pointer
+                )),
+            };
+        }
+    };
+}
+
+get_text_test!(get_text_2B_6E81, 0x2B, 0x6E81, "A strange seed was\nplanted on its\nback at birth.¶The plant sprouts\nand grows with\nthis #MON<DEXEND>");
@@ -1,3 +1,4 @@
+use pkmnapi_db::string::*;
 use pkmnapi_db::*;
 
 mod common;
@@ -13,7 +14,11 @@ macro_rules! get_trade_test {
             match db.get_trade(&$trade_id) {
                 Ok(trade) => assert_eq!(
                     trade,
-                    Trade::new($give_pokedex_id, $get_pokedex_id, $nickname),
+                    Trade::new(
+                        $give_pokedex_id,
+                        $get_pokedex_id,
+                        ROMString::from($nickname),
+                    ),
                     "Searched for trade ID: {}",
                     $trade_id
                 ),
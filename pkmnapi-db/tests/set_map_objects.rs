@@ -0,0 +1,35 @@
+use pkmnapi_db::*;
+
+mod common;
+
+macro_rules! set_map_objects_test {
+    ($test_name:ident, $map_id:expr) => {
+        #[test]
+        #[ignore]
+        #[allow(non_snake_case)]
+        fn $test_name() {
+            let mut db = common::load_rom();
+
+            let map_objects = db.get_map_objects(&$map_id).unwrap();
+
+            assert!(!map_objects.warps.is_empty());
+
+            let patch_set = db.set_map_objects(&$map_id, &map_objects).unwrap();
+
+            assert_eq!(
+                patch_set.patches.len(),
+                1,
+                "Map objects were relocated for map ID: {}",
+                $map_id
+            );
+
+            db.apply_patch(patch_set).unwrap();
+
+            assert_eq!(db.get_map_objects(&$map_id).unwrap(), map_objects);
+        }
+    };
+}
+
+set_map_objects_test!(set_map_objects_0, 0);
+set_map_objects_test!(set_map_objects_1, 1);
+set_map_objects_test!(set_map_objects_2, 2);
@@ -18,13 +18,13 @@ macro_rules! set_mart_items_test {
             let db = common::load_rom();
 
             match db.set_mart_items(&$mart_id, &$mart_items) {
-                Ok(patch) => assert_eq!(
-                    patch,
-                    Patch {
+                Ok(patch_set) => assert_eq!(
+                    patch_set.patches,
+                    vec![Patch {
                         offset: $patch_offset,
                         length: $patch_data.len(),
                         data: $patch_data
-                    },
+                    }],
                     "Searched for mart ID: {}",
                     $mart_id
                 ),
@@ -24,13 +24,13 @@ macro_rules! set_pokedex_text_test {
                     text: ROMString::from($pokedex_text),
                 },
             ) {
-                Ok(patch) => assert_eq!(
-                    patch,
-                    Patch {
+                Ok(patch_set) => assert_eq!(
+                    patch_set.patches,
+                    vec![Patch {
                         offset: $patch_offset,
                         length: $patch_data.len(),
                         data: $patch_data
-                    },
+                    }],
                     "Searched for Pokédex ID: {}",
                     $pokedex_id
                 ),
@@ -18,13 +18,13 @@ macro_rules! set_pokemon_evolutions_test {
             let db = common::load_rom();
 
             match db.set_pokemon_evolutions(&$pokedex_id, &$pokemon_evolutions) {
-                Ok(patch) => assert_eq!(
-                    patch,
-                    Patch {
+                Ok(patch_set) => assert_eq!(
+                    patch_set.patches,
+                    vec![Patch {
                         offset: $patch_offset,
                         length: $patch_data.len(),
                         data: $patch_data
-                    },
+                    }],
                     "Searched for Pokédex ID: {}",
                     $pokedex_id
                 ),
@@ -24,9 +24,9 @@ macro_rules! set_pokemon_pic_test {
 
             let pic = Pic::from_png(pic_data).unwrap();
 
-            match db.set_pokemon_pic(&$pokedex_id, &$pic_face, &pic, $encoding_method) {
-                Ok(patch) => assert_eq!(
-                    patch,
+            match db.set_pokemon_pic(&$pokedex_id, &$pic_face, &pic, Some($encoding_method)) {
+                Ok(patch_set) => assert_eq!(
+                    patch_set.patches[0],
                     Patch {
                         offset: $patch_offset,
                         length: $patch_data.len(),
@@ -0,0 +1,49 @@
+use pkmnapi_db::patch::*;
+use pkmnapi_db::text::*;
+
+mod common;
+
+macro_rules! set_text_test {
+    (
+        $test_name:ident,
+        $bank_id:expr,
+        $pointer:expr,
+        $text:expr,
+        $patch_offset:expr,
+        $patch_data:expr
+    ) => {
+        #[test]
+        #[ignore]
+        #[allow(non_snake_case)]
+        fn $test_name() {
+            let db = common::load_rom();
+
+            match db.set_text(&$bank_id, &$pointer, &Text::from_markup($text).unwrap()) {
+                Ok(patch) => assert_eq!(
+                    patch,
+                    Patch {
+                        offset: $patch_offset,
+                        length: $patch_data.len(),
+                        data: $patch_data
+                    },
+                    "Searched for text: {:02X}:{:04X}",
+                    $bank_id,
+                    $pointer
+                ),
+                Err(_) => panic!(format!(
+                    "Could not find text: {:02X}:{:04X}",
+                    $bank_id, $pointer
+                )),
+            };
+        }
+    };
+}
+
+set_text_test!(
+    set_text_2B_6E81,
+    0x2B,
+    0x6E81,
+    "ABCDE<DEXEND>",
+    0xAEE81,
+    vec![0x00, 0x80, 0x81, 0x82, 0x83, 0x84, 0x5F]
+);
@@ -0,0 +1,25 @@
+use pkmnapi_db::img::*;
+use std::fs;
+
+mod common;
+
+#[test]
+#[ignore]
+fn set_town_map_img() {
+    let db = common::load_rom();
+
+    let img_data = fs::read("../secrets/data/town_map.png").unwrap();
+    let img = Img::from_png(img_data).unwrap();
+
+    let patch_set = db.set_town_map_img(&img).unwrap();
+
+    assert_eq!(patch_set.patches.len(), 1);
+
+    let patch = &patch_set.patches[0];
+
+    assert_eq!(patch.offset, 0x71100);
+    assert_eq!(
+        patch.data,
+        db.rom[patch.offset..(patch.offset + patch.length)].to_vec()
+    );
+}
@@ -1,4 +1,5 @@
 use pkmnapi_db::patch::*;
+use pkmnapi_db::string::*;
 use pkmnapi_db::*;
 
 mod common;
@@ -21,7 +22,11 @@ macro_rules! set_trade_test {
 
             match db.set_trade(
                 &$trade_id,
-                &Trade::new($give_pokedex_id, $get_pokedex_id, $nickname),
+                &Trade::new(
+                    $give_pokedex_id,
+                    $get_pokedex_id,
+                    ROMString::from($nickname),
+                ),
             ) {
                 Ok(patch) => assert_eq!(
                     patch,
@@ -24,13 +24,13 @@ macro_rules! set_trainer_parties_test {
                 .collect::<Vec<Party>>();
 
             match db.set_trainer_parties(&$trainer_id, &trainer_parties) {
-                Ok(patch) => assert_eq!(
-                    patch,
-                    Patch {
+                Ok(patch_set) => assert_eq!(
+                    patch_set.patches,
+                    vec![Patch {
                         offset: $patch_offset,
                         length: $patch_data.len(),
                         data: $patch_data
-                    },
+                    }],
                     "Searched for trainer ID: {}",
                     $trainer_id
                 ),
@@ -22,14 +22,14 @@ macro_rules! set_trainer_pic_test {
 
             let pic = Pic::from_png(pic_data).unwrap();
 
-            match db.set_trainer_pic(&$trainer_id, &pic, $encoding_method) {
-                Ok(patch) => assert_eq!(
-                    patch,
-                    Patch {
+            match db.set_trainer_pic(&$trainer_id, &pic, Some($encoding_method)) {
+                Ok(patch_set) => assert_eq!(
+                    patch_set.patches,
+                    vec![Patch {
                         offset: $patch_offset,
                         length: $patch_data.len(),
                         data: $patch_data
-                    },
+                    }],
                     "Searched for trainer ID: {}",
                     $trainer_id
                 ),